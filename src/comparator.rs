@@ -0,0 +1,34 @@
+use std::cmp::Ordering;
+use std::fmt::{Debug, Formatter};
+use std::rc::Rc;
+
+type CompareFn<V> = dyn Fn(&V, &V) -> Ordering;
+
+/// Comparator wraps a user provided closure ordering two elements.
+///
+/// Closures neither implement `Eq` nor `Debug`, so two comparators are equal if they share the same closure.
+pub(crate) struct Comparator<V> {
+    cmp: Rc<CompareFn<V>>,
+}
+
+impl<V> Comparator<V> {
+    pub(crate) fn new(cmp: impl Fn(&V, &V) -> Ordering + 'static) -> Self {
+        Comparator { cmp: Rc::new(cmp) }
+    }
+
+    pub(crate) fn compare(&self, a: &V, b: &V) -> Ordering {
+        (self.cmp)(a, b)
+    }
+}
+
+impl<V> Clone for Comparator<V> {
+    fn clone(&self) -> Self {
+        Comparator { cmp: self.cmp.clone() }
+    }
+}
+
+impl<V> Debug for Comparator<V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Comparator").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,215 @@
+use super::Taxonomy;
+use crate::Identity;
+use crate::TaxonomyError;
+
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use std::rc::Rc;
+
+/// Nodes grouped at the top of a diagram and all nodes rendered, in order.
+type DiagramNodes<K> = (Vec<Rc<K>>, Vec<Rc<K>>);
+
+impl<K, V, M, E> Taxonomy<K, V, M, E>
+where
+    K: Hash + Eq,
+    V: Identity<K>,
+{
+    /// Renders the taxonomy or the subtree under a node as directed graph in Graphviz DOT syntax.
+    ///
+    /// If `node_id` is `None` the whole taxonomy is rendered and its root-nodes are grouped in a cluster, otherwise
+    /// the node and its descendants are rendered and the node is put in the cluster. Each node appears once, a
+    /// node having more than one super-node has one incoming arrow per super-node. Nodes are named `n0`, `n1`, ...
+    /// depth-first and pre-order, the grouped nodes are rendered first, followed by the other nodes and the arrows,
+    /// thus the output is stable.
+    ///
+    /// `label` returns the label of a node. `style` returns the attributes of the arrow from a super-node to a
+    /// sub-node, e.g. `style=dashed, color=gray`, or `None` for default attributes.
+    ///
+    /// # Errors
+    ///
+    /// - [`TaxonomyError::NodeNotFound`]: node with `node_id` not found.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kodiak_taxonomy::{Identity, Taxonomy, TaxonomyError};
+    ///
+    /// struct Class {
+    ///     name: String,
+    /// }
+    ///
+    /// impl Identity<String> for Class {
+    ///     fn id(&self) -> String {
+    ///         self.name.clone()
+    ///     }
+    /// }
+    ///
+    /// fn main() -> Result<(), TaxonomyError<String>> {
+    ///     let mut tax: Taxonomy<String, Class> = Taxonomy::new();
+    ///     tax.add(None, Class{name: "Device".to_string()})?
+    ///        .add(Some("Device".to_string()), Class{name: "Server".to_string()})?;
+    ///
+    ///     let dot = tax.to_dot(None, |class| class.name.clone(), |_, _| Some("color=gray".to_string()))?;
+    ///     assert!(dot.starts_with("digraph taxonomy {\n    subgraph cluster_roots {\n"));
+    ///     assert!(dot.contains("        n0 [label=\"Device\"];\n    }\n    n1 [label=\"Server\"];\n"));
+    ///     assert!(dot.contains("    n0 -> n1 [color=gray];\n"));
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn to_dot<L, S>(&self, node_id: Option<Rc<K>>, mut label: L, mut style: S) -> Result<String, TaxonomyError<K>>
+    where
+        L: FnMut(&V) -> String,
+        S: FnMut(&V, &V) -> Option<String>,
+    {
+        let (top_ids, node_ids) = self._diagram_nodes(node_id)?;
+        let names = Self::_diagram_names(&node_ids);
+
+        let mut node = |node_id: &Rc<K>, indent: &str| {
+            let element = self._get_node_opt(node_id.clone()).unwrap().get();
+            format!(
+                "{}{} [label=\"{}\"];\n",
+                indent,
+                names[node_id],
+                Self::_escape_dot(&label(element))
+            )
+        };
+
+        let mut dot =
+            String::from("digraph taxonomy {\n    subgraph cluster_roots {\n        style=dashed;\n        label=\"\";\n");
+        for node_id in top_ids.iter() {
+            dot.push_str(&node(node_id, "        "));
+        }
+        dot.push_str("    }\n");
+        for node_id in node_ids.iter().filter(|node_id| !top_ids.contains(node_id)) {
+            dot.push_str(&node(node_id, "    "));
+        }
+        for (super_id, sub_id) in self._diagram_edges(&node_ids) {
+            let (super_element, sub_element) = (self.get(super_id.clone()).unwrap(), self.get(sub_id.clone()).unwrap());
+            match style(super_element, sub_element) {
+                Some(style) => dot.push_str(&format!("    {} -> {} [{}];\n", names[&super_id], names[&sub_id], style)),
+                None => dot.push_str(&format!("    {} -> {};\n", names[&super_id], names[&sub_id])),
+            }
+        }
+        dot.push_str("}\n");
+
+        Ok(dot)
+    }
+
+    /// Renders the taxonomy or the subtree under a node as Mermaid flowchart, `graph TD`.
+    ///
+    /// See [`to_dot`](Self::to_dot), the root-nodes or the node are grouped in a subgraph. `style` returns the CSS
+    /// style of the arrow from a super-node to a sub-node, e.g. `stroke:gray`, which is applied by `linkStyle`, or
+    /// `None` for the default style.
+    ///
+    /// # Errors
+    ///
+    /// - [`TaxonomyError::NodeNotFound`]: node with `node_id` not found.
+    // Test coverage: { unit = done, integration = done, doc = none } -> ok
+    pub fn to_mermaid<L, S>(&self, node_id: Option<Rc<K>>, mut label: L, mut style: S) -> Result<String, TaxonomyError<K>>
+    where
+        L: FnMut(&V) -> String,
+        S: FnMut(&V, &V) -> Option<String>,
+    {
+        let (top_ids, node_ids) = self._diagram_nodes(node_id)?;
+        let names = Self::_diagram_names(&node_ids);
+
+        let mut node = |node_id: &Rc<K>, indent: &str| {
+            let element = self._get_node_opt(node_id.clone()).unwrap().get();
+            format!(
+                "{}{}[\"{}\"]\n",
+                indent,
+                names[node_id],
+                Self::_escape_mermaid(&label(element))
+            )
+        };
+
+        let mut mermaid = String::from("graph TD\n    subgraph roots [\" \"]\n");
+        for node_id in top_ids.iter() {
+            mermaid.push_str(&node(node_id, "        "));
+        }
+        mermaid.push_str("    end\n");
+        for node_id in node_ids.iter().filter(|node_id| !top_ids.contains(node_id)) {
+            mermaid.push_str(&node(node_id, "    "));
+        }
+        let mut link_styles = Vec::new();
+        for (index, (super_id, sub_id)) in self._diagram_edges(&node_ids).into_iter().enumerate() {
+            mermaid.push_str(&format!("    {} --> {}\n", names[&super_id], names[&sub_id]));
+            let (super_element, sub_element) = (self.get(super_id).unwrap(), self.get(sub_id).unwrap());
+            if let Some(style) = style(super_element, sub_element) {
+                link_styles.push(format!("    linkStyle {} {}\n", index, style));
+            }
+        }
+        mermaid.push_str(&link_styles.concat());
+
+        Ok(mermaid)
+    }
+}
+
+#[doc(hidden)]
+impl<K, V, M, E> Taxonomy<K, V, M, E>
+where
+    K: Hash + Eq,
+    V: Identity<K>,
+{
+    /// Returns the edges between the nodes rendered, in order of the super-nodes and their sub-nodes.
+    // Test coverage: { unit = done, integration = n/a, doc = n/a } -> ok
+    fn _diagram_edges(&self, node_ids: &[Rc<K>]) -> Vec<(Rc<K>, Rc<K>)> {
+        node_ids
+            .iter()
+            .flat_map(|node_id| {
+                self._sub_ids_of(Some(node_id.clone()))
+                    .into_iter()
+                    .map(move |sub_id| (node_id.clone(), sub_id))
+            })
+            .collect()
+    }
+
+    /// Returns the names of the nodes rendered, `n0`, `n1`, ... in order.
+    // Test coverage: { unit = done, integration = n/a, doc = n/a } -> ok
+    fn _diagram_names(node_ids: &[Rc<K>]) -> HashMap<Rc<K>, String> {
+        node_ids
+            .iter()
+            .enumerate()
+            .map(|(index, node_id)| (node_id.clone(), format!("n{}", index)))
+            .collect()
+    }
+
+    /// Returns the nodes grouped at the top of the diagram, i.e. the root-nodes or the node with `node_id`, and all
+    /// nodes rendered, depth-first and pre-order, each node once.
+    // Test coverage: { unit = done, integration = n/a, doc = n/a } -> ok
+    fn _diagram_nodes(&self, node_id: Option<Rc<K>>) -> Result<DiagramNodes<K>, TaxonomyError<K>> {
+        let top_ids = match node_id {
+            None => self.node0.iter().cloned().collect::<Vec<_>>(),
+            Some(node_id) => {
+                self._get_node_res(node_id.clone())?;
+                vec![node_id]
+            }
+        };
+
+        let mut visited = HashSet::new();
+        let mut node_ids = Vec::new();
+        let mut stack = top_ids.iter().rev().cloned().collect::<Vec<_>>();
+        while let Some(node_id) = stack.pop() {
+            if visited.insert(node_id.clone()) {
+                stack.extend(self._sub_ids_of(Some(node_id.clone())).into_iter().rev());
+                node_ids.push(node_id);
+            }
+        }
+
+        Ok((top_ids, node_ids))
+    }
+
+    /// Escapes a label to be written as quoted string in DOT syntax.
+    // Test coverage: { unit = done, integration = n/a, doc = n/a } -> ok
+    fn _escape_dot(label: &str) -> String {
+        label.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+    }
+
+    /// Escapes a label to be written as quoted string in Mermaid syntax.
+    // Test coverage: { unit = done, integration = n/a, doc = n/a } -> ok
+    fn _escape_mermaid(label: &str) -> String {
+        label.replace('"', "#quot;").replace('\n', "<br/>")
+    }
+}
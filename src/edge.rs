@@ -5,23 +5,50 @@ use std::rc::Rc;
 /// In a taxonomy which allows nodes to have more than one superordinate node,
 /// a node might be a sub node in more than one edge. In practice, an `Edge`
 /// allows us to define a node's distinct location in the taxonomy.
+///
+/// An `Edge` might carry a payload of type `E`, e.g. a weight or the confidence of a classification.
+/// `E` defaults to `()` for edges without payload.
 #[derive(Eq, PartialEq, Clone, Debug)]
-pub struct Edge<K> {
+pub struct Edge<K, E = ()> {
     super_id: Option<Rc<K>>,
     node_id: Rc<K>,
+    payload: E,
 }
 
-/// Position defines a node's position in the taxonomy.
 impl<K> Edge<K> {
-    pub(crate) fn new(super_id: Option<Rc<K>>, node_id: Rc<K>) -> Self {
-        Edge { super_id, node_id }
+    /// Creates an edge without payload between a super-node and a node, `None` stands for the root of the taxonomy.
+    pub fn new(super_id: Option<Rc<K>>, node_id: Rc<K>) -> Self {
+        Edge::with_payload(super_id, node_id, ())
+    }
+}
+
+impl<K, E> Edge<K, E> {
+    /// Creates an edge carrying a payload between a super-node and a node, `None` stands for the root of the taxonomy.
+    pub fn with_payload(super_id: Option<Rc<K>>, node_id: Rc<K>, payload: E) -> Self {
+        Edge {
+            super_id,
+            node_id,
+            payload,
+        }
     }
 
-    pub(crate) fn super_id(&self) -> Option<Rc<K>> {
+    /// Returns the id of the super-node or `None` if the node is a root-node.
+    pub fn super_id(&self) -> Option<Rc<K>> {
         self.super_id.clone()
     }
 
-    pub(crate) fn node_id(&self) -> Rc<K> {
+    /// Returns the id of the sub-node.
+    pub fn node_id(&self) -> Rc<K> {
         self.node_id.clone()
     }
+
+    /// Returns an immutable reference to the payload.
+    pub fn payload(&self) -> &E {
+        &self.payload
+    }
+
+    /// Consumes the edge and returns its payload.
+    pub fn into_payload(self) -> E {
+        self.payload
+    }
 }
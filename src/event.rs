@@ -0,0 +1,26 @@
+use std::rc::Rc;
+
+/// Event sent to the observers of a taxonomy, see [`Taxonomy::subscribe`](crate::Taxonomy::subscribe).
+///
+/// `None` stands for the root of the taxonomy.
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub enum Event<K> {
+    /// Node has been added as a sub-node of a super-node, see [`add`](crate::Taxonomy::add).
+    NodeAdded(Option<Rc<K>>, Rc<K>),
+
+    /// Node has been appended to another super-node, see [`append`](crate::Taxonomy::append).
+    EdgeAdded(Option<Rc<K>>, Rc<K>),
+
+    /// Node has been removed from a super-node, but remains in the taxonomy, see [`remove_from`](crate::Taxonomy::remove_from).
+    EdgeRemoved(Option<Rc<K>>, Rc<K>),
+
+    /// Node has been moved from one super-node to another, or to another position among the sub-nodes of the same
+    /// super-node, see [`move_to`](crate::Taxonomy::move_to) and [`reorder`](crate::Taxonomy::reorder).
+    NodeMoved(Rc<K>, Option<Rc<K>>, Option<Rc<K>>),
+
+    /// Node has been removed from the taxonomy together with all its edges, see [`remove`](crate::Taxonomy::remove).
+    NodeRemoved(Rc<K>),
+
+    /// Element, metadata or payloads of a node might have been changed, e.g. by [`get_mut`](crate::Taxonomy::get_mut).
+    ElementMutated(Rc<K>),
+}
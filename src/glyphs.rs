@@ -0,0 +1,22 @@
+/// Glyphs drawing the branches of a tree rendered by [`Taxonomy::render_tree`](crate::Taxonomy::render_tree).
+#[derive(Eq, PartialEq, Clone, Copy, Debug, Default, Hash)]
+pub enum Glyphs {
+    /// Box-drawing characters, i.e. `├──`, `└──` and `│`.
+    #[default]
+    Unicode,
+
+    /// ASCII characters, i.e. `|--`, `` `-- `` and `|`.
+    Ascii,
+}
+
+impl Glyphs {
+    /// Returns the glyphs for a node followed by a sibling, for the last node, for a level continued below and
+    /// for a level ended, in this order.
+    // Test coverage: { unit = done, integration = n/a, doc = n/a } -> ok
+    pub(crate) fn branches(&self) -> [&'static str; 4] {
+        match self {
+            Glyphs::Unicode => ["├── ", "└── ", "│   ", "    "],
+            Glyphs::Ascii => ["|-- ", "`-- ", "|   ", "    "],
+        }
+    }
+}
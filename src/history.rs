@@ -0,0 +1,104 @@
+use crate::Journal;
+use crate::Operation;
+use crate::SnapshotFn;
+
+use std::collections::VecDeque;
+use std::fmt::{Debug, Formatter};
+use std::hash::Hash;
+
+pub(crate) type Entry<K, V, M, E> = (Operation<K>, Journal<K, V, M, E>);
+
+/// History keeps the journals of the latest operations, so they can be undone and redone.
+///
+/// A journal recorded for an operation holds the state before the operation. Undoing an operation restores this
+/// state and keeps the state after the operation for redo, and vice versa.
+pub(crate) struct History<K, V, M, E> {
+    snapshot: SnapshotFn<K, V, M, E>,
+    capacity: usize,
+    undo: VecDeque<Entry<K, V, M, E>>,
+    redo: Vec<Entry<K, V, M, E>>,
+}
+
+impl<K, V, M, E> History<K, V, M, E>
+where
+    K: Hash + Eq,
+{
+    pub(crate) fn new(snapshot: SnapshotFn<K, V, M, E>, capacity: usize) -> Self {
+        History {
+            snapshot,
+            capacity,
+            undo: VecDeque::new(),
+            redo: Vec::new(),
+        }
+    }
+
+    pub(crate) fn snapshot(&self) -> SnapshotFn<K, V, M, E> {
+        self.snapshot
+    }
+
+    /// Records a new operation, which discards all operations to redo.
+    pub(crate) fn record(&mut self, operation: Operation<K>, journal: Journal<K, V, M, E>) {
+        self.redo.clear();
+        self.push_undo(operation, journal);
+    }
+
+    /// Pushes an operation to undo, the oldest operation is dropped if capacity is exceeded.
+    pub(crate) fn push_undo(&mut self, operation: Operation<K>, journal: Journal<K, V, M, E>) {
+        self.undo.push_back((operation, journal));
+        while self.undo.len() > self.capacity {
+            self.undo.pop_front();
+        }
+    }
+
+    pub(crate) fn pop_undo(&mut self) -> Option<Entry<K, V, M, E>> {
+        self.undo.pop_back()
+    }
+
+    pub(crate) fn push_redo(&mut self, operation: Operation<K>, journal: Journal<K, V, M, E>) {
+        self.redo.push((operation, journal));
+    }
+
+    pub(crate) fn pop_redo(&mut self) -> Option<Entry<K, V, M, E>> {
+        self.redo.pop()
+    }
+
+    pub(crate) fn last_undo(&self) -> Option<&Operation<K>> {
+        self.undo.back().map(|(operation, _)| operation)
+    }
+
+    pub(crate) fn last_redo(&self) -> Option<&Operation<K>> {
+        self.redo.last().map(|(operation, _)| operation)
+    }
+
+    /// Returns the operations to undo, oldest first.
+    pub(crate) fn operations(&self) -> Vec<&Operation<K>> {
+        self.undo.iter().map(|(operation, _)| operation).collect()
+    }
+}
+
+impl<K, V, M, E> Clone for History<K, V, M, E>
+where
+    K: Hash + Eq + Clone,
+{
+    fn clone(&self) -> Self {
+        History {
+            snapshot: self.snapshot,
+            capacity: self.capacity,
+            undo: self.undo.clone(),
+            redo: self.redo.clone(),
+        }
+    }
+}
+
+impl<K, V, M, E> Debug for History<K, V, M, E>
+where
+    K: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("History")
+            .field("capacity", &self.capacity)
+            .field("undo", &self.undo.iter().map(|(operation, _)| operation).collect::<Vec<_>>())
+            .field("redo", &self.redo.iter().map(|(operation, _)| operation).collect::<Vec<_>>())
+            .finish()
+    }
+}
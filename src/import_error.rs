@@ -0,0 +1,42 @@
+use crate::TaxonomyError;
+
+use std::error::Error;
+use std::fmt::{Debug, Display};
+
+/// Errors that might occur when importing a taxonomy from text, each error refers to the line of the input causing
+/// it. Lines are counted starting with 1.
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub enum ImportError<K> {
+    /// Input is malformed, e.g. an unexpected token or an unterminated literal.
+    Syntax(usize, String),
+
+    /// Input is well-formed, but building the taxonomy from it failed, e.g. because of a loop.
+    Taxonomy(usize, TaxonomyError<K>),
+}
+
+impl<K> ImportError<K> {
+    /// Returns the line of the input causing the error.
+    pub fn line(&self) -> usize {
+        match self {
+            ImportError::Syntax(line, _) => *line,
+            ImportError::Taxonomy(line, _) => *line,
+        }
+    }
+}
+
+#[doc(hidden)]
+impl<K: Debug> Error for ImportError<K> {}
+
+// Excluded from code coverage check because error messages are trivial and not considered to be part of the public API
+#[cfg(not(tarpaulin_include))]
+#[doc(hidden)]
+impl<K: Debug> Display for ImportError<K> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            // Rust API Guideline - C-GOOD-ERR
+            // The error message given by the Display representation of an error type should be lowercase without trailing punctuation, and typically concise.
+            ImportError::Syntax(line, message) => write!(f, "line {}: {}", line, message),
+            ImportError::Taxonomy(line, error) => write!(f, "line {}: {}", line, error),
+        }
+    }
+}
@@ -0,0 +1,44 @@
+/// Indentation of the lines of an outline, see [`Taxonomy::from_outline`](crate::Taxonomy::from_outline) and
+/// [`Taxonomy::to_outline`](crate::Taxonomy::to_outline).
+#[derive(Eq, PartialEq, Clone, Copy, Debug, Default, Hash)]
+pub enum Indent {
+    /// Each level is indented by one tab.
+    #[default]
+    Tab,
+
+    /// Each level is indented by the number of spaces given, at least one.
+    Spaces(usize),
+}
+
+impl Indent {
+    /// Returns the whitespace indenting a single level.
+    // Test coverage: { unit = done, integration = n/a, doc = n/a } -> ok
+    pub(crate) fn unit(&self) -> String {
+        match self {
+            Indent::Tab => "\t".to_string(),
+            Indent::Spaces(spaces) => " ".repeat((*spaces).max(1)),
+        }
+    }
+
+    /// Returns the level of a line's indentation or a message describing why the indentation is invalid.
+    // Test coverage: { unit = done, integration = n/a, doc = n/a } -> ok
+    pub(crate) fn level(&self, line: &str) -> Result<usize, String> {
+        let indentation = &line[..line.len() - line.trim_start_matches([' ', '\t']).len()];
+        match self {
+            Indent::Tab if indentation.contains(' ') => Err("expected tabs for indentation but found spaces".to_string()),
+            Indent::Tab => Ok(indentation.len()),
+            Indent::Spaces(_) if indentation.contains('\t') => Err("expected spaces for indentation but found tabs".to_string()),
+            Indent::Spaces(spaces) => {
+                let spaces = (*spaces).max(1);
+                match indentation.len() % spaces {
+                    0 => Ok(indentation.len() / spaces),
+                    _ => Err(format!(
+                        "indentation of {} spaces is not a multiple of {}",
+                        indentation.len(),
+                        spaces
+                    )),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,671 @@
+use crate::Cursor;
+use crate::Edge;
+use crate::Identity;
+use crate::Node;
+use crate::Occurrence;
+use crate::RefMut;
+use crate::SharedUpdates;
+use crate::Taxonomy;
+use crate::TraversalOrder;
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::{Debug, Formatter};
+use std::hash::Hash;
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+/// An iterator over immutable references to the elements of a [`Taxonomy`].
+///
+/// By default, elements are returned in the same order as [`Taxonomy::traverse`] does: depth-first, pre-order,
+/// following the order of root-nodes and sub-nodes. See [`TraversalOrder`] for the other orders supported.
+/// In any order, a node with more than one super-node is returned once per super-node.
+///
+/// `Iter` keeps its own cursor, so several iterators might traverse the same taxonomy at the same time.
+///
+/// This struct is created by [`Taxonomy::iter`] and [`Taxonomy::iter_in`].
+#[derive(Debug)]
+pub struct Iter<'a, K: Hash + Eq, V: Identity<K>, M = (), E = ()> {
+    taxonomy: &'a Taxonomy<K, V, M, E>,
+    state: State<K>,
+    finished: bool,
+}
+
+/// Position of an `Iter` within the taxonomy, depending on the order of traversal.
+#[derive(Debug)]
+enum State<K> {
+    PreOrder(Vec<Cursor<K>>),
+    PostOrder(Vec<Cursor<K>>),
+    BreadthFirst(VecDeque<Rc<K>>),
+}
+
+impl<'a, K, V, M, E> Iter<'a, K, V, M, E>
+where
+    K: Hash + Eq,
+    V: Identity<K>,
+{
+    pub(crate) fn new(taxonomy: &'a Taxonomy<K, V, M, E>, order: TraversalOrder) -> Self {
+        let state = match order {
+            TraversalOrder::PreOrder => State::PreOrder(Vec::new()),
+            TraversalOrder::PostOrder => State::PostOrder(Vec::new()),
+            TraversalOrder::BreadthFirst => State::BreadthFirst(taxonomy._root_node_ids().collect()),
+        };
+
+        Iter {
+            taxonomy,
+            state,
+            finished: false,
+        }
+    }
+}
+
+impl<'a, K, V, M, E> Clone for Iter<'a, K, V, M, E>
+where
+    K: Hash + Eq,
+    V: Identity<K>,
+{
+    fn clone(&self) -> Self {
+        let clone_cursor = |cursor: &Vec<Cursor<K>>| {
+            cursor
+                .iter()
+                .map(|cursor| Cursor::new(cursor.super_id(), cursor.node_index()))
+                .collect()
+        };
+
+        let state = match &self.state {
+            State::PreOrder(cursor) => State::PreOrder(clone_cursor(cursor)),
+            State::PostOrder(cursor) => State::PostOrder(clone_cursor(cursor)),
+            State::BreadthFirst(queue) => State::BreadthFirst(queue.clone()),
+        };
+
+        Iter {
+            taxonomy: self.taxonomy,
+            state,
+            finished: self.finished,
+        }
+    }
+}
+
+impl<'a, K, V, M, E> Iterator for Iter<'a, K, V, M, E>
+where
+    K: Hash + Eq,
+    V: Identity<K>,
+{
+    type Item = &'a V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        // An empty cursor restarts traversal, so remember when the last node has been passed.
+        let node_id = match &mut self.state {
+            State::PreOrder(cursor) => self.taxonomy._next_from(cursor),
+            State::PostOrder(cursor) => self.taxonomy._next_post_order_from(cursor),
+            State::BreadthFirst(queue) => self.taxonomy._next_breadth_first_from(queue),
+        };
+
+        match node_id {
+            None => {
+                self.finished = true;
+                None
+            }
+            Some(node_id) => self.taxonomy.get(node_id),
+        }
+    }
+}
+
+impl<'a, K, V, M, E> FusedIterator for Iter<'a, K, V, M, E>
+where
+    K: Hash + Eq,
+    V: Identity<K>,
+{
+}
+
+/// An iterator over immutable references to the elements of a [`Taxonomy`], returning each node only once.
+///
+/// Elements are returned depth-first, pre-order. A node with more than one super-node is returned
+/// at the occurrence chosen by the [`Occurrence`] policy. All other occurrences are skipped, including
+/// their sub-nodes.
+///
+/// This struct is created by [`Taxonomy::iter_unique`].
+#[derive(Debug)]
+pub struct IterUnique<'a, K: Hash + Eq, V: Identity<K>, M = (), E = ()> {
+    taxonomy: &'a Taxonomy<K, V, M, E>,
+    occurrence: Occurrence,
+    cursor: Vec<Cursor<K>>,
+    visited: HashSet<Rc<K>>,
+    finished: bool,
+}
+
+impl<'a, K, V, M, E> IterUnique<'a, K, V, M, E>
+where
+    K: Hash + Eq,
+    V: Identity<K>,
+{
+    pub(crate) fn new(taxonomy: &'a Taxonomy<K, V, M, E>, occurrence: Occurrence) -> Self {
+        IterUnique {
+            taxonomy,
+            occurrence,
+            cursor: Vec::new(),
+            visited: HashSet::new(),
+            finished: false,
+        }
+    }
+}
+
+impl<'a, K, V, M, E> Iterator for IterUnique<'a, K, V, M, E>
+where
+    K: Hash + Eq,
+    V: Identity<K>,
+{
+    type Item = &'a V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        // An empty cursor restarts traversal, so remember when the last node has been passed.
+        match self
+            .taxonomy
+            ._next_unique_from(&mut self.cursor, &mut self.visited, self.occurrence)
+        {
+            None => {
+                self.finished = true;
+                None
+            }
+            Some(node_id) => self.taxonomy.get(node_id),
+        }
+    }
+}
+
+impl<'a, K, V, M, E> FusedIterator for IterUnique<'a, K, V, M, E>
+where
+    K: Hash + Eq,
+    V: Identity<K>,
+{
+}
+
+/// An iterator over the levels of a [`Taxonomy`], returning the elements of one level at a time.
+///
+/// The first level holds the root-nodes, the second level their sub-nodes and so on. Within a level,
+/// elements are ordered breadth-first. A node with more than one super-node is part of a level once per super-node
+/// at this level and might be part of several levels.
+///
+/// This struct is created by [`Taxonomy::levels`].
+#[derive(Debug)]
+pub struct Levels<'a, K: Hash + Eq, V: Identity<K>, M = (), E = ()> {
+    taxonomy: &'a Taxonomy<K, V, M, E>,
+    level: Vec<Rc<K>>,
+}
+
+impl<'a, K, V, M, E> Levels<'a, K, V, M, E>
+where
+    K: Hash + Eq,
+    V: Identity<K>,
+{
+    pub(crate) fn new(taxonomy: &'a Taxonomy<K, V, M, E>) -> Self {
+        Levels {
+            taxonomy,
+            level: taxonomy._root_node_ids().collect(),
+        }
+    }
+}
+
+impl<'a, K, V, M, E> Iterator for Levels<'a, K, V, M, E>
+where
+    K: Hash + Eq,
+    V: Identity<K>,
+{
+    type Item = Vec<&'a V>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.level.is_empty() {
+            return None;
+        }
+
+        let next_level = self.taxonomy._next_level(&self.level);
+        let level = std::mem::replace(&mut self.level, next_level);
+
+        Some(level.into_iter().filter_map(|node_id| self.taxonomy.get(node_id)).collect())
+    }
+}
+
+impl<'a, K, V, M, E> FusedIterator for Levels<'a, K, V, M, E>
+where
+    K: Hash + Eq,
+    V: Identity<K>,
+{
+}
+
+/// An element returned by [`Visits`] together with its position in the taxonomy.
+///
+/// A node with more than one super-node is visited once per super-node. Its position distinguishes
+/// these occurrences.
+#[derive(Debug)]
+pub struct Visit<'a, K, V> {
+    element: &'a V,
+    edge: Edge<K>,
+    path: Vec<Rc<K>>,
+}
+
+impl<'a, K, V> Visit<'a, K, V> {
+    /// Returns an immutable reference to the element.
+    pub fn element(&self) -> &'a V {
+        self.element
+    }
+
+    /// Returns the depth of the node, root-nodes have a depth of 0.
+    pub fn depth(&self) -> usize {
+        self.path.len() - 1
+    }
+
+    /// Returns the edge the node has been reached through. Its super-node is `None` for a root-node.
+    pub fn edge(&self) -> &Edge<K> {
+        &self.edge
+    }
+
+    /// Returns the ids of all nodes from the root-node down to and including the node.
+    pub fn path(&self) -> &[Rc<K>] {
+        &self.path
+    }
+}
+
+/// An iterator over the elements of a [`Taxonomy`] together with their position, see [`Visit`].
+///
+/// Elements are returned depth-first, pre-order, like [`Iter`] does.
+///
+/// This struct is created by [`Taxonomy::visits`].
+#[derive(Debug)]
+pub struct Visits<'a, K: Hash + Eq, V: Identity<K>, M = (), E = ()> {
+    taxonomy: &'a Taxonomy<K, V, M, E>,
+    cursor: Vec<Cursor<K>>,
+    finished: bool,
+}
+
+impl<'a, K, V, M, E> Visits<'a, K, V, M, E>
+where
+    K: Hash + Eq,
+    V: Identity<K>,
+{
+    pub(crate) fn new(taxonomy: &'a Taxonomy<K, V, M, E>) -> Self {
+        Visits {
+            taxonomy,
+            cursor: Vec::new(),
+            finished: false,
+        }
+    }
+}
+
+impl<'a, K, V, M, E> Iterator for Visits<'a, K, V, M, E>
+where
+    K: Hash + Eq,
+    V: Identity<K>,
+{
+    type Item = Visit<'a, K, V>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        // An empty cursor restarts traversal, so remember when the last node has been passed.
+        let node_id = match self.taxonomy._next_from(&mut self.cursor) {
+            None => {
+                self.finished = true;
+                return None;
+            }
+            Some(node_id) => node_id,
+        };
+
+        // Each cursor on the stack points to one node of the path, the last one to the node itself.
+        let path = self
+            .cursor
+            .iter()
+            .filter_map(|cursor| self.taxonomy._get_node_id_at(cursor).1)
+            .collect();
+        let super_id = self.cursor.last().and_then(|cursor| cursor.super_id());
+
+        Some(Visit {
+            element: self.taxonomy.get(node_id.clone())?,
+            edge: Edge::new(super_id, node_id),
+            path,
+        })
+    }
+}
+
+impl<'a, K, V, M, E> FusedIterator for Visits<'a, K, V, M, E>
+where
+    K: Hash + Eq,
+    V: Identity<K>,
+{
+}
+
+/// Direction in which [`Relatives`] walks the taxonomy.
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub(crate) enum Direction {
+    Supers,
+    Subs,
+}
+
+/// An iterator over the ids of a node's ancestors or descendants.
+///
+/// Ids are returned breadth-first, i.e. ordered by their distance to the node, and each id only once,
+/// even if it is reachable along several paths. Super-nodes are returned in the order they have been added,
+/// sub-nodes in their order below their super-node.
+///
+/// This struct is created by [`Taxonomy::ancestors`], [`Taxonomy::descendants`], [`Taxonomy::parents`] and
+/// [`Taxonomy::children`].
+#[derive(Debug)]
+pub struct Relatives<'a, K: Hash + Eq, V: Identity<K>, M = (), E = ()> {
+    taxonomy: &'a Taxonomy<K, V, M, E>,
+    direction: Direction,
+    max_depth: Option<usize>,
+    queue: VecDeque<(Rc<K>, usize)>,
+    visited: HashSet<Rc<K>>,
+}
+
+impl<'a, K, V, M, E> Relatives<'a, K, V, M, E>
+where
+    K: Hash + Eq,
+    V: Identity<K>,
+{
+    pub(crate) fn new(taxonomy: &'a Taxonomy<K, V, M, E>, node_id: Rc<K>, direction: Direction, max_depth: Option<usize>) -> Self {
+        let mut relatives = Relatives {
+            taxonomy,
+            direction,
+            max_depth,
+            queue: VecDeque::new(),
+            visited: HashSet::new(),
+        };
+        relatives.enqueue(node_id, 0);
+
+        relatives
+    }
+
+    /// Queues the direct relatives of a node unless they are visited already or beyond depth limit.
+    fn enqueue(&mut self, node_id: Rc<K>, depth: usize) {
+        if self.max_depth.is_some_and(|max_depth| depth >= max_depth) {
+            return;
+        }
+
+        for relative_id in self.taxonomy._relatives_of(node_id, self.direction) {
+            if self.visited.insert(relative_id.clone()) {
+                self.queue.push_back((relative_id, depth + 1));
+            }
+        }
+    }
+}
+
+impl<'a, K, V, M, E> Iterator for Relatives<'a, K, V, M, E>
+where
+    K: Hash + Eq,
+    V: Identity<K>,
+{
+    type Item = Rc<K>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (node_id, depth) = self.queue.pop_front()?;
+        self.enqueue(node_id.clone(), depth);
+
+        Some(node_id)
+    }
+}
+
+impl<'a, K, V, M, E> FusedIterator for Relatives<'a, K, V, M, E>
+where
+    K: Hash + Eq,
+    V: Identity<K>,
+{
+}
+
+/// An iterator over the ids of a node's co-nodes, i.e. all nodes sharing a super-node with the node.
+///
+/// For a root-node, all other root-nodes are co-nodes. Each id is returned only once, even if the nodes
+/// share more than one super-node. The node itself is never returned.
+///
+/// This struct is created by [`Taxonomy::siblings`].
+#[derive(Debug)]
+pub struct Siblings<'a, K: Hash + Eq, V: Identity<K>, M = (), E = ()> {
+    taxonomy: &'a Taxonomy<K, V, M, E>,
+    supers: std::vec::IntoIter<Option<Rc<K>>>,
+    subs: std::vec::IntoIter<Rc<K>>,
+    visited: HashSet<Rc<K>>,
+}
+
+impl<'a, K, V, M, E> Siblings<'a, K, V, M, E>
+where
+    K: Hash + Eq,
+    V: Identity<K>,
+{
+    pub(crate) fn new(taxonomy: &'a Taxonomy<K, V, M, E>, node_id: Rc<K>, supers: Vec<Option<Rc<K>>>) -> Self {
+        let mut visited = HashSet::new();
+        visited.insert(node_id);
+
+        Siblings {
+            taxonomy,
+            supers: supers.into_iter(),
+            subs: Vec::new().into_iter(),
+            visited,
+        }
+    }
+}
+
+impl<'a, K, V, M, E> Iterator for Siblings<'a, K, V, M, E>
+where
+    K: Hash + Eq,
+    V: Identity<K>,
+{
+    type Item = Rc<K>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            for node_id in self.subs.by_ref() {
+                if self.visited.insert(node_id.clone()) {
+                    return Some(node_id);
+                }
+            }
+
+            // Continue with the sub-nodes of the next super-node
+            self.subs = self.taxonomy._sub_ids_of(self.supers.next()?).into_iter();
+        }
+    }
+}
+
+impl<'a, K, V, M, E> FusedIterator for Siblings<'a, K, V, M, E>
+where
+    K: Hash + Eq,
+    V: Identity<K>,
+{
+}
+
+/// An iterator over the edges of a [`Taxonomy`], each edge carrying an immutable reference to its payload.
+///
+/// Root membership is represented as an edge with `None` as super-node.
+///
+/// This struct is created by [`Taxonomy::edges`], [`Taxonomy::edges_into`] and [`Taxonomy::edges_from`].
+#[derive(Debug)]
+pub struct Edges<'a, K: Hash + Eq, V: Identity<K>, M = (), E = ()> {
+    taxonomy: &'a Taxonomy<K, V, M, E>,
+    edges: std::vec::IntoIter<(Option<Rc<K>>, Rc<K>)>,
+}
+
+impl<'a, K, V, M, E> Edges<'a, K, V, M, E>
+where
+    K: Hash + Eq,
+    V: Identity<K>,
+{
+    pub(crate) fn new(taxonomy: &'a Taxonomy<K, V, M, E>, edges: Vec<(Option<Rc<K>>, Rc<K>)>) -> Self {
+        Edges {
+            taxonomy,
+            edges: edges.into_iter(),
+        }
+    }
+}
+
+impl<'a, K, V, M, E> Iterator for Edges<'a, K, V, M, E>
+where
+    K: Hash + Eq,
+    V: Identity<K>,
+{
+    type Item = Edge<K, &'a E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (super_id, node_id) = self.edges.next()?;
+        let payload = self.taxonomy._payload_of(super_id.clone(), node_id.clone())?;
+
+        Some(Edge::with_payload(super_id, node_id, payload))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.edges.size_hint()
+    }
+}
+
+impl<'a, K, V, M, E> ExactSizeIterator for Edges<'a, K, V, M, E>
+where
+    K: Hash + Eq,
+    V: Identity<K>,
+{
+}
+
+impl<'a, K, V, M, E> FusedIterator for Edges<'a, K, V, M, E>
+where
+    K: Hash + Eq,
+    V: Identity<K>,
+{
+}
+
+/// An iterator over mutable references to the elements of a [`Taxonomy`].
+///
+/// Elements are returned depth-first, pre-order. Because a mutable reference has to be unique,
+/// a node with more than one super-node is returned only once, at its first occurrence. Changes made through
+/// the references returned are recorded as one operation, see [`RefMut`].
+///
+/// This struct is created by [`Taxonomy::iter_mut`].
+pub struct IterMut<'a, K: Hash + Eq, V: Identity<K>, M = (), E = ()> {
+    order: std::vec::IntoIter<Rc<K>>,
+    nodes: HashMap<Rc<K>, &'a mut Node<K, V, M, E>>,
+    updates: SharedUpdates<'a, K, V, M, E>,
+}
+
+impl<'a, K, V, M, E> IterMut<'a, K, V, M, E>
+where
+    K: Hash + Eq,
+    V: Identity<K>,
+{
+    pub(crate) fn new(
+        order: Vec<Rc<K>>,
+        nodes: HashMap<Rc<K>, &'a mut Node<K, V, M, E>>,
+        updates: SharedUpdates<'a, K, V, M, E>,
+    ) -> Self {
+        IterMut {
+            order: order.into_iter(),
+            nodes,
+            updates,
+        }
+    }
+}
+
+impl<'a, K, V, M, E> Iterator for IterMut<'a, K, V, M, E>
+where
+    K: Hash + Eq,
+    V: Identity<K>,
+{
+    type Item = RefMut<'a, K, V, M, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node_id = self.order.next()?;
+        let node = self.nodes.remove(&node_id)?;
+        Some(RefMut::new(node_id, node, Node::get, Node::get_mut, self.updates.clone()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.order.size_hint()
+    }
+}
+
+impl<K, V, M, E> Debug for IterMut<'_, K, V, M, E>
+where
+    K: Hash + Eq,
+    V: Identity<K>,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IterMut")
+            .field("len", &self.order.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, K, V, M, E> ExactSizeIterator for IterMut<'a, K, V, M, E>
+where
+    K: Hash + Eq,
+    V: Identity<K>,
+{
+}
+
+impl<'a, K, V, M, E> FusedIterator for IterMut<'a, K, V, M, E>
+where
+    K: Hash + Eq,
+    V: Identity<K>,
+{
+}
+
+/// An owning iterator over the elements of a [`Taxonomy`].
+///
+/// Elements are returned depth-first, pre-order. Because an element is owned by the taxonomy only once,
+/// a node with more than one super-node is returned only once, at its first occurrence.
+///
+/// This struct is created by the `into_iter` method on [`Taxonomy`] (provided by the [`IntoIterator`] trait).
+#[derive(Debug)]
+pub struct IntoIter<K: Hash + Eq, V: Identity<K>, M = (), E = ()> {
+    order: std::vec::IntoIter<Rc<K>>,
+    elements: HashMap<Rc<K>, V>,
+    taxonomy: PhantomData<Taxonomy<K, V, M, E>>,
+}
+
+impl<K, V, M, E> IntoIter<K, V, M, E>
+where
+    K: Hash + Eq,
+    V: Identity<K>,
+{
+    pub(crate) fn new(order: Vec<Rc<K>>, elements: HashMap<Rc<K>, V>) -> Self {
+        IntoIter {
+            order: order.into_iter(),
+            elements,
+            taxonomy: PhantomData,
+        }
+    }
+}
+
+impl<K, V, M, E> Iterator for IntoIter<K, V, M, E>
+where
+    K: Hash + Eq,
+    V: Identity<K>,
+{
+    type Item = V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node_id = self.order.next()?;
+        self.elements.remove(&node_id)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.order.size_hint()
+    }
+}
+
+impl<K, V, M, E> ExactSizeIterator for IntoIter<K, V, M, E>
+where
+    K: Hash + Eq,
+    V: Identity<K>,
+{
+}
+
+impl<K, V, M, E> FusedIterator for IntoIter<K, V, M, E>
+where
+    K: Hash + Eq,
+    V: Identity<K>,
+{
+}
@@ -0,0 +1,100 @@
+use crate::Comparator;
+use crate::Cursor;
+use crate::Event;
+use crate::Node;
+
+use std::collections::{HashMap, LinkedList};
+use std::fmt::{Debug, Formatter};
+use std::hash::Hash;
+use std::rc::Rc;
+
+pub(crate) type SnapshotFn<K, V, M, E> = fn(&Node<K, V, M, E>) -> Node<K, V, M, E>;
+type Record<K, V, M, E> = Option<Node<K, V, M, E>>;
+
+/// Journal records the state of a taxonomy at the beginning of a transaction or an operation, so changes can be
+/// rolled back or undone.
+///
+/// Nodes are recorded lazily, right before they are updated for the first time, `None` stands for a node which
+/// did not exist. The remaining state of the taxonomy is recorded at once when the journal is created. Events are
+/// kept back until the changes are committed, so observers are not notified of changes rolled back.
+pub(crate) struct Journal<K, V, M, E> {
+    snapshot: SnapshotFn<K, V, M, E>,
+    pub(crate) nodes: HashMap<Rc<K>, Record<K, V, M, E>>,
+    pub(crate) node0: LinkedList<Rc<K>>,
+    pub(crate) last_updated_node: Option<Rc<K>>,
+    pub(crate) cursor: Vec<Cursor<K>>,
+    pub(crate) reachability: bool,
+    pub(crate) order: Option<Comparator<V>>,
+    pub(crate) events: Vec<Event<K>>,
+}
+
+impl<K, V, M, E> Journal<K, V, M, E>
+where
+    K: Hash + Eq,
+{
+    pub(crate) fn new(
+        snapshot: SnapshotFn<K, V, M, E>,
+        node0: LinkedList<Rc<K>>,
+        last_updated_node: Option<Rc<K>>,
+        cursor: Vec<Cursor<K>>,
+        reachability: bool,
+        order: Option<Comparator<V>>,
+    ) -> Self {
+        Journal {
+            snapshot,
+            nodes: HashMap::new(),
+            node0,
+            last_updated_node,
+            cursor,
+            reachability,
+            order,
+            events: Vec::new(),
+        }
+    }
+
+    /// Records a node unless it has been recorded before, i.e. the first state recorded is kept.
+    pub(crate) fn record(&mut self, node_id: Rc<K>, node: Option<&Node<K, V, M, E>>) {
+        if !self.nodes.contains_key(&node_id) {
+            let node = node.map(self.snapshot);
+            self.nodes.insert(node_id, node);
+        }
+    }
+
+    /// Takes over the nodes of a nested journal which have not been recorded by this journal and its events.
+    pub(crate) fn merge(&mut self, mut nested: Journal<K, V, M, E>) {
+        for (node_id, node) in nested.nodes {
+            self.nodes.entry(node_id).or_insert(node);
+        }
+        self.events.append(&mut nested.events);
+    }
+}
+
+impl<K, V, M, E> Clone for Journal<K, V, M, E>
+where
+    K: Hash + Eq + Clone,
+{
+    fn clone(&self) -> Self {
+        Journal {
+            snapshot: self.snapshot,
+            nodes: self
+                .nodes
+                .iter()
+                .map(|(node_id, node)| (node_id.clone(), node.as_ref().map(self.snapshot)))
+                .collect(),
+            node0: self.node0.clone(),
+            last_updated_node: self.last_updated_node.clone(),
+            cursor: self.cursor.clone(),
+            reachability: self.reachability,
+            order: self.order.clone(),
+            events: self.events.clone(),
+        }
+    }
+}
+
+impl<K, V, M, E> Debug for Journal<K, V, M, E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Journal")
+            .field("nodes", &self.nodes.len())
+            .finish_non_exhaustive()
+    }
+}
@@ -52,7 +52,7 @@ Collection of useful rustdoc options awaiting their implementation.
 //! Here is the code.
 //!
 //! ```rust
-//! use kodiak_taxonomy::{Identity, Taxonomy, TaxonomyError};
+//! use kodiak_taxonomy::{Identity, Taxonomy, TaxonomyError, TreeStyle};
 //!
 //! // Simplistic type `Class` to store in taxonomy (kodiak-taxonomy supports arbitrary types).
 //! #[derive(Debug)]
@@ -103,44 +103,108 @@ Collection of useful rustdoc options awaiting their implementation.
 //!   tax.add(Some(device_id.clone()), server)?
 //!      .add(Some(device_id), client);
 //!
-//!   // Traverse the taxonomy and print the classes.
-//!   while let Some(class) = tax.traverse() {
+//!   // Iterate over the taxonomy and print the classes.
+//!   for class in &tax {
 //!     println!("{:?}", class);
 //!   }
 //!
+//!   // Render the taxonomy as tree, the second occurrence of User is marked.
+//!   let style = TreeStyle::default().with_marker("(identical to HRM::User)");
+//!   let tree = tax.render_tree(&style, |class| class.name.clone());
+//!   assert_eq!(tree, "├── HRM
+//! │   └── User
+//! └── CMDB
+//!     ├── User (identical to HRM::User)
+//!     └── Device
+//!         ├── Server
+//!         └── Client
+//! ");
+//!
 //!   Ok(())
 //! }
 //! ```
 //!
 //! The library provides many more functions. Have a look at detailed documentation provided.
 //!
+//! # Import and export
+//!
+//! Taxonomies are exchanged as SKOS concept schemes in Turtle or N-Triples syntax, see
+//! [`Taxonomy::to_turtle`] and [`Taxonomy::from_turtle`]. Imports report errors together with the line of the
+//! input causing them, see [`ImportError`]. Diagrams of a taxonomy are rendered in Graphviz DOT or Mermaid syntax,
+//! see [`Taxonomy::to_dot`] and [`Taxonomy::to_mermaid`]. Outlines, one node per line indented by its level, are
+//! read and written by [`Taxonomy::from_outline`] and [`Taxonomy::to_outline`]. Adjacency lists in CSV format, one
+//! edge per row, are read and written by [`Taxonomy::from_adjacency_csv`] and [`Taxonomy::to_adjacency_csv`], the
+//! import reports the errors of all rows failing. Vocabularies in OBO 1.4 flat-file format are read by
+//! [`Taxonomy::from_obo`].
+//!
+//! # Cargo features
+//!
+//! - `serde`: implements Serde's `Serialize` and `Deserialize` for [`Taxonomy`]. Nodes, root-nodes and sub-nodes are
+//!   serialized in order, deserialization validates the taxonomy and rejects loops, dangling ids and super-nodes
+//!   not consistent with sub-nodes.
+//!
 //! # TL;DR
 //!
 //! Kodiak's specific requirements regarding its taxonomy and its implementation status:
 //! 1. [x] The top of the taxonomy allows multiple elements, i.e. users are free to create multiple root-nodes and are not forced to invent a super-node.
 //! 2. [x] An element can have more than one superordinate element
-//! 3. [ ] Elements might be complemented by arbitrary meta data (still todo: not implemented yet)
-//! 4. [ ] Edges (a tuple of a super and its sub element) might be complemented with arbitrary attributes (still todo: not implemented yet)
+//! 3. [x] Elements might be complemented by arbitrary meta data
+//! 4. [x] Edges (a tuple of a super and its sub element) might be complemented with arbitrary attributes
 //!
 //! [^Wikipedia]: [Taxonomy @ Wikipedia](https://en.wikipedia.org/wiki/Taxonomy)
 
 // Keep crate's module structure completely private, see public re-exports below.
 // (also hides modules from crate documentation)
+mod comparator;
 mod cursor;
 mod edge;
+mod event;
+mod glyphs;
+mod history;
+mod import_error;
+mod indent;
+mod iter;
+mod journal;
 mod node;
+mod obo_term;
+mod observer;
+mod occurrence;
+mod operation;
+mod ref_mut;
+mod removal_policy;
+mod skos_concept;
 mod taxonomy;
 mod taxonomy_error;
 mod tests;
 mod traits;
+mod traversal_order;
+mod tree_style;
 
 // Re-exports for convenient use within crate.
+pub(crate) use crate::comparator::Comparator;
 pub(crate) use crate::cursor::Cursor;
-pub(crate) use crate::edge::Edge;
+pub(crate) use crate::history::{Entry, History};
+pub(crate) use crate::journal::{Journal, SnapshotFn};
 pub(crate) use crate::node::Node;
+pub(crate) use crate::observer::Observers;
+pub(crate) use crate::ref_mut::{SharedUpdates, Updates};
 
 // Publicly re-exporting all items valuable to users.
 // (avoids explicitly listing re-exports in crate documentation as there is no alternate path to those items)
+pub use edge::Edge;
+pub use event::Event;
+pub use glyphs::Glyphs;
+pub use import_error::ImportError;
+pub use indent::Indent;
+pub use iter::{Edges, IntoIter, Iter, IterMut, IterUnique, Levels, Relatives, Siblings, Visit, Visits};
+pub use obo_term::OboTerm;
+pub use occurrence::Occurrence;
+pub use operation::Operation;
+pub use ref_mut::RefMut;
+pub use removal_policy::RemovalPolicy;
+pub use skos_concept::SkosConcept;
 pub use taxonomy::Taxonomy;
 pub use taxonomy_error::TaxonomyError;
 pub use traits::Identity;
+pub use traversal_order::TraversalOrder;
+pub use tree_style::TreeStyle;
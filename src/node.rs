@@ -14,15 +14,19 @@ use std::rc::Rc;
 /// is constrained by the trait `Identity`.
 ///
 /// In addition, a `Node` also stores the ids of its super-ordinated
-/// and sub-ordinated nodes.
+/// and sub-ordinated nodes, optional metadata of type `M` and the payloads of type `E`
+/// of the edges to its super-nodes.
 ///
 /// Users of the library do not directly interact with `Node`.
 #[derive(Eq, PartialEq, Clone, Debug)]
-pub(crate) struct Node<K, V> {
+pub(crate) struct Node<K, V, M = (), E = ()> {
     id: Rc<K>,
     element: V,
-    supers: LinkedList<SuperNode<Rc<K>>>, // Sequence doesn't matter, but if it's a root node SuperNode::None is always the first element in LinkedList
+    supers: LinkedList<SuperNode<Rc<K>>>, // Sequence matters: super-nodes in the order the node has been added or appended to them
     subs: LinkedList<Rc<K>>,              // Sequence matters
+    primary: Option<SuperNode<Rc<K>>>,    // Designated primary super-node, if None the earliest super-node is primary
+    metadata: Option<M>,                  // Metadata stored separately from element
+    payloads: Vec<(Option<Rc<K>>, E)>,    // Payloads of edges to super-nodes, None stands for the root
 }
 
 /// Represents a super-ordinated node. If `SuperNode` is `None` the `Node`
@@ -33,7 +37,7 @@ enum SuperNode<K> {
     Id(K),
 }
 
-impl<K, V> Node<K, V>
+impl<K, V, M, E> Node<K, V, M, E>
 where
     K: Hash + Eq,
     V: Identity<K>,
@@ -49,6 +53,29 @@ where
             element,
             supers,
             subs: LinkedList::new(),
+            primary: None,
+            metadata: None,
+            payloads: Vec::new(),
+        }
+    }
+
+    /// Returns a copy of the node, used to record its state before an update.
+    /// Unlike `clone`, `K` is not required to implement `Clone`.
+    // Test coverage: { unit = done, integration = n/a, doc = n/a } -> ok
+    pub(crate) fn snapshot(&self) -> Self
+    where
+        V: Clone,
+        M: Clone,
+        E: Clone,
+    {
+        Node {
+            id: self.id.clone(),
+            element: self.element.clone(),
+            supers: self.supers.clone(),
+            subs: self.subs.clone(),
+            primary: self.primary.clone(),
+            metadata: self.metadata.clone(),
+            payloads: self.payloads.clone(),
         }
     }
 
@@ -70,12 +97,49 @@ where
         &mut self.element
     }
 
+    /// Consumes the node and returns its element.
+    // Test coverage: { unit = none, integration = n/a, doc = n/a } -> ok
+    pub(crate) fn into_element(self) -> V {
+        self.element
+    }
+
+    /// Returns an immutable reference to node's metadata or `None` if there is no metadata.
+    // Test coverage: { unit = done, integration = n/a, doc = n/a } -> ok
+    pub(crate) fn metadata(&self) -> Option<&M> {
+        self.metadata.as_ref()
+    }
+
+    /// Returns a mutable reference to node's metadata or `None` if there is no metadata.
+    // Test coverage: { unit = done, integration = n/a, doc = n/a } -> ok
+    pub(crate) fn metadata_mut(&mut self) -> Option<&mut M> {
+        self.metadata.as_mut()
+    }
+
+    /// Sets node's metadata and returns the metadata replaced, if any.
+    // Test coverage: { unit = done, integration = n/a, doc = n/a } -> ok
+    pub(crate) fn set_metadata(&mut self, metadata: M) -> Option<M> {
+        self.metadata.replace(metadata)
+    }
+
+    /// Removes node's metadata and returns it, if any.
+    // Test coverage: { unit = done, integration = n/a, doc = n/a } -> ok
+    pub(crate) fn remove_metadata(&mut self) -> Option<M> {
+        self.metadata.take()
+    }
+
     /// Returns an immutable reference to subordinated nodes' ids.
     // Test coverage: { unit = none, integration = n/a, doc = n/a } -> ok
     pub(crate) fn subs(&self) -> &LinkedList<Rc<K>> {
         &self.subs
     }
 
+    /// Replaces node's sub-nodes, e.g. to reorder them.
+    // Test coverage: { unit = done, integration = n/a, doc = n/a } -> ok
+    pub(crate) fn set_subs(&mut self, subs: LinkedList<Rc<K>>) -> &mut Self {
+        self.subs = subs;
+        self
+    }
+
     /// Appends the id of a subordinate node to node's sub-nodes.
     // Test coverage: { unit = done, integration = n/a, doc = n/a } -> ok
     pub(crate) fn append_sub(&mut self, node_id: Rc<K>) -> &mut Self {
@@ -140,25 +204,26 @@ where
         self.subs().iter().nth(index).cloned()
     }
 
+    /// Returns the ids of all super nodes, `None` for the root of the taxonomy
+    // Test coverage: { unit = done, integration = n/a, doc = n/a } -> ok
+    pub(crate) fn super_ids(&self) -> Vec<Option<Rc<K>>> {
+        self.supers.iter().map(|cursor| cursor.id()).collect()
+    }
+
     /// Returns a list of valid super nodes (excluding None)
     // Test coverage: { unit = missing, integration = n/a, doc = n/a } -> not ok
     pub(crate) fn supers(&self) -> LinkedList<Rc<K>> {
         self.supers.iter().filter_map(|cursor| cursor.id()).collect()
     }
 
-    /// Adds additional id to node's super-nodes.
+    /// Adds additional id to node's super-nodes, behind the super-nodes added before.
     /// - silently ignores if id is already a super-node
     /// - if id is None this node becomes a root-node
     // Test coverage: { unit = done, integration = n/a, doc = n/a } -> ok
     pub(crate) fn add_super(&mut self, id: Option<Rc<K>>) -> &mut Self {
-        match id {
-            None => self.supers.push_front(SuperNode::new(None)),
-            Some(id) => {
-                let super_node = SuperNode::new(Some(id));
-                if !self.supers.contains(&super_node) {
-                    self.supers.push_back(super_node);
-                }
-            }
+        let super_node = SuperNode::new(id);
+        if !self.supers.contains(&super_node) {
+            self.supers.push_back(super_node);
         }
 
         self
@@ -178,24 +243,141 @@ where
         !self.supers.is_empty()
     }
 
+    /// Returns an immutable reference to the payload of the edge to a super-node or `None` if there is no payload.
+    // Test coverage: { unit = done, integration = n/a, doc = n/a } -> ok
+    pub(crate) fn payload(&self, super_id: Option<Rc<K>>) -> Option<&E> {
+        self.payloads
+            .iter()
+            .find(|(id, _)| *id == super_id)
+            .map(|(_, payload)| payload)
+    }
+
+    /// Returns a mutable reference to the payload of the edge to a super-node or `None` if there is no payload.
+    // Test coverage: { unit = done, integration = n/a, doc = n/a } -> ok
+    pub(crate) fn payload_mut(&mut self, super_id: Option<Rc<K>>) -> Option<&mut E> {
+        self.payloads
+            .iter_mut()
+            .find(|(id, _)| *id == super_id)
+            .map(|(_, payload)| payload)
+    }
+
+    /// Sets the payload of the edge to a super-node and returns the payload replaced, if any.
+    /// Does not check, if super-node is a super-node of this node.
+    // Test coverage: { unit = done, integration = n/a, doc = n/a } -> ok
+    pub(crate) fn set_payload(&mut self, super_id: Option<Rc<K>>, payload: E) -> Option<E> {
+        match self.payload_mut(super_id.clone()) {
+            Some(current) => Some(std::mem::replace(current, payload)),
+            None => {
+                self.payloads.push((super_id, payload));
+                None
+            }
+        }
+    }
+
+    /// Removes the payload of the edge to a super-node and returns it, if any.
+    // Test coverage: { unit = done, integration = n/a, doc = n/a } -> ok
+    pub(crate) fn remove_payload(&mut self, super_id: Option<Rc<K>>) -> Option<E> {
+        let index = self.payloads.iter().position(|(id, _)| *id == super_id)?;
+        Some(self.payloads.remove(index).1)
+    }
+
     /// Removes a super-node identified by id, silently ignores if there is no super with this id.
+    /// If the super-node is the designated primary super-node, the designation is removed as well.
+    /// The payload of the edge to the super-node is removed, too.
     // Test coverage: { unit = done, integration = n/a, doc = n/a } -> ok
     pub(crate) fn remove_super(&mut self, id: Option<Rc<K>>) {
         if let Some(index) = self.supers.iter().position(|cursor| cursor.id() == id) {
             let mut remain = self.supers.split_off(index);
-            remain.pop_front();
+            if remain.pop_front() == self.primary {
+                self.primary = None;
+            }
             self.supers.append(&mut remain);
         }
+        self.remove_payload(id);
     }
 
-    /// Returns true when node is a root node, e.g. supers starts with None (or is empty (unreachable))
+    /// Returns true if id is a super-node of this node, `None` stands for the root of the taxonomy.
     // Test coverage: { unit = done, integration = n/a, doc = n/a } -> ok
-    pub(crate) fn is_root(&self) -> bool {
-        match self.supers.front() {
-            None => true,
-            Some(super_node) => super_node.id().is_none(),
+    pub(crate) fn has_super_id(&self, id: Option<Rc<K>>) -> bool {
+        self.supers.iter().any(|super_node| super_node.id() == id)
+    }
+
+    /// Returns the id of the primary super-node or `None` if it is the root of the taxonomy.
+    ///
+    /// The primary super-node is the designated one, or if none is designated, the earliest super-node, i.e. the
+    /// one the node has been added or appended to first among its current super-nodes.
+    // Test coverage: { unit = done, integration = n/a, doc = n/a } -> ok
+    pub(crate) fn primary_super(&self) -> Option<Rc<K>> {
+        match &self.primary {
+            Some(primary) => primary.id(),
+            None => self.supers.front().and_then(|super_node| super_node.id()),
         }
     }
+
+    /// Designates a super-node as primary super-node, silently ignores if id is not a super-node.
+    // Test coverage: { unit = done, integration = n/a, doc = n/a } -> ok
+    pub(crate) fn set_primary_super(&mut self, id: Option<Rc<K>>) -> &mut Self {
+        if self.has_super_id(id.clone()) {
+            self.primary = Some(SuperNode::new(id));
+        }
+
+        self
+    }
+
+    /// Creates a `Node` from its parts, used to deserialize a taxonomy. Super-nodes are kept in order, duplicates
+    /// are dropped. `primary` is designated as primary super-node if it is a super-node, `Some(None)` designates the
+    /// root of the taxonomy.
+    // Test coverage: { unit = done, integration = n/a, doc = n/a } -> ok
+    #[cfg(feature = "serde")]
+    pub(crate) fn from_parts(
+        element: V,
+        supers: Vec<Option<Rc<K>>>,
+        primary: Option<Option<Rc<K>>>,
+        subs: LinkedList<Rc<K>>,
+        metadata: Option<M>,
+        payloads: Vec<(Option<Rc<K>>, E)>,
+    ) -> Self {
+        let mut node = Node {
+            id: Rc::new(element.id()),
+            element,
+            supers: LinkedList::new(),
+            subs,
+            primary: None,
+            metadata,
+            payloads,
+        };
+        for id in supers {
+            if !node.has_super_id(id.clone()) {
+                node.add_super(id);
+            }
+        }
+        if let Some(primary) = primary {
+            node.set_primary_super(primary);
+        }
+
+        node
+    }
+
+    /// Returns the designated primary super-node or `None` if none is designated. `Some(None)` stands for the root
+    /// of the taxonomy, which is designated explicitly as it isn't necessarily the earliest super-node.
+    // Test coverage: { unit = done, integration = n/a, doc = n/a } -> ok
+    #[cfg(feature = "serde")]
+    pub(crate) fn designated_primary(&self) -> Option<Option<Rc<K>>> {
+        self.primary.as_ref().map(|primary| primary.id())
+    }
+
+    /// Returns the payloads of the edges to the super-nodes, `None` stands for the root of the taxonomy.
+    // Test coverage: { unit = done, integration = n/a, doc = n/a } -> ok
+    #[cfg(feature = "serde")]
+    pub(crate) fn payloads(&self) -> &[(Option<Rc<K>>, E)] {
+        &self.payloads
+    }
+
+    /// Returns true when node is a root node, e.g. supers contains None (or is empty (unreachable))
+    // Test coverage: { unit = done, integration = n/a, doc = n/a } -> ok
+    pub(crate) fn is_root(&self) -> bool {
+        self.supers.is_empty() || self.has_super_id(None)
+    }
 }
 
 impl<K> SuperNode<Rc<K>> {
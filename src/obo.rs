@@ -0,0 +1,299 @@
+use super::Taxonomy;
+use crate::Identity;
+use crate::ImportError;
+use crate::OboTerm;
+use crate::TaxonomyError::*;
+
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::rc::Rc;
+use std::str::FromStr;
+
+/// A `[Term]` stanza read: its line, the id parsed, the term, its `is_a` lines with the ids of the super-nodes and its
+/// unknown tags with their values.
+struct OboStanza<K> {
+    line: usize,
+    id: K,
+    term: OboTerm,
+    is_a: Vec<(usize, K)>,
+    tags: Vec<(String, String)>,
+}
+
+/// The tags of a `[Term]` stanza read so far: the line of its header, its id together with the text of the id, its
+/// name, its obsolete flag, its `is_a` lines with the ids of the super-nodes and its unknown tags with their values.
+struct OboBuilder<K> {
+    line: usize,
+    id: Option<(K, String)>,
+    name: Option<String>,
+    is_obsolete: Option<bool>,
+    is_a: Vec<(usize, K)>,
+    tags: Vec<(String, String)>,
+}
+
+impl<K> OboBuilder<K> {
+    fn new(line: usize) -> Self {
+        OboBuilder {
+            line,
+            id: None,
+            name: None,
+            is_obsolete: None,
+            is_a: Vec::new(),
+            tags: Vec::new(),
+        }
+    }
+
+    /// Returns the stanza read, a term has to have an id.
+    fn finish(self) -> Result<OboStanza<K>, ImportError<K>> {
+        let Some((id, text)) = self.id else {
+            return Err(ImportError::Syntax(self.line, "term without id".to_string()));
+        };
+
+        Ok(OboStanza {
+            line: self.line,
+            id,
+            term: OboTerm::new(text, self.name, self.is_obsolete.unwrap_or_default()),
+            is_a: self.is_a,
+            tags: self.tags,
+        })
+    }
+}
+
+impl<K, V, M, E> Taxonomy<K, V, M, E>
+where
+    K: Hash + Eq,
+    V: Identity<K>,
+{
+    /// Imports a taxonomy from a flat file in OBO 1.4 format, each `[Term]` stanza becoming a node.
+    ///
+    /// The `id`, `name` and `is_obsolete` tags of a term are passed to `element` as [`OboTerm`], which has to
+    /// return an element with the id given. Ids are parsed by [`FromStr`]. Each `is_a` tag makes the term a
+    /// sub-node of the term referred to, terms without `is_a` tag become root-nodes. Other tags, e.g. `def` or
+    /// `synonym`, are kept in order as metadata of the node, if any, collected from pairs of tag and value.
+    /// Comments, trailing modifiers of ids, the header and other stanzas, e.g. `[Typedef]`, are skipped.
+    ///
+    /// Root-nodes and sub-nodes are added in order of the terms in the input, obsolete terms are imported as well.
+    ///
+    /// # Errors
+    ///
+    /// - [`ImportError::Syntax`]: a line is neither a stanza header nor a tag-value pair, a term has no id, a tag
+    ///   occurring once is repeated, an id is invalid, `is_obsolete` is neither `true` nor `false` or the id of the
+    ///   element returned by `element` differs from the id of the term.
+    /// - [`ImportError::Taxonomy`]: building the taxonomy failed, e.g. [`DuplicateNode`](crate::TaxonomyError::DuplicateNode)
+    ///   if two terms have the same id, [`NodeNotFound`](crate::TaxonomyError::NodeNotFound) if `is_a` refers to
+    ///   an unknown term or [`LoopDetected`](crate::TaxonomyError::LoopDetected) if `is_a` tags form a loop.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    /// use kodiak_taxonomy::{Identity, ImportError, Taxonomy};
+    ///
+    /// struct Class {
+    ///     id: String,
+    ///     name: String,
+    /// }
+    ///
+    /// impl Identity<String> for Class {
+    ///     fn id(&self) -> String {
+    ///         self.id.clone()
+    ///     }
+    /// }
+    ///
+    /// fn main() -> Result<(), ImportError<String>> {
+    ///     let obo = "format-version: 1.4\n\n\
+    ///                [Term]\nid: EX:1\nname: device\n\n\
+    ///                [Term]\nid: EX:2\nname: server\nis_a: EX:1 ! device\ndef: \"A device serving.\" []\n";
+    ///     let tax: Taxonomy<String, Class, Vec<(String, String)>> = Taxonomy::from_obo(obo, |term| Class {
+    ///         id: term.id().to_string(),
+    ///         name: term.name().unwrap_or_default().to_string(),
+    ///     })?;
+    ///     let super_id = tax.parents(Rc::new("EX:2".to_string())).unwrap().next().unwrap();
+    ///     assert_eq!(tax.get(super_id).unwrap().name, "device");
+    ///
+    ///     let metadata = tax.metadata(Rc::new("EX:2".to_string())).unwrap().unwrap();
+    ///     assert_eq!(metadata, &vec![("def".to_string(), "\"A device serving.\" []".to_string())]);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn from_obo<F>(input: &str, mut element: F) -> Result<Self, ImportError<K>>
+    where
+        K: Clone + FromStr,
+        M: FromIterator<(String, String)>,
+        E: Default,
+        F: FnMut(&OboTerm) -> V,
+    {
+        let stanzas = Self::_obo_stanzas(input)?;
+
+        // Terms are unique and refer to terms only
+        let mut terms = HashMap::new();
+        for (index, stanza) in stanzas.iter().enumerate() {
+            if terms.insert(&stanza.id, index).is_some() {
+                return Err(ImportError::Taxonomy(stanza.line, DuplicateNode(Rc::new(stanza.id.clone()))));
+            }
+        }
+        for (line, super_id) in stanzas.iter().flat_map(|stanza| stanza.is_a.iter()) {
+            if !terms.contains_key(super_id) {
+                return Err(ImportError::Taxonomy(*line, NodeNotFound(Rc::new(super_id.clone()))));
+            }
+        }
+
+        // Sub-nodes of each term with the lines of their `is_a` tags, in order
+        let mut sub_ids: HashMap<&K, Vec<(usize, usize)>> = HashMap::new();
+        for (index, stanza) in stanzas.iter().enumerate() {
+            for (line, super_id) in stanza.is_a.iter() {
+                sub_ids.entry(super_id).or_default().push((index, *line));
+            }
+        }
+
+        // Depth-first from the root-nodes, a term is added at its first occurrence and appended at further ones
+        let mut tax = Taxonomy::new();
+        let mut tags = stanzas.iter().map(|stanza| stanza.tags.clone()).collect::<Vec<_>>();
+        let mut stack = stanzas
+            .iter()
+            .enumerate()
+            .filter(|(_, stanza)| stanza.is_a.is_empty())
+            .map(|(index, stanza)| (None, index, stanza.line))
+            .rev()
+            .collect::<Vec<_>>();
+        while let Some((super_id, index, line)) = stack.pop() {
+            let stanza = &stanzas[index];
+            if tax.nodes.contains_key(&stanza.id) {
+                tax.append(super_id, stanza.id.clone())
+                    .map_err(|err| ImportError::Taxonomy(line, err))?;
+                continue;
+            }
+
+            let element = element(&stanza.term);
+            if element.id() != stanza.id {
+                return Err(ImportError::Syntax(
+                    stanza.line,
+                    "id of element differs from term id".to_string(),
+                ));
+            }
+            tax.add(super_id, element).map_err(|err| ImportError::Taxonomy(line, err))?;
+            let tags = std::mem::take(&mut tags[index]);
+            if !tags.is_empty() {
+                let node_id = Rc::new(stanza.id.clone());
+                tax.set_metadata(node_id, tags.into_iter().collect())
+                    .map_err(|err| ImportError::Taxonomy(line, err))?;
+            }
+            if let Some(sub_ids) = sub_ids.get(&stanza.id) {
+                stack.extend(sub_ids.iter().rev().map(|(sub, line)| (Some(stanza.id.clone()), *sub, *line)));
+            }
+        }
+
+        // Terms not reached are part of a loop or reachable through a loop only
+        if let Some(stanza) = stanzas.iter().find(|stanza| !tax.nodes.contains_key(&stanza.id)) {
+            return Err(ImportError::Taxonomy(stanza.line, LoopDetected(Rc::new(stanza.id.clone()))));
+        }
+
+        Ok(tax)
+    }
+}
+
+#[doc(hidden)]
+impl<K, V, M, E> Taxonomy<K, V, M, E>
+where
+    K: Hash + Eq,
+    V: Identity<K>,
+{
+    /// Parses the id in a value, i.e. its first word, ignoring trailing modifiers.
+    // Test coverage: { unit = done, integration = n/a, doc = n/a } -> ok
+    fn _obo_id(line: usize, tag: &str, value: &str) -> Result<(K, String), ImportError<K>>
+    where
+        K: FromStr,
+    {
+        let text = value
+            .split_whitespace()
+            .next()
+            .ok_or_else(|| ImportError::Syntax(line, format!("missing value of tag `{}`", tag)))?;
+        let id = text
+            .parse::<K>()
+            .map_err(|_| ImportError::Syntax(line, format!("invalid id `{}`", text)))?;
+
+        Ok((id, text.to_string()))
+    }
+
+    /// Reads the `[Term]` stanzas of a flat file, skipping its header and other stanzas.
+    // Test coverage: { unit = done, integration = n/a, doc = n/a } -> ok
+    fn _obo_stanzas(input: &str) -> Result<Vec<OboStanza<K>>, ImportError<K>>
+    where
+        K: FromStr,
+    {
+        let mut stanzas = Vec::new();
+        let mut term: Option<OboBuilder<K>> = None;
+        for (line, text) in input.lines().enumerate().map(|(index, text)| (index + 1, text.trim())) {
+            if text.is_empty() || text.starts_with('!') {
+                continue;
+            }
+
+            if let Some(header) = text.strip_prefix('[') {
+                let kind = header
+                    .strip_suffix(']')
+                    .ok_or_else(|| ImportError::Syntax(line, "unterminated stanza header".to_string()))?;
+                if let Some(term) = term.take() {
+                    stanzas.push(term.finish()?);
+                }
+                if kind.trim() == "Term" {
+                    term = Some(OboBuilder::new(line));
+                }
+                continue;
+            }
+
+            let (tag, value) = text
+                .split_once(':')
+                .ok_or_else(|| ImportError::Syntax(line, format!("expected tag-value pair but found `{}`", text)))?;
+            let (tag, value) = (tag.trim(), Self::_obo_value(value));
+            if tag.is_empty() {
+                return Err(ImportError::Syntax(line, "missing tag".to_string()));
+            }
+
+            // Header and other stanzas are skipped
+            let Some(term) = term.as_mut() else {
+                continue;
+            };
+            let duplicate = || ImportError::Syntax(line, format!("duplicate tag `{}`", tag));
+            match tag {
+                "id" if term.id.is_some() => return Err(duplicate()),
+                "id" => term.id = Some(Self::_obo_id(line, tag, &value)?),
+                "name" if term.name.is_some() => return Err(duplicate()),
+                "name" => term.name = Some(value),
+                "is_a" => term.is_a.push((line, Self::_obo_id(line, tag, &value)?.0)),
+                "is_obsolete" if term.is_obsolete.is_some() => return Err(duplicate()),
+                "is_obsolete" => match value.as_str() {
+                    "true" => term.is_obsolete = Some(true),
+                    "false" => term.is_obsolete = Some(false),
+                    _ => return Err(ImportError::Syntax(line, format!("invalid boolean `{}`", value))),
+                },
+                _ => term.tags.push((tag.to_string(), value)),
+            }
+        }
+        if let Some(term) = term {
+            stanzas.push(term.finish()?);
+        }
+
+        Ok(stanzas)
+    }
+
+    /// Returns a value without its trailing comment, i.e. the text after an unescaped `!` outside of quotes.
+    // Test coverage: { unit = done, integration = n/a, doc = n/a } -> ok
+    fn _obo_value(value: &str) -> String {
+        let (mut quoted, mut escaped) = (false, false);
+        let end = value
+            .char_indices()
+            .find(|(_, c)| {
+                let is_comment = !quoted && !escaped && *c == '!';
+                match (escaped, c) {
+                    (false, '\\') => escaped = true,
+                    (false, '"') => quoted = !quoted,
+                    _ => escaped = false,
+                }
+                is_comment
+            })
+            .map_or(value.len(), |(index, _)| index);
+
+        value[..end].trim().to_string()
+    }
+}
@@ -0,0 +1,33 @@
+/// An `OboTerm` describes a `[Term]` stanza of an OBO flat file, see
+/// [`Taxonomy::from_obo`](crate::Taxonomy::from_obo).
+///
+/// Its id identifies the term, e.g. `GO:0008150`, its optional name and obsolete flag are read from the `name` and
+/// `is_obsolete` tags.
+#[derive(Eq, PartialEq, Clone, Debug, Hash)]
+pub struct OboTerm {
+    id: String,
+    name: Option<String>,
+    is_obsolete: bool,
+}
+
+impl OboTerm {
+    /// Creates a term identified by an id with an optional name.
+    pub fn new(id: String, name: Option<String>, is_obsolete: bool) -> Self {
+        OboTerm { id, name, is_obsolete }
+    }
+
+    /// Returns the id of the term.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns the name of the term or `None` if it has no name.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Returns whether the term is marked obsolete.
+    pub fn is_obsolete(&self) -> bool {
+        self.is_obsolete
+    }
+}
@@ -0,0 +1,65 @@
+use crate::Event;
+
+use std::fmt::{Debug, Formatter};
+
+type ObserverFn<K> = dyn FnMut(&Event<K>);
+
+/// Observers keeps the closures subscribed to the events of a taxonomy, each identified by a subscription id.
+///
+/// Closures neither implement `Eq` nor `Clone`, thus observers are ignored when taxonomies are compared and are
+/// not taken over by a clone of a taxonomy.
+pub(crate) struct Observers<K> {
+    next_id: usize,
+    observers: Vec<(usize, Box<ObserverFn<K>>)>,
+}
+
+impl<K> Observers<K> {
+    pub(crate) fn new() -> Self {
+        Observers {
+            next_id: 0,
+            observers: Vec::new(),
+        }
+    }
+
+    /// Adds an observer and returns its subscription id.
+    pub(crate) fn subscribe(&mut self, observer: impl FnMut(&Event<K>) + 'static) -> usize {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.observers.push((id, Box::new(observer)));
+
+        id
+    }
+
+    /// Removes an observer, returns false if there is no observer with the subscription id.
+    pub(crate) fn unsubscribe(&mut self, id: usize) -> bool {
+        let len = self.observers.len();
+        self.observers.retain(|(observer_id, _)| *observer_id != id);
+
+        self.observers.len() < len
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.observers.is_empty()
+    }
+
+    /// Sends an event to all observers in the order of subscription.
+    pub(crate) fn notify(&mut self, event: &Event<K>) {
+        for (_, observer) in self.observers.iter_mut() {
+            observer(event);
+        }
+    }
+}
+
+impl<K> Clone for Observers<K> {
+    fn clone(&self) -> Self {
+        Observers::new()
+    }
+}
+
+impl<K> Debug for Observers<K> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Observers")
+            .field("len", &self.observers.len())
+            .finish_non_exhaustive()
+    }
+}
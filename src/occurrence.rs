@@ -0,0 +1,14 @@
+/// Policy deciding which occurrence of a node is returned when each node is visited only once.
+///
+/// A node with more than one super-node occurs once per super-node in the taxonomy. An
+/// [`IterUnique`](crate::IterUnique) returns each node exactly once, together with its sub-nodes.
+/// Occurrences not returned are skipped including their sub-nodes.
+#[derive(Eq, PartialEq, Clone, Copy, Debug, Default, Hash)]
+pub enum Occurrence {
+    /// The first occurrence in depth-first, pre-order traversal is returned.
+    #[default]
+    First,
+
+    /// The occurrence below the primary super-node is returned, see [`Taxonomy::set_primary_super`](crate::Taxonomy::set_primary_super).
+    Primary,
+}
@@ -0,0 +1,49 @@
+use std::rc::Rc;
+
+/// Operation recorded in the history of a taxonomy, see [`Taxonomy::history`](crate::Taxonomy::history).
+///
+/// Each public function changing a taxonomy is recorded as one operation, `None` stands for the root of the taxonomy.
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub enum Operation<K> {
+    /// Node has been added to a super-node, see [`add`](crate::Taxonomy::add).
+    Add(Option<Rc<K>>, Rc<K>),
+
+    /// Node has been appended to a super-node, see [`append`](crate::Taxonomy::append) and [`append_at`](crate::Taxonomy::append_at).
+    Append(Option<Rc<K>>, Rc<K>),
+
+    /// Node has been moved from one super-node to another, see [`move_to`](crate::Taxonomy::move_to).
+    MoveTo(Rc<K>, Option<Rc<K>>, Option<Rc<K>>),
+
+    /// Node has been moved among the sub-nodes of a super-node, see [`reorder`](crate::Taxonomy::reorder).
+    Reorder(Option<Rc<K>>, Rc<K>),
+
+    /// Sub-nodes of a super-node have been sorted, see [`sort_subs_by`](crate::Taxonomy::sort_subs_by).
+    Sort(Option<Rc<K>>),
+
+    /// All nodes have been sorted, see [`sort_all_by`](crate::Taxonomy::sort_all_by).
+    SortAll,
+
+    /// Keeping the taxonomy sorted has been enabled or disabled, see [`enable_keep_sorted`](crate::Taxonomy::enable_keep_sorted).
+    KeepSorted(bool),
+
+    /// Node has been removed, see [`remove`](crate::Taxonomy::remove) and [`take`](crate::Taxonomy::take).
+    Remove(Rc<K>),
+
+    /// Edge has been removed, see [`remove_from`](crate::Taxonomy::remove_from).
+    RemoveFrom(Option<Rc<K>>, Rc<K>),
+
+    /// Node has been removed together with its descendants, see [`remove_subtree`](crate::Taxonomy::remove_subtree).
+    RemoveSubtree(Rc<K>),
+
+    /// Element, metadata or primary super-node of a node has been changed, e.g. through [`get_mut`](crate::Taxonomy::get_mut).
+    Update(Rc<K>),
+
+    /// Payload of an edge has been changed, see [`set_edge_payload`](crate::Taxonomy::set_edge_payload).
+    UpdateEdge(Option<Rc<K>>, Rc<K>),
+
+    /// Elements have been changed through [`iter_mut`](crate::Taxonomy::iter_mut).
+    UpdateAll,
+
+    /// Several operations have been applied at once, see [`transaction`](crate::Taxonomy::transaction).
+    Transaction,
+}
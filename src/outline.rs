@@ -0,0 +1,155 @@
+use super::Taxonomy;
+use crate::Identity;
+use crate::ImportError;
+use crate::Indent;
+
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use std::rc::Rc;
+
+impl<K, V, M, E> Taxonomy<K, V, M, E>
+where
+    K: Hash + Eq,
+    V: Identity<K>,
+{
+    /// Imports a taxonomy from an outline, one node per line, the indentation of a line showing its level.
+    ///
+    /// Each line holds the label of a node, a line indented by one level more than the line before is a sub-node
+    /// of that line's node. Lines are indented by tabs or by a number of spaces per level, see [`Indent`]. Blank
+    /// lines are skipped, leading and trailing whitespace is not part of a label.
+    ///
+    /// `element` creates the element of a node from its label. A label occurring more than once refers to the
+    /// same node, i.e. its first occurrence adds the node and further occurrences append the node to their
+    /// super-node, thus outlines describe nodes having more than one super-node. Occurrences repeating an edge
+    /// are ignored.
+    ///
+    /// # Errors
+    ///
+    /// - [`ImportError::Syntax`]: a line is indented by other whitespace than configured, by a number of spaces
+    ///   not matching a level or by more than one level deeper than the line before.
+    /// - [`ImportError::Taxonomy`]: building the taxonomy failed, e.g. [`LoopDetected`](crate::TaxonomyError::LoopDetected)
+    ///   if a label occurs below itself or [`DuplicateNode`](crate::TaxonomyError::DuplicateNode) if `element`
+    ///   creates elements with equal ids for different labels.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    /// use kodiak_taxonomy::{Identity, ImportError, Indent, Taxonomy};
+    ///
+    /// struct Class {
+    ///     name: String,
+    /// }
+    ///
+    /// impl Identity<String> for Class {
+    ///     fn id(&self) -> String {
+    ///         self.name.clone()
+    ///     }
+    /// }
+    ///
+    /// fn main() -> Result<(), ImportError<String>> {
+    ///     let outline = "HRM\n  User\nCMDB\n  User\n  Device\n    Server\n";
+    ///     let tax: Taxonomy<String, Class> = Taxonomy::from_outline(outline, Indent::Spaces(2), |label| Class {
+    ///         name: label.to_string(),
+    ///     })?;
+    ///     assert_eq!(tax.parents(Rc::new("User".to_string())).unwrap().count(), 2);
+    ///     assert_eq!(tax.to_outline(Indent::Spaces(2), |class| class.name.clone()), outline);
+    ///
+    ///     let err = Taxonomy::<String, Class>::from_outline("HRM\n\tUser\n", Indent::Spaces(2), |label| Class {
+    ///         name: label.to_string(),
+    ///     });
+    ///     assert_eq!(err.err().map(|err| err.line()), Some(2));
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn from_outline<F>(input: &str, indent: Indent, mut element: F) -> Result<Self, ImportError<K>>
+    where
+        K: Clone,
+        E: Default,
+        F: FnMut(&str) -> V,
+    {
+        let mut tax = Taxonomy::new();
+        let mut ids: HashMap<String, K> = HashMap::new();
+
+        // Ids of the nodes of the lines above, one per level
+        let mut path: Vec<K> = Vec::new();
+        for (line, text) in input.lines().enumerate().map(|(index, text)| (index + 1, text)) {
+            let label = text.trim();
+            if label.is_empty() {
+                continue;
+            }
+
+            let level = indent.level(text).map_err(|message| ImportError::Syntax(line, message))?;
+            if level > path.len() {
+                let message = format!("indented by {} levels, expected {} at most", level, path.len());
+                return Err(ImportError::Syntax(line, message));
+            }
+            path.truncate(level);
+            let super_id = path.last().cloned();
+
+            let node_id = match ids.get(label) {
+                Some(node_id) => {
+                    let is_edge = tax.index_of(super_id.clone().map(Rc::new), Rc::new(node_id.clone())).is_ok();
+                    if !is_edge {
+                        tax.append(super_id, node_id.clone())
+                            .map_err(|err| ImportError::Taxonomy(line, err))?;
+                    }
+                    node_id.clone()
+                }
+                None => {
+                    let element = element(label);
+                    let node_id = element.id();
+                    tax.add(super_id, element).map_err(|err| ImportError::Taxonomy(line, err))?;
+                    ids.insert(label.to_string(), node_id.clone());
+                    node_id
+                }
+            };
+            path.push(node_id);
+        }
+
+        Ok(tax)
+    }
+
+    /// Exports the taxonomy as outline, one node per line, indented by its level.
+    ///
+    /// Root-nodes and sub-nodes are written in order, `label` returns the text of a line. A node having more than
+    /// one super-node is written below each of its super-nodes, but its sub-nodes are written below its first
+    /// occurrence only. Thus, [`from_outline`](Self::from_outline) restores the taxonomy if labels are unique and
+    /// neither empty nor enclosed in whitespace.
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn to_outline<L>(&self, indent: Indent, mut label: L) -> String
+    where
+        L: FnMut(&V) -> String,
+    {
+        let unit = indent.unit();
+        let mut outline = String::new();
+        let mut written = HashSet::new();
+
+        // Depth-first with the level of each node
+        let mut stack = self
+            .node0
+            .iter()
+            .rev()
+            .map(|node_id| (node_id.clone(), 0))
+            .collect::<Vec<(Rc<K>, usize)>>();
+        while let Some((node_id, level)) = stack.pop() {
+            let element = self._get_node_opt(node_id.clone()).unwrap().get();
+            outline.push_str(&unit.repeat(level));
+            outline.push_str(&label(element));
+            outline.push('\n');
+
+            if written.insert(node_id.clone()) {
+                stack.extend(
+                    self._sub_ids_of(Some(node_id))
+                        .into_iter()
+                        .rev()
+                        .map(|sub_id| (sub_id, level + 1)),
+                );
+            }
+        }
+
+        outline
+    }
+}
@@ -0,0 +1,160 @@
+use crate::Entry;
+use crate::Event;
+use crate::History;
+use crate::Journal;
+use crate::Node;
+use crate::Observers;
+
+use std::cell::RefCell;
+use std::fmt::{Debug, Formatter};
+use std::hash::Hash;
+use std::ops::{Deref, DerefMut};
+use std::rc::Rc;
+
+type GetFn<K, V, M, E, T> = fn(&Node<K, V, M, E>) -> &T;
+type GetMutFn<K, V, M, E, T> = fn(&mut Node<K, V, M, E>) -> &mut T;
+pub(crate) type SharedUpdates<'a, K, V, M, E> = Rc<RefCell<Updates<'a, K, V, M, E>>>;
+
+/// A mutable reference to the element or the metadata of a node.
+///
+/// `RefMut` dereferences to the element, or to the metadata if `T` is `M`. The node is recorded for
+/// [`undo`](crate::Taxonomy::undo) and [`transaction`](crate::Taxonomy::transaction) rollback right before it is
+/// mutably dereferenced for the first time, thus only reading through a `RefMut` records nothing. Once all references
+/// handed out by the same call are dropped, the nodes changed are recorded as one [`Operation`](crate::Operation)
+/// and reported as [`ElementMutated`](Event::ElementMutated) to the observers.
+///
+/// This struct is created by [`Taxonomy::get_mut`](crate::Taxonomy::get_mut),
+/// [`Taxonomy::metadata_mut`](crate::Taxonomy::metadata_mut), [`Taxonomy::traverse_mut`](crate::Taxonomy::traverse_mut)
+/// and [`Taxonomy::iter_mut`](crate::Taxonomy::iter_mut).
+pub struct RefMut<'a, K: Hash + Eq, V, M = (), E = (), T = V> {
+    node_id: Rc<K>,
+    node: &'a mut Node<K, V, M, E>,
+    get: GetFn<K, V, M, E, T>,
+    get_mut: GetMutFn<K, V, M, E, T>,
+    mutated: bool,
+    updates: SharedUpdates<'a, K, V, M, E>,
+}
+
+impl<'a, K, V, M, E, T> RefMut<'a, K, V, M, E, T>
+where
+    K: Hash + Eq,
+{
+    pub(crate) fn new(
+        node_id: Rc<K>,
+        node: &'a mut Node<K, V, M, E>,
+        get: GetFn<K, V, M, E, T>,
+        get_mut: GetMutFn<K, V, M, E, T>,
+        updates: SharedUpdates<'a, K, V, M, E>,
+    ) -> Self {
+        RefMut {
+            node_id,
+            node,
+            get,
+            get_mut,
+            mutated: false,
+            updates,
+        }
+    }
+}
+
+impl<K, V, M, E, T> Deref for RefMut<'_, K, V, M, E, T>
+where
+    K: Hash + Eq,
+{
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        (self.get)(self.node)
+    }
+}
+
+impl<K, V, M, E, T> DerefMut for RefMut<'_, K, V, M, E, T>
+where
+    K: Hash + Eq,
+{
+    fn deref_mut(&mut self) -> &mut T {
+        if !self.mutated {
+            self.mutated = true;
+            self.updates.borrow_mut().record(self.node_id.clone(), self.node);
+        }
+        (self.get_mut)(self.node)
+    }
+}
+
+impl<K, V, M, E, T> Debug for RefMut<'_, K, V, M, E, T>
+where
+    K: Hash + Eq,
+    T: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+/// Updates collects the nodes mutated through the references handed out by one call, see [`RefMut`].
+///
+/// If the history is enabled and no transaction is open, the call is recorded as an operation by a journal of its
+/// own, which is kept by the history once all references are dropped and only if a node has been mutated.
+/// Otherwise, nodes are recorded by the journal of the open transaction, if any. Events are sent once all references
+/// are dropped, or kept back by the journal of the open transaction like any other event.
+pub(crate) struct Updates<'a, K: Hash + Eq, V, M, E> {
+    entry: Option<Entry<K, V, M, E>>,
+    journals: &'a mut Vec<Journal<K, V, M, E>>,
+    history: &'a mut Option<History<K, V, M, E>>,
+    observers: &'a mut Observers<K>,
+    node_ids: Vec<Rc<K>>,
+}
+
+impl<'a, K, V, M, E> Updates<'a, K, V, M, E>
+where
+    K: Hash + Eq,
+{
+    pub(crate) fn new(
+        entry: Option<Entry<K, V, M, E>>,
+        journals: &'a mut Vec<Journal<K, V, M, E>>,
+        history: &'a mut Option<History<K, V, M, E>>,
+        observers: &'a mut Observers<K>,
+    ) -> Self {
+        Updates {
+            entry,
+            journals,
+            history,
+            observers,
+            node_ids: Vec::new(),
+        }
+    }
+
+    /// Records a node right before it is mutated for the first time.
+    fn record(&mut self, node_id: Rc<K>, node: &Node<K, V, M, E>) {
+        let own = self.entry.as_mut().map(|(_, journal)| journal);
+        if let Some(journal) = own.or(self.journals.last_mut()) {
+            journal.record(node_id.clone(), Some(node));
+        }
+        self.node_ids.push(node_id);
+    }
+}
+
+impl<K, V, M, E> Drop for Updates<'_, K, V, M, E>
+where
+    K: Hash + Eq,
+{
+    fn drop(&mut self) {
+        if let (Some((operation, journal)), Some(history)) = (self.entry.take(), self.history.as_mut()) {
+            if !self.node_ids.is_empty() {
+                history.record(operation, journal);
+            }
+        }
+
+        if !self.observers.is_empty() {
+            let events = self.node_ids.drain(..).map(Event::ElementMutated);
+            match self.journals.last_mut() {
+                Some(journal) => journal.events.extend(events),
+                None => {
+                    for event in events {
+                        self.observers.notify(&event);
+                    }
+                }
+            }
+        }
+    }
+}
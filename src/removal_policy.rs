@@ -0,0 +1,14 @@
+/// Policy deciding which descendants are removed together with a node, see [`Taxonomy::remove_subtree`](crate::Taxonomy::remove_subtree).
+///
+/// In a taxonomy allowing nodes to have more than one super-node, a descendant of a node might also be reachable
+/// without passing the node, e.g. because it is a sub-node of another node as well.
+#[derive(Eq, PartialEq, Clone, Copy, Debug, Default, Hash)]
+pub enum RemovalPolicy {
+    /// Only descendants which become unreachable from the root-nodes are removed. Descendants still reachable
+    /// via another super-node remain in the taxonomy and lose their edges to removed nodes only.
+    #[default]
+    Orphans,
+
+    /// All descendants are removed, regardless of whether they are reachable via another super-node.
+    All,
+}
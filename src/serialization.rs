@@ -0,0 +1,253 @@
+use super::Taxonomy;
+use crate::Identity;
+use crate::Node;
+use crate::TaxonomyError;
+use crate::TaxonomyError::*;
+
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use std::collections::{HashMap, HashSet, LinkedList, VecDeque};
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::rc::Rc;
+
+/// Serialized form of a taxonomy: the ids of the root-nodes in order and all nodes, depth-first and pre-order.
+#[derive(Serialize)]
+#[serde(rename = "Taxonomy")]
+struct TaxonomyRef<'a, K, V, M, E> {
+    node0: Vec<Rc<K>>,
+    nodes: Vec<NodeRef<'a, K, V, M, E>>,
+}
+
+/// Serialized form of a node: its element and metadata, the ids of its super-nodes (`None` stands for the root of
+/// the taxonomy) and of its sub-nodes in order, the designated primary super-node and the payloads of the edges to
+/// its super-nodes. `designated` tells a designated root of the taxonomy apart from no designation at all, as both
+/// have `primary` set to `None`.
+#[derive(Serialize)]
+#[serde(rename = "Node")]
+struct NodeRef<'a, K, V, M, E> {
+    element: &'a V,
+    metadata: Option<&'a M>,
+    supers: Vec<Option<Rc<K>>>,
+    designated: bool,
+    primary: Option<Rc<K>>,
+    subs: Vec<Rc<K>>,
+    payloads: Vec<(Option<Rc<K>>, &'a E)>,
+}
+
+/// Deserialized form of a taxonomy, see [`TaxonomyRef`].
+#[derive(Deserialize)]
+#[serde(rename = "Taxonomy")]
+struct TaxonomyData<K, V, M, E> {
+    node0: Vec<K>,
+    nodes: Vec<NodeData<K, V, M, E>>,
+}
+
+/// Deserialized form of a node, see [`NodeRef`].
+#[derive(Deserialize)]
+#[serde(rename = "Node")]
+struct NodeData<K, V, M, E> {
+    element: V,
+    metadata: Option<M>,
+    supers: Vec<Option<K>>,
+    designated: bool,
+    primary: Option<K>,
+    subs: Vec<K>,
+    payloads: Vec<(Option<K>, E)>,
+}
+
+/// Serializes the structure of a taxonomy: nodes with their elements, metadata and edges including payloads, in
+/// the order of root-nodes and sub-nodes. The history, observers and the settings of a taxonomy, like the
+/// reachability index or a comparator keeping it sorted, are not serialized.
+impl<K, V, M, E> Serialize for Taxonomy<K, V, M, E>
+where
+    K: Hash + Eq + Serialize,
+    V: Identity<K> + Serialize,
+    M: Serialize,
+    E: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let nodes = self
+            ._enumerate_nodes()
+            .into_iter()
+            .map(|node_id| {
+                let node = self._get_node_opt(node_id).unwrap();
+                let designated = node.designated_primary();
+                NodeRef {
+                    element: node.get(),
+                    metadata: node.metadata(),
+                    supers: node.super_ids(),
+                    designated: designated.is_some(),
+                    primary: designated.flatten(),
+                    subs: node.subs().iter().cloned().collect(),
+                    payloads: node
+                        .payloads()
+                        .iter()
+                        .map(|(super_id, payload)| (super_id.clone(), payload))
+                        .collect(),
+                }
+            })
+            .collect();
+
+        TaxonomyRef {
+            node0: self.node0.iter().cloned().collect(),
+            nodes,
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Deserializes a taxonomy serialized before and validates it, see `_from_data` for the errors
+/// reported.
+impl<'de, K, V, M, E> Deserialize<'de> for Taxonomy<K, V, M, E>
+where
+    K: Hash + Eq + Debug + Deserialize<'de>,
+    V: Identity<K> + Deserialize<'de>,
+    M: Deserialize<'de>,
+    E: Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let data = TaxonomyData::deserialize(deserializer)?;
+
+        Taxonomy::_from_data(data).map_err(D::Error::custom)
+    }
+}
+
+#[doc(hidden)]
+impl<K, V, M, E> Taxonomy<K, V, M, E>
+where
+    K: Hash + Eq,
+    V: Identity<K>,
+{
+    /// Builds a taxonomy from its deserialized form and validates it:
+    /// - [`DuplicateNode`]: two nodes have the same id.
+    /// - [`DuplicateRootNode`]: a node is listed twice as root-node.
+    /// - [`DuplicateEdge`]: a super-node is listed twice for a node or an edge has two payloads.
+    /// - [`DuplicateSubNode`]: a sub-node is listed twice for a node.
+    /// - [`NodeNotFound`]: a root-node, super-node or sub-node is not found.
+    /// - [`EdgeNotFound`]: super-nodes are not consistent with sub-nodes or root-nodes, a node is neither a root-node
+    ///   nor a sub-node, an edge has no payload or the primary super-node is not a super-node.
+    /// - [`LoopDetected`]: a node is part of a loop or reachable through a loop only.
+    // Test coverage: { unit = done, integration = done, doc = none } -> ok
+    fn _from_data(data: TaxonomyData<K, V, M, E>) -> Result<Self, TaxonomyError<K>> {
+        let mut tax = Taxonomy::new();
+        let mut order = Vec::with_capacity(data.nodes.len());
+
+        for node in data.nodes {
+            let node_id = Rc::new(node.element.id());
+            let supers = node.supers.into_iter().map(|id| id.map(Rc::new)).collect::<Vec<_>>();
+            let payloads = node
+                .payloads
+                .into_iter()
+                .map(|(id, payload)| (id.map(Rc::new), payload))
+                .collect::<Vec<_>>();
+
+            // Super-nodes and payloads are unique per edge
+            let mut super_ids = HashSet::new();
+            for super_id in supers.iter() {
+                if !super_ids.insert(super_id.clone()) {
+                    return Err(DuplicateEdge(super_id.clone(), node_id));
+                }
+            }
+            let mut payload_ids = HashSet::new();
+            for (super_id, _) in payloads.iter() {
+                if !payload_ids.insert(super_id.clone()) {
+                    return Err(DuplicateEdge(super_id.clone(), node_id));
+                }
+            }
+            if let Some(super_id) = super_ids.iter().find(|super_id| !payload_ids.contains(*super_id)) {
+                return Err(EdgeNotFound(super_id.clone(), node_id));
+            }
+            if let Some(super_id) = payload_ids.iter().find(|super_id| !super_ids.contains(*super_id)) {
+                return Err(EdgeNotFound(super_id.clone(), node_id));
+            }
+
+            // Primary super-node is one of the super-nodes, an id implies the designation
+            let primary = match (node.designated, node.primary) {
+                (false, None) => None,
+                (_, primary) => Some(primary.map(Rc::new)),
+            };
+            if let Some(primary) = primary.as_ref().filter(|primary| !super_ids.contains(*primary)) {
+                return Err(EdgeNotFound(primary.clone(), node_id));
+            }
+
+            let subs = node.subs.into_iter().map(Rc::new).collect::<LinkedList<_>>();
+            let node = Node::from_parts(node.element, supers, primary, subs, node.metadata, payloads);
+            if tax.nodes.insert(node_id.clone(), node).is_some() {
+                return Err(DuplicateNode(node_id));
+            }
+            order.push(node_id);
+        }
+
+        // Root-nodes exist and have the root of the taxonomy as super-node
+        for node_id in data.node0.into_iter().map(Rc::new) {
+            let node = tax._get_node_res(node_id.clone())?;
+            if !node.has_super_id(None) {
+                return Err(EdgeNotFound(None, node_id));
+            }
+            if tax.node0.contains(&node_id) {
+                return Err(DuplicateRootNode(node_id));
+            }
+            tax.node0.push_back(node_id);
+        }
+
+        // Super-nodes are consistent with sub-nodes and root-nodes
+        for node_id in order.iter() {
+            let node = tax._get_node_opt(node_id.clone()).unwrap();
+            if !node.has_super() {
+                return Err(EdgeNotFound(None, node_id.clone()));
+            }
+
+            for super_id in node.super_ids() {
+                let is_consistent = match super_id.clone() {
+                    None => tax.node0.contains(node_id),
+                    Some(super_id) => tax._get_node_res(super_id)?.subs().contains(node_id),
+                };
+                if !is_consistent {
+                    return Err(EdgeNotFound(super_id, node_id.clone()));
+                }
+            }
+
+            let mut sub_ids = HashSet::new();
+            for sub_id in node.subs() {
+                if !sub_ids.insert(sub_id.clone()) {
+                    return Err(DuplicateSubNode(node_id.clone(), sub_id.clone()));
+                }
+                if !tax._get_node_res(sub_id.clone())?.has_super_id(Some(node_id.clone())) {
+                    return Err(EdgeNotFound(Some(node_id.clone()), sub_id.clone()));
+                }
+            }
+        }
+
+        // Every node is reachable from a root-node without passing a loop
+        let mut pending = order
+            .iter()
+            .map(|node_id| {
+                let node = tax._get_node_opt(node_id.clone()).unwrap();
+                (node_id.clone(), node.super_ids().into_iter().flatten().count())
+            })
+            .collect::<HashMap<_, _>>();
+        let mut queue = tax
+            .node0
+            .iter()
+            .filter(|node_id| pending[*node_id] == 0)
+            .cloned()
+            .collect::<VecDeque<_>>();
+        while let Some(node_id) = queue.pop_front() {
+            pending.remove(&node_id);
+            for sub_id in tax._sub_ids_of(Some(node_id)) {
+                let count = pending.get_mut(&sub_id).unwrap();
+                *count -= 1;
+                if *count == 0 {
+                    queue.push_back(sub_id);
+                }
+            }
+        }
+        if let Some(node_id) = order.into_iter().find(|node_id| pending.contains_key(node_id)) {
+            return Err(LoopDetected(node_id));
+        }
+
+        Ok(tax)
+    }
+}
@@ -0,0 +1,761 @@
+use super::Taxonomy;
+use crate::Identity;
+use crate::ImportError;
+use crate::SkosConcept;
+use crate::TaxonomyError::*;
+
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use std::rc::Rc;
+
+const RDF_TYPE: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#type";
+const SKOS: &str = "http://www.w3.org/2004/02/skos/core#";
+
+/// Object of an RDF statement, either an IRI or the lexical form of a literal.
+#[derive(Eq, PartialEq, Clone, Debug)]
+enum Object {
+    Iri(String),
+    Literal(String),
+}
+
+/// RDF statement together with the line of the input it starts at.
+#[derive(Eq, PartialEq, Clone, Debug)]
+struct Triple {
+    line: usize,
+    subject: String,
+    predicate: String,
+    object: Object,
+}
+
+/// Statements about a subject, in order.
+type Statements = (String, Vec<(String, Object)>);
+
+impl<K, V, M, E> Taxonomy<K, V, M, E>
+where
+    K: Hash + Eq,
+    V: Identity<K>,
+{
+    /// Exports the taxonomy as SKOS concept scheme in Turtle syntax.
+    ///
+    /// `concept` maps each element to a [`SkosConcept`], i.e. to its URI and its optional label. The concept scheme
+    /// is identified by the URI `scheme`. Each node becomes a `skos:Concept` with `skos:broader` and `skos:narrower`
+    /// statements for its super-nodes and sub-nodes, root-nodes are `skos:topConceptOf` the concept scheme. Nodes
+    /// having more than one super-node get one `skos:broader` statement per super-node.
+    ///
+    /// Concepts are written depth-first and pre-order, each concept once, and sub-nodes are listed in order, thus
+    /// the output is stable and [`from_turtle`](Self::from_turtle) restores the order of root-nodes and sub-nodes.
+    /// Characters IRIs must not contain, e.g. spaces, `<`, `>` or `"`, are percent-encoded.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kodiak_taxonomy::{Identity, SkosConcept, Taxonomy, TaxonomyError};
+    ///
+    /// struct Class {
+    ///     name: String,
+    /// }
+    ///
+    /// impl Identity<String> for Class {
+    ///     fn id(&self) -> String {
+    ///         self.name.clone()
+    ///     }
+    /// }
+    ///
+    /// fn main() -> Result<(), TaxonomyError<String>> {
+    ///     let mut tax: Taxonomy<String, Class> = Taxonomy::new();
+    ///     tax.add(None, Class{name: "Device".to_string()})?
+    ///        .add(Some("Device".to_string()), Class{name: "Server".to_string()})?;
+    ///
+    ///     let turtle = tax.to_turtle("http://example.org/cmdb", |class| {
+    ///         SkosConcept::new(format!("http://example.org/cmdb/{}", class.name), Some(class.name.clone()))
+    ///     });
+    ///     assert!(turtle.contains("<http://example.org/cmdb/Server> a skos:Concept ;"));
+    ///     assert!(turtle.contains("    skos:broader <http://example.org/cmdb/Device> .\n"));
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn to_turtle<F>(&self, scheme: &str, concept: F) -> String
+    where
+        F: FnMut(&V) -> SkosConcept,
+    {
+        let mut turtle = format!("@prefix skos: <{}> .\n", SKOS);
+        for (subject, statements) in self._skos_statements(scheme, concept) {
+            turtle.push_str(&format!("\n<{}>", Self::_escape_iri(&subject)));
+            let mut last: Option<&str> = None;
+            for (predicate, object) in statements.iter() {
+                if last == Some(predicate.as_str()) {
+                    turtle.push_str(", ");
+                } else {
+                    match last {
+                        None => turtle.push(' '),
+                        Some(_) => turtle.push_str(" ;\n    "),
+                    }
+                    match predicate.as_str() {
+                        RDF_TYPE => turtle.push('a'),
+                        predicate => turtle.push_str(&Self::_turtle_iri(predicate)),
+                    }
+                    turtle.push(' ');
+                }
+                match object {
+                    Object::Iri(iri) => turtle.push_str(&Self::_turtle_iri(iri)),
+                    Object::Literal(literal) => turtle.push_str(&format!("\"{}\"", Self::_escape_literal(literal))),
+                }
+                last = Some(predicate.as_str());
+            }
+            turtle.push_str(" .\n");
+        }
+
+        turtle
+    }
+
+    /// Exports the taxonomy as SKOS concept scheme in N-Triples syntax, one statement per line.
+    ///
+    /// See [`to_turtle`](Self::to_turtle), the same statements are exported in the same order.
+    // Test coverage: { unit = done, integration = done, doc = none } -> ok
+    pub fn to_ntriples<F>(&self, scheme: &str, concept: F) -> String
+    where
+        F: FnMut(&V) -> SkosConcept,
+    {
+        let mut ntriples = String::new();
+        for (subject, statements) in self._skos_statements(scheme, concept) {
+            for (predicate, object) in statements {
+                let object = match object {
+                    Object::Iri(iri) => format!("<{}>", Self::_escape_iri(&iri)),
+                    Object::Literal(literal) => format!("\"{}\"", Self::_escape_literal(&literal)),
+                };
+                ntriples.push_str(&format!(
+                    "<{}> <{}> {} .\n",
+                    Self::_escape_iri(&subject),
+                    Self::_escape_iri(&predicate),
+                    object
+                ));
+            }
+        }
+
+        ntriples
+    }
+
+    /// Imports a taxonomy from a SKOS concept scheme in Turtle syntax.
+    ///
+    /// `element` creates the element of each concept from a [`SkosConcept`] carrying the concept's URI and its
+    /// `skos:prefLabel`, if any. Concepts are typed `skos:Concept` or referred to by `skos:broader`,
+    /// `skos:narrower`, `skos:topConceptOf` or `skos:hasTopConcept` statements. Concepts being `skos:topConceptOf`
+    /// a concept scheme and concepts without broader concept become root-nodes, each `skos:broader` or
+    /// `skos:narrower` statement becomes an edge. Other statements are ignored.
+    ///
+    /// Root-nodes and sub-nodes are ordered as they are listed first in the input, thus importing a concept
+    /// scheme exported by [`to_turtle`](Self::to_turtle) restores the order of the taxonomy.
+    ///
+    /// Prefixes, `a`, predicate and object lists as well as literals with language tag or datatype are
+    /// supported, blank nodes and collections are not.
+    ///
+    /// # Errors
+    ///
+    /// - [`ImportError::Syntax`]: the input is malformed.
+    /// - [`ImportError::Taxonomy`]: building the taxonomy failed, e.g. [`LoopDetected`] if broader concepts form a
+    ///   loop or [`DuplicateNode`] if `element` creates elements with equal ids for different concepts.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    /// use kodiak_taxonomy::{Identity, ImportError, SkosConcept, Taxonomy};
+    ///
+    /// struct Class {
+    ///     name: String,
+    /// }
+    ///
+    /// impl Identity<String> for Class {
+    ///     fn id(&self) -> String {
+    ///         self.name.clone()
+    ///     }
+    /// }
+    ///
+    /// fn main() -> Result<(), ImportError<String>> {
+    ///     let turtle = r#"
+    ///         @prefix skos: <http://www.w3.org/2004/02/skos/core#> .
+    ///         @prefix cmdb: <http://example.org/cmdb/> .
+    ///
+    ///         cmdb:Device a skos:Concept ; skos:prefLabel "Device"@en .
+    ///         cmdb:Server a skos:Concept ; skos:prefLabel "Server"@en ; skos:broader cmdb:Device .
+    ///     "#;
+    ///     let tax: Taxonomy<String, Class> = Taxonomy::from_turtle(turtle, |concept| Class {
+    ///         name: concept.label().unwrap_or(concept.uri()).to_string(),
+    ///     })?;
+    ///     assert!(tax.is_a(Rc::new("Server".to_string()), Rc::new("Device".to_string())).unwrap());
+    ///
+    ///     let err = Taxonomy::<String, Class>::from_turtle("cmdb:Device a skos:Concept .", |concept| Class {
+    ///         name: concept.uri().to_string(),
+    ///     });
+    ///     assert_eq!(err.err().map(|err| err.line()), Some(1));
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn from_turtle<F>(input: &str, element: F) -> Result<Self, ImportError<K>>
+    where
+        K: Clone,
+        E: Default,
+        F: FnMut(&SkosConcept) -> V,
+    {
+        let triples = RdfParser::new(input)
+            .parse()
+            .map_err(|(line, message)| ImportError::Syntax(line, message))?;
+
+        Self::_from_triples(triples, element)
+    }
+
+    /// Imports a taxonomy from a SKOS concept scheme in N-Triples syntax.
+    ///
+    /// See [`from_turtle`](Self::from_turtle), N-Triples is a subset of Turtle.
+    // Test coverage: { unit = done, integration = done, doc = none } -> ok
+    pub fn from_ntriples<F>(input: &str, element: F) -> Result<Self, ImportError<K>>
+    where
+        K: Clone,
+        E: Default,
+        F: FnMut(&SkosConcept) -> V,
+    {
+        Self::from_turtle(input, element)
+    }
+}
+
+#[doc(hidden)]
+impl<K, V, M, E> Taxonomy<K, V, M, E>
+where
+    K: Hash + Eq,
+    V: Identity<K>,
+{
+    /// Percent-encodes the characters an IRI must not contain to be written in Turtle or N-Triples syntax, i.e.
+    /// control characters, spaces, backticks and `<>"{}|^\`. Other characters, `%` included, are kept.
+    // Test coverage: { unit = done, integration = done, doc = n/a } -> ok
+    fn _escape_iri(iri: &str) -> String {
+        let mut escaped = String::with_capacity(iri.len());
+        for c in iri.chars() {
+            match c {
+                '\u{0}'..='\u{20}' | '<' | '>' | '"' | '{' | '}' | '|' | '^' | '`' | '\\' => {
+                    escaped.push_str(&format!("%{:02X}", c as u32))
+                }
+                c => escaped.push(c),
+            }
+        }
+
+        escaped
+    }
+
+    /// Escapes a string to be written as literal in Turtle or N-Triples syntax.
+    // Test coverage: { unit = done, integration = n/a, doc = n/a } -> ok
+    fn _escape_literal(literal: &str) -> String {
+        let mut escaped = String::with_capacity(literal.len());
+        for c in literal.chars() {
+            match c {
+                '\\' => escaped.push_str("\\\\"),
+                '"' => escaped.push_str("\\\""),
+                '\n' => escaped.push_str("\\n"),
+                '\r' => escaped.push_str("\\r"),
+                '\t' => escaped.push_str("\\t"),
+                c => escaped.push(c),
+            }
+        }
+
+        escaped
+    }
+
+    /// Builds a taxonomy from RDF statements, see [`from_turtle`](Self::from_turtle).
+    // Test coverage: { unit = done, integration = done, doc = n/a } -> ok
+    fn _from_triples<F>(triples: Vec<Triple>, mut element: F) -> Result<Self, ImportError<K>>
+    where
+        K: Clone,
+        E: Default,
+        F: FnMut(&SkosConcept) -> V,
+    {
+        let skos = |name: &str| format!("{}{}", SKOS, name);
+
+        // Concepts in order of their first appearance together with that line
+        let mut concepts: Vec<(String, usize)> = Vec::new();
+        let mut labels: HashMap<String, String> = HashMap::new();
+        let mut narrower: HashMap<String, Vec<(String, usize)>> = HashMap::new();
+        let mut broader: HashMap<String, Vec<(String, usize)>> = HashMap::new();
+        let mut supers: HashSet<String> = HashSet::new();
+        let mut roots: Vec<(String, usize)> = Vec::new();
+        let mut known: HashSet<String> = HashSet::new();
+        let mut concept = |uri: &str, line: usize| {
+            if known.insert(uri.to_string()) {
+                concepts.push((uri.to_string(), line));
+            }
+        };
+
+        for triple in triples.iter() {
+            let line = triple.line;
+            match (triple.predicate.as_str(), &triple.object) {
+                (RDF_TYPE, Object::Iri(iri)) if *iri == skos("Concept") => concept(&triple.subject, line),
+                (predicate, Object::Literal(label)) if predicate == skos("prefLabel") => {
+                    labels.entry(triple.subject.clone()).or_insert_with(|| label.clone());
+                }
+                (predicate, Object::Iri(iri)) if predicate == skos("broader") || predicate == skos("narrower") => {
+                    concept(&triple.subject, line);
+                    concept(iri, line);
+                    let (super_uri, sub_uri, edges) = match predicate == skos("narrower") {
+                        true => (&triple.subject, iri, &mut narrower),
+                        false => (iri, &triple.subject, &mut broader),
+                    };
+                    edges.entry(super_uri.clone()).or_default().push((sub_uri.clone(), line));
+                    supers.insert(sub_uri.clone());
+                }
+                (predicate, Object::Iri(_)) if predicate == skos("topConceptOf") => {
+                    concept(&triple.subject, line);
+                    roots.push((triple.subject.clone(), line));
+                }
+                (predicate, Object::Iri(iri)) if predicate == skos("hasTopConcept") => {
+                    concept(iri, line);
+                    roots.push((iri.clone(), line));
+                }
+                _ => (),
+            }
+        }
+
+        // Sub-nodes are ordered as listed by narrower statements, followed by those listed by broader statements only
+        let mut subs = narrower;
+        for (super_uri, edges) in broader {
+            subs.entry(super_uri).or_default().extend(edges);
+        }
+        for edges in subs.values_mut() {
+            let mut sub_uris = HashSet::new();
+            edges.retain(|(sub_uri, _)| sub_uris.insert(sub_uri.clone()));
+        }
+
+        // Concepts without broader concept are root-nodes, too
+        roots.extend(concepts.iter().filter(|(uri, _)| !supers.contains(uri)).cloned());
+        let mut root_uris = HashSet::new();
+        roots.retain(|(uri, _)| root_uris.insert(uri.clone()));
+
+        let mut elements = HashMap::new();
+        let mut ids = HashMap::new();
+        for (uri, _) in concepts.iter() {
+            let element = element(&SkosConcept::new(uri.clone(), labels.remove(uri)));
+            ids.insert(uri.clone(), element.id());
+            elements.insert(uri.clone(), element);
+        }
+
+        // Depth-first, thus sub-nodes are added or appended in order
+        let mut tax = Taxonomy::new();
+        let mut stack = roots
+            .into_iter()
+            .rev()
+            .map(|(uri, line)| (None, uri, line))
+            .collect::<Vec<_>>();
+        while let Some((super_uri, uri, line)) = stack.pop() {
+            let super_id = super_uri.map(|super_uri: String| ids[&super_uri].clone());
+            match elements.remove(&uri) {
+                Some(element) => {
+                    tax.add(super_id, element).map_err(|err| ImportError::Taxonomy(line, err))?;
+                    if let Some(sub_uris) = subs.get(&uri) {
+                        stack.extend(
+                            sub_uris
+                                .iter()
+                                .rev()
+                                .map(|(sub_uri, line)| (Some(uri.clone()), sub_uri.clone(), *line)),
+                        );
+                    }
+                }
+                None => {
+                    tax.append(super_id, ids[&uri].clone())
+                        .map_err(|err| ImportError::Taxonomy(line, err))?;
+                }
+            }
+        }
+
+        // Concepts not reached from a root-node are part of a loop
+        if let Some((uri, line)) = concepts.into_iter().find(|(uri, _)| elements.contains_key(uri)) {
+            return Err(ImportError::Taxonomy(line, LoopDetected(Rc::new(ids[&uri].clone()))));
+        }
+
+        Ok(tax)
+    }
+
+    /// Returns the RDF statements describing the taxonomy as SKOS concept scheme, grouped by subject.
+    // Test coverage: { unit = done, integration = n/a, doc = n/a } -> ok
+    fn _skos_statements<F>(&self, scheme: &str, mut concept: F) -> Vec<Statements>
+    where
+        F: FnMut(&V) -> SkosConcept,
+    {
+        let skos = |name: &str| format!("{}{}", SKOS, name);
+        let node_ids = self._enumerate_nodes();
+        let concepts = node_ids
+            .iter()
+            .map(|node_id| (node_id.clone(), concept(self._get_node_opt(node_id.clone()).unwrap().get())))
+            .collect::<HashMap<_, _>>();
+        let uri = |node_id: &Rc<K>| Object::Iri(concepts[node_id].uri().to_string());
+
+        let mut statements = Vec::with_capacity(node_ids.len() + 1);
+        let mut scheme_statements = vec![(RDF_TYPE.to_string(), Object::Iri(skos("ConceptScheme")))];
+        scheme_statements.extend(self.node0.iter().map(|node_id| (skos("hasTopConcept"), uri(node_id))));
+        statements.push((scheme.to_string(), scheme_statements));
+
+        for node_id in node_ids.iter() {
+            let node = self._get_node_opt(node_id.clone()).unwrap();
+            let mut node_statements = vec![(RDF_TYPE.to_string(), Object::Iri(skos("Concept")))];
+            if let Some(label) = concepts[node_id].label() {
+                node_statements.push((skos("prefLabel"), Object::Literal(label.to_string())));
+            }
+            node_statements.push((skos("inScheme"), Object::Iri(scheme.to_string())));
+            for super_id in node.super_ids() {
+                match super_id {
+                    None => node_statements.push((skos("topConceptOf"), Object::Iri(scheme.to_string()))),
+                    Some(super_id) => node_statements.push((skos("broader"), uri(&super_id))),
+                }
+            }
+            node_statements.extend(node.subs().iter().map(|sub_id| (skos("narrower"), uri(sub_id))));
+            statements.push((concepts[node_id].uri().to_string(), node_statements));
+        }
+
+        statements
+    }
+
+    /// Writes an IRI in Turtle syntax, abbreviated with prefix `skos` if it belongs to the SKOS namespace.
+    // Test coverage: { unit = done, integration = n/a, doc = n/a } -> ok
+    fn _turtle_iri(iri: &str) -> String {
+        match iri.strip_prefix(SKOS) {
+            Some(name) if !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric()) => format!("skos:{}", name),
+            _ => format!("<{}>", Self::_escape_iri(iri)),
+        }
+    }
+}
+
+/// Parser for RDF statements in Turtle syntax, supporting the subset of Turtle used to exchange SKOS concept
+/// schemes. Errors carry the line they occur at and a message.
+struct RdfParser {
+    chars: Vec<char>,
+    pos: usize,
+    line: usize,
+    base: String,
+    prefixes: HashMap<String, String>,
+}
+
+impl RdfParser {
+    /// Creates a parser for the input.
+    // Test coverage: { unit = done, integration = n/a, doc = n/a } -> ok
+    fn new(input: &str) -> Self {
+        RdfParser {
+            chars: input.chars().collect(),
+            pos: 0,
+            line: 1,
+            base: String::new(),
+            prefixes: HashMap::new(),
+        }
+    }
+
+    /// Parses all statements of the input.
+    // Test coverage: { unit = done, integration = done, doc = n/a } -> ok
+    fn parse(mut self) -> Result<Vec<Triple>, (usize, String)> {
+        let mut triples = Vec::new();
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                None => break,
+                Some('@') => {
+                    self.pos += 1;
+                    match self.word().as_str() {
+                        "prefix" => self.prefix(true)?,
+                        "base" => self.base(true)?,
+                        word => return Err(self.error(&format!("unknown directive @{}", word))),
+                    }
+                }
+                Some('P') | Some('p') | Some('B') | Some('b') if self.directive_ahead() => {
+                    match self.word().to_lowercase().as_str() {
+                        "prefix" => self.prefix(false)?,
+                        _ => self.base(false)?,
+                    }
+                }
+                Some(_) => self.statement(&mut triples)?,
+            }
+        }
+
+        Ok(triples)
+    }
+
+    /// Parses a subject followed by its predicates and objects, terminated by `.`.
+    // Test coverage: { unit = done, integration = n/a, doc = n/a } -> ok
+    fn statement(&mut self, triples: &mut Vec<Triple>) -> Result<(), (usize, String)> {
+        let line = self.line;
+        let subject = self.iri()?;
+        loop {
+            self.skip_whitespace();
+            let predicate = match self.peek() {
+                Some('a') if self.chars.get(self.pos + 1).is_none_or(|c| c.is_whitespace()) => {
+                    self.pos += 1;
+                    RDF_TYPE.to_string()
+                }
+                _ => self.iri()?,
+            };
+            loop {
+                self.skip_whitespace();
+                let object = self.object()?;
+                triples.push(Triple {
+                    line,
+                    subject: subject.clone(),
+                    predicate: predicate.clone(),
+                    object,
+                });
+                self.skip_whitespace();
+                match self.peek() {
+                    Some(',') => self.pos += 1,
+                    _ => break,
+                }
+            }
+            match self.next() {
+                Some('.') => return Ok(()),
+                Some(';') => {
+                    // Predicate lists might end with `;`
+                    self.skip_whitespace();
+                    while self.peek() == Some(';') {
+                        self.pos += 1;
+                        self.skip_whitespace();
+                    }
+                    if self.peek() == Some('.') {
+                        self.pos += 1;
+                        return Ok(());
+                    }
+                }
+                Some(c) => return Err(self.error(&format!("expected '.', ';' or ',' but found '{}'", c))),
+                None => return Err(self.error("unexpected end of input, expected '.'")),
+            }
+        }
+    }
+
+    /// Parses an object, either an IRI or a literal.
+    // Test coverage: { unit = done, integration = n/a, doc = n/a } -> ok
+    fn object(&mut self) -> Result<Object, (usize, String)> {
+        match self.peek() {
+            Some('"') => self.literal().map(Object::Literal),
+            Some('[') | Some('(') | Some('_') => Err(self.error("blank nodes and collections are not supported")),
+            Some(c) if c.is_ascii_digit() || c == '+' || c == '-' => {
+                let mut number = String::new();
+                while let Some(c) = self
+                    .peek()
+                    .filter(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+                {
+                    // A trailing `.` terminates the statement
+                    if c == '.' && !self.chars.get(self.pos + 1).is_some_and(|c| c.is_ascii_digit()) {
+                        break;
+                    }
+                    number.push(c);
+                    self.pos += 1;
+                }
+                Ok(Object::Literal(number))
+            }
+            _ => self.iri().map(Object::Iri),
+        }
+    }
+
+    /// Parses a string literal with an optional language tag or datatype, returns its lexical form.
+    // Test coverage: { unit = done, integration = n/a, doc = n/a } -> ok
+    fn literal(&mut self) -> Result<String, (usize, String)> {
+        self.pos += 1;
+        let mut literal = String::new();
+        loop {
+            match self.peek() {
+                Some('"') => {
+                    self.pos += 1;
+                    break;
+                }
+                Some('\\') => {
+                    self.pos += 1;
+                    match self.peek() {
+                        Some('n') => literal.push('\n'),
+                        Some('r') => literal.push('\r'),
+                        Some('t') => literal.push('\t'),
+                        Some(c) if matches!(c, '"' | '\'' | '\\') => literal.push(c),
+                        Some(c) => return Err(self.error(&format!("unknown escape sequence '\\{}'", c))),
+                        None => return Err(self.error("unterminated literal")),
+                    }
+                    self.pos += 1;
+                }
+                Some('\n') | None => return Err(self.error("unterminated literal")),
+                Some(c) => {
+                    literal.push(c);
+                    self.pos += 1;
+                }
+            }
+        }
+        match self.peek() {
+            Some('@') => {
+                self.pos += 1;
+                self.word();
+            }
+            Some('^') => {
+                self.pos += 1;
+                if self.next() != Some('^') {
+                    return Err(self.error("expected '^^' followed by a datatype"));
+                }
+                self.iri()?;
+            }
+            _ => (),
+        }
+
+        Ok(literal)
+    }
+
+    /// Parses an IRI, either enclosed in `<` and `>` or as prefixed name, returns the absolute IRI.
+    // Test coverage: { unit = done, integration = n/a, doc = n/a } -> ok
+    fn iri(&mut self) -> Result<String, (usize, String)> {
+        match self.peek() {
+            Some('<') => {
+                self.pos += 1;
+                let mut iri = String::new();
+                loop {
+                    match self.next() {
+                        Some('>') => break,
+                        Some(c) if c.is_whitespace() => return Err(self.error("unterminated IRI")),
+                        Some(c) => iri.push(c),
+                        None => return Err(self.error("unterminated IRI")),
+                    }
+                }
+                match iri.contains(':') {
+                    true => Ok(iri),
+                    false => Ok(format!("{}{}", self.base, iri)),
+                }
+            }
+            Some(c) if c.is_alphanumeric() || c == ':' => {
+                let prefix = self.name();
+                if self.next() != Some(':') {
+                    return Err(self.error(&format!("expected IRI but found '{}'", prefix)));
+                }
+                let name = self.name();
+                match self.prefixes.get(&prefix) {
+                    Some(namespace) => Ok(format!("{}{}", namespace, name)),
+                    None => Err(self.error(&format!("unknown prefix '{}'", prefix))),
+                }
+            }
+            Some(c) => Err(self.error(&format!("expected IRI but found '{}'", c))),
+            None => Err(self.error("unexpected end of input, expected IRI")),
+        }
+    }
+
+    /// Parses the remainder of a prefix directive, `@prefix` is terminated by `.` while `PREFIX` is not.
+    // Test coverage: { unit = done, integration = n/a, doc = n/a } -> ok
+    fn prefix(&mut self, terminated: bool) -> Result<(), (usize, String)> {
+        self.skip_whitespace();
+        let prefix = self.name();
+        if self.next() != Some(':') {
+            return Err(self.error(&format!("expected ':' after prefix '{}'", prefix)));
+        }
+        self.skip_whitespace();
+        let namespace = self.iri()?;
+        self.prefixes.insert(prefix, namespace);
+
+        self.terminate(terminated)
+    }
+
+    /// Parses the remainder of a base directive, `@base` is terminated by `.` while `BASE` is not.
+    // Test coverage: { unit = done, integration = n/a, doc = n/a } -> ok
+    fn base(&mut self, terminated: bool) -> Result<(), (usize, String)> {
+        self.skip_whitespace();
+        self.base = self.iri()?;
+
+        self.terminate(terminated)
+    }
+
+    /// Expects a terminating `.` if the directive requires one.
+    // Test coverage: { unit = done, integration = n/a, doc = n/a } -> ok
+    fn terminate(&mut self, terminated: bool) -> Result<(), (usize, String)> {
+        if terminated {
+            self.skip_whitespace();
+            if self.next() != Some('.') {
+                return Err(self.error("expected '.' after directive"));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns true if a SPARQL-style `PREFIX` or `BASE` directive starts at the current position.
+    // Test coverage: { unit = done, integration = n/a, doc = n/a } -> ok
+    fn directive_ahead(&self) -> bool {
+        ["prefix", "base"].iter().any(|directive| {
+            let end = self.pos + directive.len();
+            end < self.chars.len()
+                && self.chars[end].is_whitespace()
+                && self.chars[self.pos..end].iter().collect::<String>().to_lowercase() == *directive
+        })
+    }
+
+    /// Reads a word of alphanumeric characters and `-`.
+    // Test coverage: { unit = done, integration = n/a, doc = n/a } -> ok
+    fn word(&mut self) -> String {
+        let mut word = String::new();
+        while let Some(c) = self.peek().filter(|c| c.is_alphanumeric() || *c == '-') {
+            word.push(c);
+            self.pos += 1;
+        }
+
+        word
+    }
+
+    /// Reads the prefix or the local part of a prefixed name, a trailing `.` is not part of the name.
+    // Test coverage: { unit = done, integration = n/a, doc = n/a } -> ok
+    fn name(&mut self) -> String {
+        let mut name = String::new();
+        while let Some(c) = self
+            .peek()
+            .filter(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '.' | '%'))
+        {
+            if c == '.'
+                && !self
+                    .chars
+                    .get(self.pos + 1)
+                    .is_some_and(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '%'))
+            {
+                break;
+            }
+            name.push(c);
+            self.pos += 1;
+        }
+
+        name
+    }
+
+    /// Skips whitespace and comments, counting lines.
+    // Test coverage: { unit = done, integration = n/a, doc = n/a } -> ok
+    fn skip_whitespace(&mut self) {
+        while let Some(c) = self.peek() {
+            match c {
+                '#' => {
+                    while self.peek().is_some_and(|c| c != '\n') {
+                        self.pos += 1;
+                    }
+                }
+                c if c.is_whitespace() => {
+                    self.next();
+                }
+                _ => break,
+            }
+        }
+    }
+
+    /// Returns the current character without consuming it.
+    // Test coverage: { unit = done, integration = n/a, doc = n/a } -> ok
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    /// Consumes and returns the current character, counting lines.
+    // Test coverage: { unit = done, integration = n/a, doc = n/a } -> ok
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += 1;
+        if c == '\n' {
+            self.line += 1;
+        }
+
+        Some(c)
+    }
+
+    /// Returns an error at the current line.
+    // Test coverage: { unit = done, integration = n/a, doc = n/a } -> ok
+    fn error(&self, message: &str) -> (usize, String) {
+        (self.line, message.to_string())
+    }
+}
@@ -0,0 +1,26 @@
+/// A `SkosConcept` describes an element of a taxonomy as concept of a SKOS concept scheme, see
+/// [`Taxonomy::to_turtle`](crate::Taxonomy::to_turtle) and [`Taxonomy::from_turtle`](crate::Taxonomy::from_turtle).
+///
+/// Its URI identifies the concept, its optional label is exported as `skos:prefLabel`.
+#[derive(Eq, PartialEq, Clone, Debug, Hash)]
+pub struct SkosConcept {
+    uri: String,
+    label: Option<String>,
+}
+
+impl SkosConcept {
+    /// Creates a concept identified by an URI with an optional label.
+    pub fn new(uri: String, label: Option<String>) -> Self {
+        SkosConcept { uri, label }
+    }
+
+    /// Returns the URI of the concept.
+    pub fn uri(&self) -> &str {
+        &self.uri
+    }
+
+    /// Returns the label of the concept or `None` if it has no label.
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+}
@@ -2,15 +2,54 @@
 #[path = "tests/taxonomy/tests.rs"]
 mod tests;
 
+#[cfg(feature = "serde")]
+#[path = "serialization.rs"]
+mod serialization;
+
+#[path = "adjacency.rs"]
+mod adjacency;
+
+#[path = "diagram.rs"]
+mod diagram;
+
+#[path = "obo.rs"]
+mod obo;
+
+#[path = "outline.rs"]
+mod outline;
+
+#[path = "skos.rs"]
+mod skos;
+
+#[path = "tree.rs"]
+mod tree;
+
+use crate::Comparator;
+use crate::Event;
+use crate::History;
 use crate::Identity;
+use crate::Journal;
 use crate::Node;
+use crate::Observers;
+use crate::Operation;
+use crate::RefMut;
+use crate::SharedUpdates;
+use crate::SnapshotFn;
 use crate::TaxonomyError;
 use crate::TaxonomyError::*;
+use crate::Updates;
 
 use super::Cursor;
 use super::Edge;
+use super::Occurrence;
+use super::RemovalPolicy;
+use super::TraversalOrder;
+use super::{Edges, IntoIter, Iter, IterMut, IterUnique, Levels, Relatives, Siblings, Visits};
+use crate::iter::Direction;
 
-use std::collections::{HashMap, HashSet, LinkedList};
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet, LinkedList, VecDeque};
 use std::hash::Hash;
 use std::rc::Rc;
 
@@ -34,17 +73,27 @@ use std::rc::Rc;
 /// 3. Subordinate node (*sub-node*): node at a *lower* level of the taxonomy (relative to its super-node(s)).
 /// 4. Coordinate node (*co-node*): node at the *same* level of the taxonomy *and* sharing the same super-node.
 ///
+/// Each node might be complemented by metadata of type `M`, which is stored separately from the element.
+/// `M` defaults to `()` for taxonomies without metadata.
+/// Each edge between a super-node and a sub-node carries a payload of type `E`, see [`edge`](Self::edge).
+/// `E` defaults to `()` for taxonomies without payloads.
+///
 /// Burden:
 /// Implement the `Identity` trait for the nodes' type.
-#[derive(Eq, PartialEq, Clone, Debug)]
-pub struct Taxonomy<K: Hash + Eq, V: Identity<K>> {
-    nodes: HashMap<Rc<K>, Node<K, V>>,
+#[derive(Clone, Debug)]
+pub struct Taxonomy<K: Hash + Eq, V: Identity<K>, M = (), E = ()> {
+    nodes: HashMap<Rc<K>, Node<K, V, M, E>>,
     node0: LinkedList<Rc<K>>,
     last_updated_node: Option<Rc<K>>,
     cursor: Vec<Cursor<K>>,
+    reachability: Option<HashMap<Rc<K>, HashSet<Rc<K>>>>, // Optional index: ids of all ancestors per node
+    order: Option<Comparator<V>>,                         // Optional comparator to keep sub-nodes sorted
+    journal: Vec<Journal<K, V, M, E>>,                    // Journals of open transactions, innermost last
+    history: Option<History<K, V, M, E>>,                 // Optional journals of the latest operations to undo and redo
+    observers: Observers<K>,                              // Closures notified of changes, neither compared nor cloned
 }
 
-impl<K, V> Default for Taxonomy<K, V>
+impl<K, V, M, E> Default for Taxonomy<K, V, M, E>
 where
     K: Hash + Eq,
     V: Identity<K>,
@@ -54,7 +103,31 @@ where
     }
 }
 
-impl<K, V> Taxonomy<K, V>
+/// Taxonomies are equal if they have the same nodes, i.e. elements, metadata, edges and payloads, and the same
+/// root-nodes in order. Indexes, settings, the history and the position of [`traverse`](Taxonomy::traverse) are
+/// not compared.
+impl<K, V, M, E> PartialEq for Taxonomy<K, V, M, E>
+where
+    K: Hash + Eq,
+    V: Identity<K> + PartialEq,
+    M: PartialEq,
+    E: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.nodes == other.nodes && self.node0 == other.node0
+    }
+}
+
+impl<K, V, M, E> Eq for Taxonomy<K, V, M, E>
+where
+    K: Hash + Eq,
+    V: Identity<K> + Eq,
+    M: Eq,
+    E: Eq,
+{
+}
+
+impl<K, V, M, E> Taxonomy<K, V, M, E>
 where
     K: Hash + Eq,
     V: Identity<K>,
@@ -94,12 +167,17 @@ where
     /// let mut tax: Taxonomy<String, Class> = Taxonomy::new();
     /// ```
     // Test coverage: { unit = none, integration = missing, doc = done } -> not ok
-    pub fn new() -> Taxonomy<K, V> {
+    pub fn new() -> Taxonomy<K, V, M, E> {
         Taxonomy {
             nodes: HashMap::new(),
             node0: LinkedList::new(),
             last_updated_node: None,
             cursor: Vec::new(),
+            reachability: None,
+            order: None,
+            journal: Vec::new(),
+            history: None,
+            observers: Observers::new(),
         }
     }
 
@@ -139,6 +217,8 @@ where
 
     /// Returns a mutable reference to the element identified by `id` or `None` if id is not found.
     ///
+    /// The element is recorded for [`undo`](Self::undo) only if it is changed through the [`RefMut`] returned.
+    ///
     /// # Examples
     /// ```rust
     /// use std::rc::Rc;
@@ -172,15 +252,420 @@ where
     ///
     /// let mut element = tax.get_mut(id.clone()).unwrap();
     /// element.name = "Vertebrate".to_string();
+    /// drop(element);
     ///
     /// assert_eq!(tax.get(id).unwrap().name, "Vertebrate".to_string());
     /// ```
     // Test coverage: { unit = npne, integration = missing, doc = done } -> not ok
-    pub fn get_mut(&mut self, node_id: Rc<K>) -> Option<&mut V> {
-        match self._get_node_mut_opt(node_id) {
-            None => None,
-            Some(node) => Some(node.get_mut()),
+    pub fn get_mut(&mut self, node_id: Rc<K>) -> Option<RefMut<'_, K, V, M, E>> {
+        self._ref_mut(node_id.clone(), Operation::Update(node_id), Node::get, Node::get_mut)
+    }
+
+    /// Returns an immutable reference to the metadata of a node or `None` if the node has no metadata.
+    ///
+    /// Metadata of type `M` is stored separately from the element, so attributes like audit fields or hints
+    /// for a user interface do not have to be part of the element's type.
+    ///
+    /// # Errors
+    ///
+    /// - [`NodeNotFound`]: taxonomy does not contain the node provided by `node_id`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    /// use kodiak_taxonomy::{Identity, Taxonomy, TaxonomyError};
+    ///
+    /// struct Class {
+    ///     name: String,
+    /// }
+    ///
+    /// impl Identity<String> for Class {
+    ///     fn id(&self) -> String {
+    ///         self.name.clone()
+    ///     }
+    /// }
+    ///
+    /// struct Audit {
+    ///     created_by: String,
+    /// }
+    ///
+    /// fn main() -> Result<(), TaxonomyError<String>> {
+    ///     let mut tax: Taxonomy<String, Class, Audit> = Taxonomy::new();
+    ///     tax.add(None, Class{name: "Device".to_string()})?;
+    ///
+    ///     let device = Rc::new("Device".to_string());
+    ///     assert!(tax.metadata(device.clone())?.is_none());
+    ///
+    ///     tax.set_metadata(device.clone(), Audit{created_by: "admin".to_string()})?;
+    ///     assert_eq!(tax.metadata(device.clone())?.unwrap().created_by, "admin");
+    ///
+    ///     tax.metadata_mut(device.clone())?.unwrap().created_by = "root".to_string();
+    ///     let audit = tax.remove_metadata(device.clone())?.unwrap();
+    ///     assert_eq!(audit.created_by, "root");
+    ///     assert!(tax.metadata(device)?.is_none());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn metadata(&self, node_id: Rc<K>) -> Result<Option<&M>, TaxonomyError<K>> {
+        Ok(self._get_node_res(node_id)?.metadata())
+    }
+
+    /// Returns a mutable reference to the metadata of a node or `None` if the node has no metadata, see [`RefMut`].
+    ///
+    /// # Errors
+    ///
+    /// - [`NodeNotFound`]: taxonomy does not contain the node provided by `node_id`.
+    ///
+    /// # Examples
+    ///
+    /// See [`metadata`](Self::metadata).
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    #[allow(clippy::type_complexity)]
+    pub fn metadata_mut(&mut self, node_id: Rc<K>) -> Result<Option<RefMut<'_, K, V, M, E, M>>, TaxonomyError<K>> {
+        if self._get_node_res(node_id.clone())?.metadata().is_none() {
+            return Ok(None);
+        }
+
+        Ok(self._ref_mut(
+            node_id.clone(),
+            Operation::Update(node_id),
+            |node| node.metadata().unwrap(),
+            |node| node.metadata_mut().unwrap(),
+        ))
+    }
+
+    /// Sets the metadata of a node, replacing any metadata set before.
+    ///
+    /// # Errors
+    ///
+    /// - [`NodeNotFound`]: taxonomy does not contain the node provided by `node_id`.
+    ///
+    /// # Examples
+    ///
+    /// See [`metadata`](Self::metadata).
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn set_metadata(&mut self, node_id: Rc<K>, metadata: M) -> Result<&mut Self, TaxonomyError<K>> {
+        // Input validation: node_id
+        self._err_node_not_found(node_id.clone())?;
+
+        self._record(Operation::Update(node_id.clone()), |tax| {
+            tax._pre_update(node_id.clone());
+            tax._get_node_mut_opt(node_id.clone()).unwrap().set_metadata(metadata);
+            tax._emit(Event::ElementMutated(node_id.clone()));
+            tax._post_update(node_id);
+            Ok(())
+        })?;
+
+        Ok(self)
+    }
+
+    /// Removes the metadata of a node and returns it or `None` if the node has no metadata.
+    ///
+    /// # Errors
+    ///
+    /// - [`NodeNotFound`]: taxonomy does not contain the node provided by `node_id`.
+    ///
+    /// # Examples
+    ///
+    /// See [`metadata`](Self::metadata).
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn remove_metadata(&mut self, node_id: Rc<K>) -> Result<Option<M>, TaxonomyError<K>> {
+        // Input validation: node_id
+        self._err_node_not_found(node_id.clone())?;
+
+        self._record(Operation::Update(node_id.clone()), |tax| {
+            tax._pre_update(node_id.clone());
+            let metadata = tax._get_node_mut_opt(node_id.clone()).unwrap().remove_metadata();
+            tax._emit(Event::ElementMutated(node_id.clone()));
+            tax._post_update(node_id);
+            Ok(metadata)
+        })
+    }
+
+    /// Returns the edge between a super-node and a node, `None` stands for the root of the taxonomy.
+    /// The edge carries an immutable reference to its payload.
+    ///
+    /// # Errors
+    ///
+    /// - [`NodeNotFound`]: taxonomy does not contain either the super-node or the node provided.
+    /// - [`EdgeNotFound`]: the node is not a sub-node of the super-node or not a root-node respectively.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    /// use kodiak_taxonomy::{Identity, Taxonomy, TaxonomyError};
+    ///
+    /// struct Class {
+    ///     name: String,
+    /// }
+    ///
+    /// impl Identity<String> for Class {
+    ///     fn id(&self) -> String {
+    ///         self.name.clone()
+    ///     }
+    /// }
+    ///
+    /// fn main() -> Result<(), TaxonomyError<String>> {
+    ///     // Edges carry the confidence of a classification
+    ///     let mut tax: Taxonomy<String, Class, (), f32> = Taxonomy::new();
+    ///     tax.add_with_payload(None, Class{name: "Network Device".to_string()}, 1.0)?
+    ///        .add_with_payload(Some("Network Device".to_string()), Class{name: "Firewall".to_string()}, 0.9)?
+    ///        .add(None, Class{name: "Security Device".to_string()})?
+    ///        .append_with_payload(Some("Security Device".to_string()), "Firewall".to_string(), 0.7)?;
+    ///
+    ///     let network_device = Some(Rc::new("Network Device".to_string()));
+    ///     let security_device = Some(Rc::new("Security Device".to_string()));
+    ///     let firewall = Rc::new("Firewall".to_string());
+    ///     assert_eq!(tax.edge(network_device.clone(), firewall.clone())?.into_payload(), &0.9);
+    ///     assert_eq!(tax.edge(security_device.clone(), firewall.clone())?.into_payload(), &0.7);
+    ///
+    ///     // Payloads of edges added without payload default to `E::default()`
+    ///     assert_eq!(tax.edge(None, Rc::new("Security Device".to_string()))?.into_payload(), &0.0);
+    ///
+    ///     tax.set_edge_payload(security_device.clone(), firewall.clone(), 0.8)?;
+    ///     assert_eq!(tax.edge(security_device, firewall)?.into_payload(), &0.8);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn edge(&self, super_id: Option<Rc<K>>, node_id: Rc<K>) -> Result<Edge<K, &E>, TaxonomyError<K>> {
+        // Input validation: super_id, node_id and Edge(super_id, node_id)
+        self.index_of(super_id.clone(), node_id.clone())?;
+
+        let payload = self._payload_of(super_id.clone(), node_id.clone()).unwrap();
+
+        Ok(Edge::with_payload(super_id, node_id, payload))
+    }
+
+    /// Sets the payload of the edge between a super-node and a node, `None` stands for the root of the taxonomy.
+    ///
+    /// # Errors
+    ///
+    /// - [`NodeNotFound`]: taxonomy does not contain either the super-node or the node provided.
+    /// - [`EdgeNotFound`]: the node is not a sub-node of the super-node or not a root-node respectively.
+    ///
+    /// # Examples
+    ///
+    /// See [`edge`](Self::edge).
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn set_edge_payload(&mut self, super_id: Option<Rc<K>>, node_id: Rc<K>, payload: E) -> Result<&mut Self, TaxonomyError<K>> {
+        // Input validation: super_id, node_id and Edge(super_id, node_id)
+        self.index_of(super_id.clone(), node_id.clone())?;
+
+        self._record(Operation::UpdateEdge(super_id.clone(), node_id.clone()), |tax| {
+            tax._pre_update(node_id.clone());
+            tax._get_node_mut_opt(node_id.clone()).unwrap().set_payload(super_id, payload);
+            tax._emit(Event::ElementMutated(node_id.clone()));
+            tax._post_update(node_id);
+            Ok(())
+        })?;
+
+        Ok(self)
+    }
+
+    /// Returns an iterator over all edges of the taxonomy, each edge carrying a reference to its payload.
+    ///
+    /// Root membership is represented as an edge with `None` as super-node. The edges to the root-nodes come
+    /// first, followed by the edges to the sub-nodes of each node, nodes taken depth-first and pre-order.
+    /// Each edge is returned exactly once.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    /// use kodiak_taxonomy::{Edge, Identity, Taxonomy, TaxonomyError};
+    ///
+    /// struct Class {
+    ///     name: String,
+    /// }
+    ///
+    /// impl Identity<String> for Class {
+    ///     fn id(&self) -> String {
+    ///         self.name.clone()
+    ///     }
+    /// }
+    ///
+    /// fn main() -> Result<(), TaxonomyError<String>> {
+    ///     let mut tax: Taxonomy<String, Class> = Taxonomy::new();
+    ///     tax.add(None, Class{name: "Network Device".to_string()})?
+    ///        .add(Some("Network Device".to_string()), Class{name: "Firewall".to_string()})?
+    ///        .add(None, Class{name: "Security Device".to_string()})?
+    ///        .append(Some("Security Device".to_string()), "Firewall".to_string())?;
+    ///
+    ///     let network_device = Rc::new("Network Device".to_string());
+    ///     let security_device = Rc::new("Security Device".to_string());
+    ///     let firewall = Rc::new("Firewall".to_string());
+    ///
+    ///     let edges = tax.edges().map(|edge| (edge.super_id(), edge.node_id())).collect::<Vec<_>>();
+    ///     assert_eq!(edges, vec![
+    ///         (None, network_device.clone()),
+    ///         (None, security_device.clone()),
+    ///         (Some(network_device.clone()), firewall.clone()),
+    ///         (Some(security_device.clone()), firewall.clone()),
+    ///     ]);
+    ///
+    ///     assert_eq!(tax.edges_into(firewall.clone())?.count(), 2);
+    ///     assert_eq!(tax.edges_from(None)?.count(), 2);
+    ///
+    ///     // Edges are constructible, e.g. to remove one
+    ///     tax.remove_from(Edge::new(Some(network_device), firewall.clone()))?;
+    ///     assert_eq!(tax.edges_into(firewall)?.count(), 1);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn edges(&self) -> Edges<'_, K, V, M, E> {
+        let mut edges = self.node0.iter().map(|node_id| (None, node_id.clone())).collect::<Vec<_>>();
+
+        for super_id in self._enumerate_nodes() {
+            for node_id in self._sub_ids_of(Some(super_id.clone())) {
+                edges.push((Some(super_id.clone()), node_id));
+            }
+        }
+
+        Edges::new(self, edges)
+    }
+
+    /// Returns an iterator over the edges pointing to a node, i.e. the edges from its super-nodes.
+    ///
+    /// If the node is a root-node, the edge with `None` as super-node comes first. The other edges follow
+    /// in the order the super-nodes have been added.
+    ///
+    /// # Errors
+    ///
+    /// - [`NodeNotFound`]: taxonomy does not contain the node provided by `node_id`.
+    ///
+    /// # Examples
+    ///
+    /// See [`edges`](Self::edges).
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn edges_into(&self, node_id: Rc<K>) -> Result<Edges<'_, K, V, M, E>, TaxonomyError<K>> {
+        let node = self._get_node_res(node_id.clone())?;
+
+        let mut edges = node
+            .supers()
+            .into_iter()
+            .map(|super_id| (Some(super_id), node_id.clone()))
+            .collect::<Vec<_>>();
+        if node.is_root() {
+            edges.insert(0, (None, node_id));
+        }
+
+        Ok(Edges::new(self, edges))
+    }
+
+    /// Returns an iterator over the edges leaving a super-node, i.e. the edges to its sub-nodes in their order.
+    /// If `super_id` is `None`, the edges to the root-nodes are returned.
+    ///
+    /// # Errors
+    ///
+    /// - [`NodeNotFound`]: taxonomy does not contain the super-node provided by `super_id`.
+    ///
+    /// # Examples
+    ///
+    /// See [`edges`](Self::edges).
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn edges_from(&self, super_id: Option<Rc<K>>) -> Result<Edges<'_, K, V, M, E>, TaxonomyError<K>> {
+        // Input validation: super_id
+        if let Some(id) = super_id.clone() {
+            self._err_node_not_found(id)?;
+        }
+
+        let edges = self
+            ._sub_ids_of(super_id.clone())
+            .into_iter()
+            .map(|node_id| (super_id.clone(), node_id))
+            .collect::<Vec<_>>();
+
+        Ok(Edges::new(self, edges))
+    }
+
+    /// Returns the id of a node's primary super-node, `None` stands for the root of the taxonomy.
+    ///
+    /// Unless designated otherwise by [`set_primary_super`](Self::set_primary_super), the primary super-node is the
+    /// one the node has been added or appended to first among its current super-nodes, the root of the taxonomy
+    /// included. Thus, appending a node to another super-node or to the root does not change its primary
+    /// super-node, while removing the edge to it makes the next one primary.
+    ///
+    /// # Errors
+    ///
+    /// - [`NodeNotFound`]: taxonomy does not contain the node provided by `node_id`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    /// use kodiak_taxonomy::{Identity, Taxonomy, TaxonomyError};
+    ///
+    /// struct Class {
+    ///     name: String,
+    /// }
+    ///
+    /// impl Identity<String> for Class {
+    ///     fn id(&self) -> String {
+    ///         self.name.clone()
+    ///     }
+    /// }
+    ///
+    /// fn main() -> Result<(), TaxonomyError<String>> {
+    ///     let mut tax: Taxonomy<String, Class> = Taxonomy::new();
+    ///     tax.add(None, Class{name: "Network Device".to_string()})?
+    ///        .add(Some("Network Device".to_string()), Class{name: "Firewall".to_string()})?
+    ///        .add(None, Class{name: "Security Device".to_string()})?
+    ///        .append(Some("Security Device".to_string()), "Firewall".to_string())?;
+    ///
+    ///     let firewall = Rc::new("Firewall".to_string());
+    ///     assert_eq!(tax.primary_super(firewall.clone())?, Some(Rc::new("Network Device".to_string())));
+    ///
+    ///     tax.set_primary_super(firewall.clone(), Some(Rc::new("Security Device".to_string())))?;
+    ///     assert_eq!(tax.primary_super(firewall)?, Some(Rc::new("Security Device".to_string())));
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn primary_super(&self, node_id: Rc<K>) -> Result<Option<Rc<K>>, TaxonomyError<K>> {
+        Ok(self._get_node_res(node_id)?.primary_super())
+    }
+
+    /// Designates one of a node's super-nodes as its primary super-node, `None` designates the root of the taxonomy.
+    ///
+    /// The primary super-node is used to select one of several occurrences of a node, e.g. by
+    /// [`iter_unique`](Self::iter_unique). If the edge to the primary super-node is removed, the designation is removed too.
+    ///
+    /// # Errors
+    ///
+    /// - [`NodeNotFound`]: taxonomy does not contain the node provided by `node_id`.
+    /// - [`EdgeNotFound`]: `super_id` is not a super-node of the node.
+    ///
+    /// # Examples
+    ///
+    /// See [`primary_super`](Self::primary_super).
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn set_primary_super(&mut self, node_id: Rc<K>, super_id: Option<Rc<K>>) -> Result<&mut Self, TaxonomyError<K>> {
+        // Input validation: node_id
+        let node = self._get_node_res(node_id.clone())?;
+
+        // Input validation: Edge(super_id, node_id)
+        if !node.has_super_id(super_id.clone()) {
+            return Err(EdgeNotFound(super_id, node_id));
         }
+
+        self._record(Operation::Update(node_id.clone()), |tax| {
+            tax._pre_update(node_id.clone());
+            tax._get_node_mut_opt(node_id.clone()).unwrap().set_primary_super(super_id);
+            tax._emit(Event::ElementMutated(node_id.clone()));
+            tax._post_update(node_id);
+            Ok(())
+        })?;
+
+        Ok(self)
     }
 
     /// Returns the id of the last updated node or `None` if no node has been updated yet.
@@ -225,18 +710,41 @@ where
     /// }
     /// ```
     // Test coverage: { unit = done, integration = missing, doc = done } -> not ok
-    pub fn add(&mut self, super_id: Option<K>, element: V) -> Result<&mut Self, TaxonomyError<K>> {
+    pub fn add(&mut self, super_id: Option<K>, element: V) -> Result<&mut Self, TaxonomyError<K>>
+    where
+        E: Default,
+    {
+        self.add_with_payload(super_id, element, E::default())
+    }
+
+    /// Adds an element of type `V` to taxonomy like [`add`](Self::add) does, with a payload of type `E` attached to
+    /// the edge between the super-node, or the root of the taxonomy, and the new node.
+    ///
+    /// # Errors
+    ///
+    /// - [`DuplicateNode`]: taxonomy contains a node with the same `node_id`, see [`Identity`].
+    /// - [`NodeNotFound`]: taxonomy does not contain the super-node provided.
+    ///
+    /// # Examples
+    ///
+    /// See [`edge`](Self::edge).
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn add_with_payload(&mut self, super_id: Option<K>, element: V, payload: E) -> Result<&mut Self, TaxonomyError<K>> {
         let node_id = Rc::new(element.id());
 
         // Input validation: element
-        self._err_duplicate_node(node_id)?;
+        self._err_duplicate_node(node_id.clone())?;
 
         match super_id {
             // Element becomes a root-node
             #[rustfmt::skip]
             None => { // tarpaulin: exclude false positive from code coverage
                 let node = Node::new(element);
-                self._add_root_node(node);
+                self._record(Operation::Add(None, node_id.clone()), |tax| {
+                    tax._add_root_node(node, payload);
+                    tax._emit(Event::NodeAdded(None, node_id));
+                    Ok(())
+                })?;
             }
             // Element becomes a sub-node of existing node
             Some(super_id) => {
@@ -248,7 +756,11 @@ where
                 // Adding a new non-root-node / element to taxonomy without loop detection
                 let mut node = Node::new(element);
                 node.remove_super(None);
-                self._add_non_root_node(super_id, node);
+                self._record(Operation::Add(Some(super_id.clone()), node_id.clone()), |tax| {
+                    tax._add_non_root_node(super_id.clone(), node, payload);
+                    tax._emit(Event::NodeAdded(Some(super_id), node_id));
+                    Ok(())
+                })?;
             }
         }
 
@@ -268,7 +780,28 @@ where
     /// println!("Test");
     /// ```
     // Test coverage: { unit = done, integration = missing, doc = done } -> not ok
-    pub fn append(&mut self, super_id: Option<K>, node_id: K) -> Result<&mut Self, TaxonomyError<K>> {
+    pub fn append(&mut self, super_id: Option<K>, node_id: K) -> Result<&mut Self, TaxonomyError<K>>
+    where
+        E: Default,
+    {
+        self.append_with_payload(super_id, node_id, E::default())
+    }
+
+    /// Appends an existing node as sub-node like [`append`](Self::append) does, with a payload of type `E` attached
+    /// to the new edge.
+    ///
+    /// # Errors
+    ///
+    /// - [`DuplicateRootNode`]: node with `node_id` is already a root-node.
+    /// - [`DuplicateSubNode`]: super-node has a sub-node with the same `node_id` as the node to be appended, see [`Identity`].
+    /// - [`NodeNotFound`]: taxonomy does not contain either the super-node or the node provided.
+    /// - [`LoopDetected`]: appending the node to the taxonomy would result in a loop.
+    ///
+    /// # Examples
+    ///
+    /// See [`edge`](Self::edge).
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn append_with_payload(&mut self, super_id: Option<K>, node_id: K, payload: E) -> Result<&mut Self, TaxonomyError<K>> {
         let node_id = Rc::new(node_id);
 
         // Input validation: element
@@ -279,10 +812,14 @@ where
             #[rustfmt::skip]
             None => { // tarpaulin: exclude false positive from code coverage
                 let pos = self.node0.len();
-                match self.node0.contains(&node_id) {
-                    true => return Err(DuplicateRootNode(node_id)),
-                    false => self._append_root_at(node_id, pos),
-                };
+                if self.node0.contains(&node_id) {
+                    return Err(DuplicateRootNode(node_id));
+                }
+                self._record(Operation::Append(None, node_id.clone()), |tax| {
+                    tax._append_root_at(node_id.clone(), pos, payload);
+                    tax._emit(Event::EdgeAdded(None, node_id));
+                    Ok(())
+                })?;
             }
             // Node is appended to existing super-node
             Some(super_id) => {
@@ -297,7 +834,11 @@ where
                 // Prevent loop and append node to super-node
                 self._err_loop_detected(super_id.clone(), node_id.clone(), None)?;
                 let pos = self._get_node_opt(super_id.clone()).unwrap().count_subs();
-                self._append_at(super_id, node_id, pos);
+                self._record(Operation::Append(Some(super_id.clone()), node_id.clone()), |tax| {
+                    tax._append_at(super_id.clone(), node_id.clone(), pos, payload);
+                    tax._emit(Event::EdgeAdded(Some(super_id), node_id));
+                    Ok(())
+                })?;
             }
         }
 
@@ -322,7 +863,38 @@ where
     /// ```text
     /// ```
     // Test coverage: { unit = done, integration = missing, doc = missing } -> not ok
-    pub fn append_at(&mut self, super_id: Option<K>, node_id: K, index: usize) -> Result<&mut Self, TaxonomyError<K>> {
+    pub fn append_at(&mut self, super_id: Option<K>, node_id: K, index: usize) -> Result<&mut Self, TaxonomyError<K>>
+    where
+        E: Default,
+    {
+        self.append_at_with_payload(super_id, node_id, index, E::default())
+    }
+
+    /// Appends an existing node as sub-node at a specified index like [`append_at`](Self::append_at) does, with a
+    /// payload of type `E` attached to the new edge.
+    ///
+    /// # Errors
+    ///
+    /// - [`DuplicateRootNode`]: node with `node_id` is already a root-node.
+    /// - [`DuplicateSubNode`]: super-node has already a sub-node with the same `node_id` as the node to be appended, see [`Identity`].
+    /// - [`NodeNotFound`]: taxonomy does not contain either the super-node or the node provided.
+    /// - [`LoopDetected`]: appending the node to the taxonomy would result in a loop.
+    ///
+    /// # Panics
+    ///
+    /// Method does **not** panic. If `index` is out of bound, node is appended to the back.
+    ///
+    /// # Examples
+    ///
+    /// See [`edge`](Self::edge).
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn append_at_with_payload(
+        &mut self,
+        super_id: Option<K>,
+        node_id: K,
+        index: usize,
+        payload: E,
+    ) -> Result<&mut Self, TaxonomyError<K>> {
         let node_id = Rc::new(node_id);
 
         // Input validation: node_id
@@ -332,10 +904,14 @@ where
             // Node is appended to root nodes
             #[rustfmt::skip]
             None => { // tarpaulin: exclude false positive from code coverage
-                match self.node0.contains(&node_id) {
-                    true => return Err(DuplicateRootNode(node_id)),
-                    false => self._append_root_at(node_id, index),
-                };
+                if self.node0.contains(&node_id) {
+                    return Err(DuplicateRootNode(node_id));
+                }
+                self._record(Operation::Append(None, node_id.clone()), |tax| {
+                    tax._append_root_at(node_id.clone(), index, payload);
+                    tax._emit(Event::EdgeAdded(None, node_id));
+                    Ok(())
+                })?;
             }
             // Node is appended to existing super-node
             Some(super_id) => {
@@ -350,7 +926,11 @@ where
 
                 // Prevent loop and append node to super-node
                 self._err_loop_detected(super_id.clone(), node_id.clone(), None)?;
-                self._append_at(super_id, node_id, index);
+                self._record(Operation::Append(Some(super_id.clone()), node_id.clone()), |tax| {
+                    tax._append_at(super_id.clone(), node_id.clone(), index, payload);
+                    tax._emit(Event::EdgeAdded(Some(super_id), node_id));
+                    Ok(())
+                })?;
             }
         }
 
@@ -360,7 +940,7 @@ where
     /// Moves node from one super-node to another of to root-nodes.
     ///
     /// Supports root and non-root nodes as source and destination.
-    /// Does not support moving to another position at the same super-node.
+    /// If source and destination are the same, node is moved to another position, see [`reorder`](Self::reorder).
     ///
     /// # Errors
     ///
@@ -401,269 +981,2140 @@ where
         let from_edge = Edge::new(from_super_id, node_id.clone());
         self._err_edge_not_found(&from_edge)?;
 
+        // Moving to the same super-node only changes the position
+        if from_edge.super_id() == to_super_id {
+            self._record(Operation::Reorder(to_super_id.clone(), node_id.clone()), |tax| {
+                tax._reorder(to_super_id, node_id, index);
+                Ok(())
+            })?;
+            return Ok(self);
+        }
+
         // Input validation: Edge(to_super_id, node_id)
-        // todo: duplicate edge prevents to use move_to for moving an element to another position at the same super_node.
         let to_edge = Edge::new(to_super_id.clone(), node_id.clone());
         self._err_duplicate_edge(&to_edge)?;
 
-        match to_super_id {
-            // Node is appended to root-nodes
-            #[rustfmt::skip]
-            None => { // tarpaulin: exclude false positive from code coverage
-                self._append_root_at(node_id, index);
-            }
-            // Node is appended to existing super-node
-            Some(super_id) => {
-                // Prevent loop and append node to super-node
-                self._err_loop_detected(super_id.clone(), node_id.clone(), None)?;
-                self._append_at(super_id, node_id, index);
-            }
+        // Prevent loop
+        if let Some(super_id) = to_super_id.clone() {
+            self._err_loop_detected(super_id, node_id.clone(), None)?;
         }
 
-        self.remove_from(from_edge)?;
+        let operation = Operation::MoveTo(node_id.clone(), from_edge.super_id(), to_super_id.clone());
+        self._record(operation, |tax| {
+            // Carry the payload from the source edge along to the destination edge
+            tax._pre_update(node_id.clone());
+            let payload = tax
+                ._get_node_mut_opt(node_id.clone())
+                .unwrap()
+                .remove_payload(from_edge.super_id())
+                .unwrap();
+
+            match to_super_id.clone() {
+                // Node is appended to root-nodes
+                #[rustfmt::skip]
+                None => { // tarpaulin: exclude false positive from code coverage
+                    tax._append_root_at(node_id.clone(), index, payload);
+                }
+                // Node is appended to existing super-node
+                Some(super_id) => {
+                    tax._append_at(super_id, node_id.clone(), index, payload);
+                }
+            }
+
+            // Node has another super-node now, thus only the edge is removed
+            tax._detach(from_edge.super_id(), node_id.clone());
+            tax._emit(Event::NodeMoved(node_id, from_edge.super_id(), to_super_id));
+            Ok(())
+        })?;
 
         Ok(self)
     }
 
-    /// Removes a node from taxonomy, even it is represented in multiple places within the taxonomy.
-    ///
-    /// Refuses removal if node has sub-nodes, use remove_recursively.
+    /// Returns the index of a node among the sub-nodes of a super-node or among the root-nodes if `super_id` is `None`.
     ///
     /// # Errors
     ///
-    /// - [`NodeHasSubNode`]: node provided by `node_id` has sub-nodes. Use [`remove_recursively`](Self::remove_recursively) to remove a node with all sub-nodes.
-    /// - [`NodeNotFound`]: taxonomy does not contain the node provided by `node_id`.
+    /// - [`NodeNotFound`]: taxonomy does not contain either the super-node or the node provided.
+    /// - [`EdgeNotFound`]: the node is not a sub-node of the super-node or not a root-node respectively.
     ///
     /// # Examples
-    /// todo
-    // Test coverage: { unit = done, integration = missing, doc = missing } -> not ok
-    pub fn remove(&mut self, node_id: Rc<K>) -> Result<&mut Self, TaxonomyError<K>> {
+    ///
+    /// See [`reorder`](Self::reorder).
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn index_of(&self, super_id: Option<Rc<K>>, node_id: Rc<K>) -> Result<usize, TaxonomyError<K>> {
         // Input validation: node_id
-        let node = self._get_node_res(node_id.clone())?;
-
-        // Do not remove if node has sub-nodes
-        self._err_node_has_sub(node)?;
-
-        // Collect ids of all direct super-nodes
-        let super_ids = node.supers().iter().cloned().collect::<Vec<Rc<K>>>();
-
-        // Delete node from all super-nodes
-        for super_id in super_ids {
-            self._pre_update(super_id.clone());
-
-            let super_node = self._get_node_mut_opt(super_id.clone()).unwrap();
-            super_node.remove_sub(node_id.clone());
+        self._err_node_not_found(node_id.clone())?;
 
-            self._post_update(super_id);
+        // Input validation: super_id
+        if let Some(id) = super_id.clone() {
+            self._err_node_not_found(id)?;
         }
 
-        let node = self._get_node_res(node_id.clone())?;
-        if node.is_root() {
-            self._remove_root_node(node_id);
-        } else {
-            self._remove_non_root_node(node_id);
-        }
+        // Input validation: Edge(super_id, node_id)
+        let edge = Edge::new(super_id.clone(), node_id.clone());
+        self._err_edge_not_found(&edge)?;
 
-        Ok(self)
+        Ok(self._sub_ids_of(super_id).iter().position(|id| *id == node_id).unwrap())
     }
 
-    /// Removes a sub-node from a specified edge (a super- / sub-node relationship).
-    ///
-    /// Nodes with multiple super-nodes remain in the taxonomy. Only the specified edge
-    /// is removed.
-    /// Nodes with just one super-node are removed from the taxonomy. In case of any
-    /// sub-nodes removal is recursive.
-    /// Nodes without a super node (root nodes) are removed from the taxonomy, also
-    /// recursively in case there are sub nodes.
+    /// Moves a node to another position among the sub-nodes of the same super-node or among the root-nodes if
+    /// `super_id` is `None`. After reordering, the node is found at `index`, all other nodes keep their relative order.
     ///
     /// # Errors
     ///
-    /// - [`EdgeNotFound`]: either super-node or sub-node are not found in the taxonomy or the edge (tuple of source-super-node and node) between the two does not exist.
+    /// - [`NodeNotFound`]: taxonomy does not contain either the super-node or the node provided.
+    /// - [`EdgeNotFound`]: the node is not a sub-node of the super-node or not a root-node respectively.
+    ///
+    /// # Panics
+    ///
+    /// Method does **not** panic. If `index` is out of bound, node is moved to the back.
     ///
     /// # Examples
-    /// todo: write example
-    /// todo: write an integration test (probably fails because Edge can not be created by the user)
-    // Test coverage: { unit = done, integration = missing, doc = missing } -> not ok
-    pub fn remove_from(&mut self, edge: Edge<K>) -> Result<&mut Self, TaxonomyError<K>> {
-        self._err_edge_not_found(&edge)?;
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    /// use kodiak_taxonomy::{Identity, Taxonomy, TaxonomyError};
+    ///
+    /// struct Class {
+    ///     name: String,
+    /// }
+    ///
+    /// impl Identity<String> for Class {
+    ///     fn id(&self) -> String {
+    ///         self.name.clone()
+    ///     }
+    /// }
+    ///
+    /// fn main() -> Result<(), TaxonomyError<String>> {
+    ///     let mut tax: Taxonomy<String, Class> = Taxonomy::new();
+    ///     tax.add(None, Class{name: "Device".to_string()})?
+    ///        .add(Some("Device".to_string()), Class{name: "Firewall".to_string()})?
+    ///        .add(Some("Device".to_string()), Class{name: "Router".to_string()})?
+    ///        .add(Some("Device".to_string()), Class{name: "Switch".to_string()})?;
+    ///
+    ///     let device = Some(Rc::new("Device".to_string()));
+    ///     let switch = Rc::new("Switch".to_string());
+    ///     tax.reorder(device.clone(), switch.clone(), 1)?;
+    ///     assert_eq!(tax.index_of(device.clone(), switch.clone())?, 1);
+    ///
+    ///     let names = tax.iter().map(|class| class.name.as_str()).collect::<Vec<_>>();
+    ///     assert_eq!(names, vec!["Device", "Firewall", "Switch", "Router"]);
+    ///
+    ///     tax.move_to_front(device.clone(), switch.clone())?
+    ///        .move_down(device.clone(), Rc::new("Router".to_string()))?;
+    ///
+    ///     let names = tax.iter().map(|class| class.name.as_str()).collect::<Vec<_>>();
+    ///     assert_eq!(names, vec!["Device", "Switch", "Firewall", "Router"]);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn reorder(&mut self, super_id: Option<Rc<K>>, node_id: Rc<K>, index: usize) -> Result<&mut Self, TaxonomyError<K>> {
+        // Input validation: super_id, node_id and Edge(super_id, node_id)
+        self.index_of(super_id.clone(), node_id.clone())?;
 
-        match (edge.super_id(), edge.node_id()) {
-            // Node is a root-node because edge's super_id is None
-            (None, node_id) => {
-                // If node has other super-nodes, only this edge is removed.
-                // If node has no other super-node, the edges to its sub-nodes are removed
-                //   which might result in a recusive removal of a whole node tree from the taxonomy.
-                self._pre_update(node_id.clone());
-                let node = self._get_node_mut_opt(node_id.clone()).unwrap();
-                node.remove_super(None);
+        self._record(Operation::Reorder(super_id.clone(), node_id.clone()), |tax| {
+            tax._reorder(super_id, node_id, index);
+            Ok(())
+        })?;
 
-                // Determine index of node (which is an ex-root-node) in node0 and remove it
-                if let Some(index) = self.node0.iter().position(|root_node_id| root_node_id.clone() == node_id) {
-                    // Optimize for first and last element in self.node0
-                    if index == 0 {
-                        self.node0.pop_front();
-                    } else if index == self.node0.len() - 1 {
-                        self.node0.pop_back();
-                    } else {
-                        let mut remain = self.node0.split_off(index);
-                        remain.pop_front();
-                        self.node0.append(&mut remain);
-                    }
-                }
+        Ok(self)
+    }
 
-                // If node does not have another super-node it has to be removed
-                // from the taxonomy all together.
-                let node = self._get_node_mut_opt(node_id.clone()).unwrap();
-                if !node.has_super() {
-                    // Remove edges to sub-nodes recursively
-                    if node.has_sub() {
-                        let sub_ids = node.subs().iter().cloned().collect::<Vec<Rc<K>>>();
+    /// Swaps the positions of two nodes among the sub-nodes of the same super-node or among the root-nodes if
+    /// `super_id` is `None`.
+    ///
+    /// # Errors
+    ///
+    /// - [`NodeNotFound`]: taxonomy does not contain either the super-node or one of the nodes provided.
+    /// - [`EdgeNotFound`]: one of the nodes is not a sub-node of the super-node or not a root-node respectively.
+    ///
+    /// # Examples
+    ///
+    /// See [`reorder`](Self::reorder).
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn swap(&mut self, super_id: Option<Rc<K>>, node_id: Rc<K>, other_id: Rc<K>) -> Result<&mut Self, TaxonomyError<K>> {
+        // Input validation: super_id, node_id, other_id and edges
+        let index = self.index_of(super_id.clone(), node_id.clone())?;
+        let other_index = self.index_of(super_id.clone(), other_id.clone())?;
 
-                        for sub_id in sub_ids {
-                            self.remove_from(Edge::new(Some(node_id.clone()), sub_id.clone()))?;
-                        }
-                    }
+        if index == other_index {
+            return Ok(self);
+        }
 
-                    self.remove(node_id.clone())?;
-                }
-                self._post_update(node_id);
+        self._record(Operation::Reorder(super_id.clone(), node_id.clone()), |tax| {
+            // Move the node in back first, so the index of the node in front stays valid
+            if index < other_index {
+                tax._reorder(super_id.clone(), other_id, index);
+                tax._reorder(super_id, node_id, other_index);
+            } else {
+                tax._reorder(super_id.clone(), node_id, other_index);
+                tax._reorder(super_id, other_id, index);
             }
-            // Node is a sub-node
-            // If node has multiple super-nodes, only remove it from super-node specified in Edge.
-            // If node has no other super-node, the edges to its sub-nodes are removed
-            //   which might result in a recusive removal of a whole node tree from the taxonomy.
-            // If node has one super-node and no sub-nodes, simply remove it.
-            (Some(super_id), node_id) => {
-                // Remove node from super-node
-                self._pre_update(super_id.clone());
-                let super_node = self._get_node_mut_opt(super_id.clone()).unwrap();
-                super_node.remove_sub(node_id.clone());
-                self._post_update(super_id.clone());
-
-                // Remove super-node from node
-                self._pre_update(node_id.clone());
-                let node = self._get_node_mut_opt(node_id.clone()).unwrap();
-                node.remove_super(Some(super_id));
-
-                // If node does not have another super-node it has to be removed
-                // from the taxonomy all together.
-                if !node.has_super() {
-                    // Remove edges to sub-nodes recursively
-                    if node.has_sub() {
-                        let sub_ids = node.subs().iter().cloned().collect::<Vec<Rc<K>>>();
-
-                        for sub_id in sub_ids {
-                            self.remove_from(Edge::new(Some(node_id.clone()), sub_id.clone()))?;
-                        }
-                    }
+            Ok(())
+        })?;
 
-                    self.remove(node_id.clone())?;
-                }
+        Ok(self)
+    }
 
-                self._post_update(node_id);
-            }
+    /// Moves a node one position towards the front among the sub-nodes of its super-node or among the root-nodes
+    /// if `super_id` is `None`. Does nothing, if the node is already in front.
+    ///
+    /// # Errors
+    ///
+    /// - [`NodeNotFound`]: taxonomy does not contain either the super-node or the node provided.
+    /// - [`EdgeNotFound`]: the node is not a sub-node of the super-node or not a root-node respectively.
+    ///
+    /// # Examples
+    ///
+    /// See [`reorder`](Self::reorder).
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn move_up(&mut self, super_id: Option<Rc<K>>, node_id: Rc<K>) -> Result<&mut Self, TaxonomyError<K>> {
+        // Input validation: super_id, node_id and Edge(super_id, node_id)
+        let index = self.index_of(super_id.clone(), node_id.clone())?;
+
+        if index > 0 {
+            self._record(Operation::Reorder(super_id.clone(), node_id.clone()), |tax| {
+                tax._reorder(super_id, node_id, index - 1);
+                Ok(())
+            })?;
         }
 
         Ok(self)
     }
 
-    /// Remove node from taxonomy: recursively and completely.
-    /// - recursively: including its sub-nodes, their sub-nodes and so on.
-    /// - completely: from all its super-nodes
-    ///
-    /// Use remove_from if you want to remove the node only from one of its super-nodes.
+    /// Moves a node one position towards the back among the sub-nodes of its super-node or among the root-nodes
+    /// if `super_id` is `None`. Does nothing, if the node is already in the back.
     ///
     /// # Errors
     ///
-    /// - [`NodeNotFound`]: taxonomy does not contain the node provided by `node_id`.
+    /// - [`NodeNotFound`]: taxonomy does not contain either the super-node or the node provided.
+    /// - [`EdgeNotFound`]: the node is not a sub-node of the super-node or not a root-node respectively.
     ///
     /// # Examples
-    /// todo
-    /// ```text
-    /// ```
-    // Test coverage: { unit = missing, integration = missing, doc = missing } -> not ok
-    pub fn remove_recursively(&mut self, node_id: Rc<K>) -> Result<&mut Self, TaxonomyError<K>> {
-        let node = self._get_node_res(node_id.clone())?;
-        let subs = node.subs().iter().cloned().collect::<Vec<Rc<K>>>();
+    ///
+    /// See [`reorder`](Self::reorder).
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn move_down(&mut self, super_id: Option<Rc<K>>, node_id: Rc<K>) -> Result<&mut Self, TaxonomyError<K>> {
+        // Input validation: super_id, node_id and Edge(super_id, node_id)
+        let index = self.index_of(super_id.clone(), node_id.clone())?;
 
-        for sub in subs {
-            self.remove_recursively(sub.clone())?;
+        if index + 1 < self._sub_ids_of(super_id.clone()).len() {
+            self._record(Operation::Reorder(super_id.clone(), node_id.clone()), |tax| {
+                tax._reorder(super_id, node_id, index + 1);
+                Ok(())
+            })?;
         }
 
-        self.remove(node_id)
+        Ok(self)
     }
 
-    /// Traverses the taxonomy from first to last node, returning immutable references to its elements.
+    /// Moves a node to the front of the sub-nodes of its super-node or of the root-nodes if `super_id` is `None`.
+    ///
+    /// # Errors
+    ///
+    /// - [`NodeNotFound`]: taxonomy does not contain either the super-node or the node provided.
+    /// - [`EdgeNotFound`]: the node is not a sub-node of the super-node or not a root-node respectively.
     ///
     /// # Examples
-    /// todo
-    /// ```text
-    // Test coverage: { unit = done, integration = missing, doc = missing } -> not ok
-    pub fn traverse(&mut self) -> Option<&V> {
-        if let Some(node_id) = self._next() {
-            return self.get(node_id);
-        }
-        None
+    ///
+    /// See [`reorder`](Self::reorder).
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn move_to_front(&mut self, super_id: Option<Rc<K>>, node_id: Rc<K>) -> Result<&mut Self, TaxonomyError<K>> {
+        self.reorder(super_id, node_id, 0)
     }
 
-    /// Traverses the taxonomy from first to last node, returning mutable references to its elements.
+    /// Moves a node to the back of the sub-nodes of its super-node or of the root-nodes if `super_id` is `None`.
+    ///
+    /// # Errors
+    ///
+    /// - [`NodeNotFound`]: taxonomy does not contain either the super-node or the node provided.
+    /// - [`EdgeNotFound`]: the node is not a sub-node of the super-node or not a root-node respectively.
     ///
     /// # Examples
-    /// todo
-    /// ```text
-    // Test coverage: { unit = done, integration = missing, doc = missing } -> not ok
-    pub fn traverse_mut(&mut self) -> Option<&mut V> {
-        if let Some(node_id) = self._next() {
-            return self.get_mut(node_id);
-        }
-        None
+    ///
+    /// See [`reorder`](Self::reorder).
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn move_to_back(&mut self, super_id: Option<Rc<K>>, node_id: Rc<K>) -> Result<&mut Self, TaxonomyError<K>> {
+        self.reorder(super_id, node_id, usize::MAX)
     }
-}
 
-//
-// Private functions
-//
-#[doc(hidden)]
-impl<K, V> Taxonomy<K, V>
-where
-    K: Hash + Eq,
-    V: Identity<K>,
-{
-    /// Adds a non-root-node to the taxonomy.
-    // Test coverage: { unit = done, integration = none, doc = none } -> ok
-    fn _add_non_root_node(&mut self, super_id: Rc<K>, node: Node<K, V>) -> &mut Self {
-        let node_id = node.id();
+    /// Sorts the sub-nodes of a super-node or the root-nodes if `super_id` is `None`, using a comparator over elements.
+    ///
+    /// The sort is stable, sub-nodes comparing equal keep their relative order. Only the sub-nodes of the super-node
+    /// are sorted, see [`sort_all_by`](Self::sort_all_by) to sort the whole taxonomy.
+    ///
+    /// # Errors
+    ///
+    /// - [`NodeNotFound`]: taxonomy does not contain the super-node provided by `super_id`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    /// use kodiak_taxonomy::{Identity, Taxonomy, TaxonomyError};
+    ///
+    /// struct Class {
+    ///     name: String,
+    /// }
+    ///
+    /// impl Identity<String> for Class {
+    ///     fn id(&self) -> String {
+    ///         self.name.clone()
+    ///     }
+    /// }
+    ///
+    /// fn main() -> Result<(), TaxonomyError<String>> {
+    ///     let mut tax: Taxonomy<String, Class> = Taxonomy::new();
+    ///     tax.add(None, Class{name: "Device".to_string()})?
+    ///        .add(Some("Device".to_string()), Class{name: "Switch".to_string()})?
+    ///        .add(Some("Device".to_string()), Class{name: "Firewall".to_string()})?
+    ///        .add(Some("Device".to_string()), Class{name: "Router".to_string()})?;
+    ///
+    ///     tax.sort_subs_by(Some(Rc::new("Device".to_string())), |a, b| a.name.cmp(&b.name))?;
+    ///
+    ///     let names = tax.iter().map(|class| class.name.as_str()).collect::<Vec<_>>();
+    ///     assert_eq!(names, vec!["Device", "Firewall", "Router", "Switch"]);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn sort_subs_by<F>(&mut self, super_id: Option<Rc<K>>, mut cmp: F) -> Result<&mut Self, TaxonomyError<K>>
+    where
+        F: FnMut(&V, &V) -> Ordering,
+    {
+        // Input validation: super_id
+        if let Some(id) = super_id.clone() {
+            self._err_node_not_found(id)?;
+        }
 
-        self._pre_update(node_id.clone());
+        self._record(Operation::Sort(super_id.clone()), |tax| {
+            tax._sort_subs_by(super_id, &mut cmp);
+            Ok(())
+        })?;
 
-        // Add node to taxonomy
-        self.nodes.insert(node_id.clone(), node);
+        Ok(self)
+    }
 
-        // Append node to super-node as the last node of all sub-nodes
-        let index = self._get_node_opt(super_id.clone()).unwrap().count_subs();
-        self._append_at(super_id, node_id.clone(), index);
+    /// Sorts the root-nodes and the sub-nodes of every node, using a comparator over elements.
+    ///
+    /// The sort is stable, nodes comparing equal keep their relative order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kodiak_taxonomy::{Identity, Taxonomy, TaxonomyError};
+    ///
+    /// struct Class {
+    ///     name: String,
+    /// }
+    ///
+    /// impl Identity<String> for Class {
+    ///     fn id(&self) -> String {
+    ///         self.name.clone()
+    ///     }
+    /// }
+    ///
+    /// fn main() -> Result<(), TaxonomyError<String>> {
+    ///     let mut tax: Taxonomy<String, Class> = Taxonomy::new();
+    ///     tax.add(None, Class{name: "Organisation".to_string()})?
+    ///        .add(None, Class{name: "Device".to_string()})?
+    ///        .add(Some("Device".to_string()), Class{name: "Switch".to_string()})?
+    ///        .add(Some("Device".to_string()), Class{name: "Firewall".to_string()})?;
+    ///
+    ///     tax.sort_all_by(|a, b| a.name.cmp(&b.name));
+    ///
+    ///     let names = tax.iter().map(|class| class.name.as_str()).collect::<Vec<_>>();
+    ///     assert_eq!(names, vec!["Device", "Firewall", "Switch", "Organisation"]);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn sort_all_by<F>(&mut self, mut cmp: F) -> &mut Self
+    where
+        F: FnMut(&V, &V) -> Ordering,
+    {
+        let super_ids = self
+            .nodes
+            .iter()
+            .filter(|(_, node)| node.has_sub())
+            .map(|(id, _)| id.clone())
+            .collect::<Vec<_>>();
 
-        self._post_update(node_id);
+        // Sorting never fails
+        let _ = self._record(Operation::SortAll, |tax| {
+            tax._sort_subs_by(None, &mut cmp);
+            for super_id in super_ids {
+                tax._sort_subs_by(Some(super_id), &mut cmp);
+            }
+            Ok(())
+        });
 
         self
     }
 
-    /// Adds a root-node to the taxonomy.
-    // Test coverage: { unit = done, integration = none, doc = none } -> ok
-    fn _add_root_node(&mut self, node: Node<K, V>) -> &mut Self {
-        let node_id = node.id();
-
+    /// Sorts the whole taxonomy, see [`sort_all_by`](Self::sort_all_by), and keeps it sorted from now on.
+    ///
+    /// While enabled, [`add`](Self::add), [`append`](Self::append), [`append_at`](Self::append_at) and
+    /// [`move_to`](Self::move_to) insert a node at the position the comparator gives instead of at the end or at
+    /// the index provided. Nodes comparing equal keep their order of insertion.
+    ///
+    /// Reordering functions like [`reorder`](Self::reorder) are still applied, as is mutating elements by
+    /// [`get_mut`](Self::get_mut). Both might leave the taxonomy unsorted until [`sort_all_by`](Self::sort_all_by)
+    /// is called or sorting is enabled again.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kodiak_taxonomy::{Identity, Taxonomy, TaxonomyError};
+    ///
+    /// struct Class {
+    ///     name: String,
+    /// }
+    ///
+    /// impl Identity<String> for Class {
+    ///     fn id(&self) -> String {
+    ///         self.name.clone()
+    ///     }
+    /// }
+    ///
+    /// fn main() -> Result<(), TaxonomyError<String>> {
+    ///     let mut tax: Taxonomy<String, Class> = Taxonomy::new();
+    ///     tax.enable_keep_sorted(|a: &Class, b: &Class| a.name.cmp(&b.name));
+    ///
+    ///     tax.add(None, Class{name: "Device".to_string()})?
+    ///        .add(Some("Device".to_string()), Class{name: "Switch".to_string()})?
+    ///        .add(Some("Device".to_string()), Class{name: "Firewall".to_string()})?
+    ///        .add(Some("Device".to_string()), Class{name: "Router".to_string()})?;
+    ///
+    ///     let names = tax.iter().map(|class| class.name.as_str()).collect::<Vec<_>>();
+    ///     assert_eq!(names, vec!["Device", "Firewall", "Router", "Switch"]);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn enable_keep_sorted<F>(&mut self, cmp: F) -> &mut Self
+    where
+        F: Fn(&V, &V) -> Ordering + 'static,
+    {
+        let comparator = Comparator::new(cmp);
+        let _ = self._record(Operation::KeepSorted(true), |tax| {
+            tax.sort_all_by(|a, b| comparator.compare(a, b));
+            tax.order = Some(comparator);
+            Ok(())
+        });
+
+        self
+    }
+
+    /// Stops keeping the taxonomy sorted, see [`enable_keep_sorted`](Self::enable_keep_sorted).
+    /// The current order of nodes is not changed.
+    // Test coverage: { unit = done, integration = none, doc = none } -> ok
+    pub fn disable_keep_sorted(&mut self) -> &mut Self {
+        let _ = self._record(Operation::KeepSorted(false), |tax| {
+            tax.order = None;
+            Ok(())
+        });
+        self
+    }
+
+    /// Returns true if the taxonomy is kept sorted, see [`enable_keep_sorted`](Self::enable_keep_sorted).
+    // Test coverage: { unit = done, integration = none, doc = none } -> ok
+    pub fn has_keep_sorted(&self) -> bool {
+        self.order.is_some()
+    }
+
+    /// Removes a node from taxonomy, even it is represented in multiple places within the taxonomy.
+    ///
+    /// Refuses removal if node has sub-nodes, use remove_recursively.
+    ///
+    /// # Errors
+    ///
+    /// - [`NodeHasSubNode`]: node provided by `node_id` has sub-nodes. Use [`remove_recursively`](Self::remove_recursively) to remove a node with all sub-nodes.
+    /// - [`NodeNotFound`]: taxonomy does not contain the node provided by `node_id`.
+    ///
+    /// # Examples
+    /// todo
+    // Test coverage: { unit = done, integration = missing, doc = missing } -> not ok
+    pub fn remove(&mut self, node_id: Rc<K>) -> Result<&mut Self, TaxonomyError<K>> {
+        self.take(node_id)?;
+
+        Ok(self)
+    }
+
+    /// Removes a node from taxonomy like [`remove`](Self::remove) does, but hands the element back to the caller.
+    ///
+    /// Refuses removal if node has sub-nodes, use [`take_subtree`](Self::take_subtree).
+    ///
+    /// # Errors
+    ///
+    /// - [`NodeHasSubNode`]: node provided by `node_id` has sub-nodes.
+    /// - [`NodeNotFound`]: taxonomy does not contain the node provided by `node_id`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    /// use kodiak_taxonomy::{Identity, Taxonomy, TaxonomyError};
+    ///
+    /// struct Class {
+    ///     name: String,
+    /// }
+    ///
+    /// impl Identity<String> for Class {
+    ///     fn id(&self) -> String {
+    ///         self.name.clone()
+    ///     }
+    /// }
+    ///
+    /// fn main() -> Result<(), TaxonomyError<String>> {
+    ///     let mut tax: Taxonomy<String, Class> = Taxonomy::new();
+    ///     tax.add(None, Class{name: "Device".to_string()})?
+    ///        .add(Some("Device".to_string()), Class{name: "Firewall".to_string()})?;
+    ///
+    ///     let firewall = tax.take(Rc::new("Firewall".to_string()))?;
+    ///     assert_eq!(firewall.name, "Firewall");
+    ///     assert!(tax.get(Rc::new("Firewall".to_string())).is_none());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn take(&mut self, node_id: Rc<K>) -> Result<V, TaxonomyError<K>> {
+        // Input validation: node_id
+        let node = self._get_node_res(node_id.clone())?;
+
+        // Do not remove if node has sub-nodes
+        self._err_node_has_sub(node)?;
+
+        // Collect ids of all direct super-nodes
+        let super_ids = node.supers().iter().cloned().collect::<Vec<Rc<K>>>();
+
+        let node = self._record(Operation::Remove(node_id.clone()), |tax| {
+            // Delete node from all super-nodes
+            for super_id in super_ids {
+                tax._pre_update(super_id.clone());
+
+                let super_node = tax._get_node_mut_opt(super_id.clone()).unwrap();
+                super_node.remove_sub(node_id.clone());
+
+                tax._post_update(super_id);
+            }
+
+            let node = tax._get_node_res(node_id.clone())?;
+            let node = if node.is_root() {
+                tax._remove_root_node(node_id.clone())
+            } else {
+                tax._remove_non_root_node(node_id.clone())
+            };
+            tax._emit(Event::NodeRemoved(node_id));
+
+            Ok(node.unwrap())
+        })?;
+
+        Ok(node.into_element())
+    }
+
+    /// Removes a sub-node from a specified edge (a super- / sub-node relationship).
+    ///
+    /// Nodes with multiple super-nodes remain in the taxonomy. Only the specified edge
+    /// is removed.
+    /// Nodes with just one super-node are removed from the taxonomy. In case of any
+    /// sub-nodes removal is recursive.
+    /// Nodes without a super node (root nodes) are removed from the taxonomy, also
+    /// recursively in case there are sub nodes.
+    ///
+    /// # Errors
+    ///
+    /// - [`EdgeNotFound`]: either super-node or sub-node are not found in the taxonomy or the edge (tuple of source-super-node and node) between the two does not exist.
+    ///
+    /// The payload of `edge` is ignored, thus edges returned by [`edges`](Self::edges) may be passed as well.
+    ///
+    /// # Examples
+    ///
+    /// See [`edges`](Self::edges).
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn remove_from<P>(&mut self, edge: Edge<K, P>) -> Result<&mut Self, TaxonomyError<K>> {
+        self._err_edge_not_found(&edge)?;
+
+        self._record(Operation::RemoveFrom(edge.super_id(), edge.node_id()), |tax| {
+            match (edge.super_id(), edge.node_id()) {
+                // Node is a root-node because edge's super_id is None
+                (None, node_id) => {
+                    // If node has other super-nodes, only this edge is removed.
+                    // If node has no other super-node, the edges to its sub-nodes are removed
+                    //   which might result in a recusive removal of a whole node tree from the taxonomy.
+                    tax._pre_update(node_id.clone());
+                    let node = tax._get_node_mut_opt(node_id.clone()).unwrap();
+                    node.remove_super(None);
+
+                    // Determine index of node (which is an ex-root-node) in node0 and remove it
+                    if let Some(index) = tax.node0.iter().position(|root_node_id| root_node_id.clone() == node_id) {
+                        // Optimize for first and last element in tax.node0
+                        if index == 0 {
+                            tax.node0.pop_front();
+                        } else if index == tax.node0.len() - 1 {
+                            tax.node0.pop_back();
+                        } else {
+                            let mut remain = tax.node0.split_off(index);
+                            remain.pop_front();
+                            tax.node0.append(&mut remain);
+                        }
+                    }
+
+                    // If node does not have another super-node it has to be removed
+                    // from the taxonomy all together.
+                    let node = tax._get_node_mut_opt(node_id.clone()).unwrap();
+                    if !node.has_super() {
+                        // Remove edges to sub-nodes recursively
+                        if node.has_sub() {
+                            let sub_ids = node.subs().iter().cloned().collect::<Vec<Rc<K>>>();
+
+                            for sub_id in sub_ids {
+                                tax.remove_from(Edge::new(Some(node_id.clone()), sub_id.clone()))?;
+                            }
+                        }
+
+                        tax.remove(node_id.clone())?;
+                    } else {
+                        tax._emit(Event::EdgeRemoved(None, node_id.clone()));
+                    }
+                    tax._post_update(node_id);
+                }
+                // Node is a sub-node
+                // If node has multiple super-nodes, only remove it from super-node specified in Edge.
+                // If node has no other super-node, the edges to its sub-nodes are removed
+                //   which might result in a recusive removal of a whole node tree from the taxonomy.
+                // If node has one super-node and no sub-nodes, simply remove it.
+                (Some(super_id), node_id) => {
+                    // Remove node from super-node
+                    tax._pre_update(super_id.clone());
+                    let super_node = tax._get_node_mut_opt(super_id.clone()).unwrap();
+                    super_node.remove_sub(node_id.clone());
+                    tax._post_update(super_id.clone());
+
+                    // Remove super-node from node
+                    tax._pre_update(node_id.clone());
+                    let node = tax._get_node_mut_opt(node_id.clone()).unwrap();
+                    node.remove_super(Some(super_id.clone()));
+
+                    // If node does not have another super-node it has to be removed
+                    // from the taxonomy all together.
+                    if !node.has_super() {
+                        // Remove edges to sub-nodes recursively
+                        if node.has_sub() {
+                            let sub_ids = node.subs().iter().cloned().collect::<Vec<Rc<K>>>();
+
+                            for sub_id in sub_ids {
+                                tax.remove_from(Edge::new(Some(node_id.clone()), sub_id.clone()))?;
+                            }
+                        }
+
+                        tax.remove(node_id.clone())?;
+                    } else {
+                        tax._reindex(node_id.clone());
+                        tax._emit(Event::EdgeRemoved(Some(super_id), node_id.clone()));
+                    }
+
+                    tax._post_update(node_id);
+                }
+            }
+
+            Ok(())
+        })?;
+
+        Ok(self)
+    }
+
+    /// Remove node from taxonomy: recursively and completely.
+    /// - recursively: including its sub-nodes, their sub-nodes and so on.
+    /// - completely: from all its super-nodes
+    ///
+    /// Use remove_from if you want to remove the node only from one of its super-nodes.
+    /// Same as [`remove_subtree`](Self::remove_subtree) with [`RemovalPolicy::All`].
+    ///
+    /// # Errors
+    ///
+    /// - [`NodeNotFound`]: taxonomy does not contain the node provided by `node_id`.
+    ///
+    /// # Examples
+    ///
+    /// See [`remove_subtree`](Self::remove_subtree).
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn remove_recursively(&mut self, node_id: Rc<K>) -> Result<&mut Self, TaxonomyError<K>> {
+        self.remove_subtree(node_id, RemovalPolicy::All)
+    }
+
+    /// Removes a node from all its super-nodes together with its descendants as decided by `policy`:
+    /// - [`RemovalPolicy::Orphans`]: only descendants which are not reachable from a root-node anymore are removed.
+    /// - [`RemovalPolicy::All`]: all descendants are removed.
+    ///
+    /// Nodes to remove are determined before the taxonomy is changed, thus either the whole removal succeeds or
+    /// the taxonomy remains unchanged.
+    ///
+    /// # Errors
+    ///
+    /// - [`NodeNotFound`]: taxonomy does not contain the node provided by `node_id`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    /// use kodiak_taxonomy::{Identity, RemovalPolicy, Taxonomy, TaxonomyError};
+    ///
+    /// #[derive(Clone)]
+    /// struct Class {
+    ///     name: String,
+    /// }
+    ///
+    /// impl Identity<String> for Class {
+    ///     fn id(&self) -> String {
+    ///         self.name.clone()
+    ///     }
+    /// }
+    ///
+    /// fn main() -> Result<(), TaxonomyError<String>> {
+    ///     let mut tax: Taxonomy<String, Class> = Taxonomy::new();
+    ///     tax.add(None, Class{name: "Device".to_string()})?
+    ///        .add(Some("Device".to_string()), Class{name: "Network Device".to_string()})?
+    ///        .add(Some("Network Device".to_string()), Class{name: "Router".to_string()})?
+    ///        .add(None, Class{name: "Security Device".to_string()})?
+    ///        .add(Some("Network Device".to_string()), Class{name: "Firewall".to_string()})?
+    ///        .append(Some("Security Device".to_string()), "Firewall".to_string())?;
+    ///
+    ///     // Firewall is still reachable via Security Device
+    ///     let mut orphans = tax.clone();
+    ///     orphans.remove_subtree(Rc::new("Network Device".to_string()), RemovalPolicy::Orphans)?;
+    ///     let names = orphans.iter().map(|class| class.name.as_str()).collect::<Vec<&str>>();
+    ///     assert_eq!(names, vec!["Device", "Security Device", "Firewall"]);
+    ///
+    ///     tax.remove_subtree(Rc::new("Network Device".to_string()), RemovalPolicy::All)?;
+    ///     let names = tax.iter().map(|class| class.name.as_str()).collect::<Vec<&str>>();
+    ///     assert_eq!(names, vec!["Device", "Security Device"]);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn remove_subtree(&mut self, node_id: Rc<K>, policy: RemovalPolicy) -> Result<&mut Self, TaxonomyError<K>> {
+        // Input validation: node_id
+        self._err_node_not_found(node_id.clone())?;
+
+        let node_ids = self._removal_set(node_id.clone(), policy);
+        self._record(Operation::RemoveSubtree(node_id), |tax| {
+            tax._remove_nodes(&node_ids);
+            Ok(())
+        })?;
+
+        Ok(self)
+    }
+
+    /// Removes a node from taxonomy recursively and completely like [`remove_recursively`](Self::remove_recursively)
+    /// does, but hands the removed nodes back to the caller as a taxonomy of their own.
+    ///
+    /// The node becomes the only root-node of the returned taxonomy, its root edge carries the payload of the edge
+    /// to its primary super-node. Edges between removed nodes are kept including their order, payloads and the nodes'
+    /// metadata. Edges from nodes remaining in the taxonomy are dropped.
+    ///
+    /// # Errors
+    ///
+    /// - [`NodeNotFound`]: taxonomy does not contain the node provided by `node_id`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    /// use kodiak_taxonomy::{Identity, Taxonomy, TaxonomyError};
+    ///
+    /// struct Class {
+    ///     name: String,
+    /// }
+    ///
+    /// impl Identity<String> for Class {
+    ///     fn id(&self) -> String {
+    ///         self.name.clone()
+    ///     }
+    /// }
+    ///
+    /// fn main() -> Result<(), TaxonomyError<String>> {
+    ///     let mut tax: Taxonomy<String, Class> = Taxonomy::new();
+    ///     tax.add(None, Class{name: "Device".to_string()})?
+    ///        .add(Some("Device".to_string()), Class{name: "Network Device".to_string()})?
+    ///        .add(Some("Network Device".to_string()), Class{name: "Switch".to_string()})?
+    ///        .add(Some("Network Device".to_string()), Class{name: "Router".to_string()})?;
+    ///
+    ///     let subtree = tax.take_subtree(Rc::new("Network Device".to_string()))?;
+    ///     assert_eq!(tax.iter().count(), 1);
+    ///
+    ///     let names = subtree.iter().map(|class| class.name.as_str()).collect::<Vec<&str>>();
+    ///     assert_eq!(names, vec!["Network Device", "Switch", "Router"]);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn take_subtree(&mut self, node_id: Rc<K>) -> Result<Taxonomy<K, V, M, E>, TaxonomyError<K>> {
+        // Input validation: node_id
+        let node = self._get_node_res(node_id.clone())?;
+        let primary_super = node.primary_super();
+
+        let node_ids = self._removal_set(node_id.clone(), RemovalPolicy::All);
+        let nodes = self._record(Operation::RemoveSubtree(node_id.clone()), |tax| {
+            Ok(tax._remove_nodes(&node_ids))
+        })?;
+        let node_ids = node_ids.into_iter().collect::<HashSet<Rc<K>>>();
+
+        let mut subtree = Taxonomy::new();
+        subtree.order = self.order.clone();
+
+        for (id, mut node) in nodes {
+            // Keep the payload of the edge to the primary super-node for the new root-node
+            let payload = match id == node_id {
+                true => node.remove_payload(primary_super.clone()),
+                false => None,
+            };
+
+            // Drop edges from super-nodes remaining in the taxonomy
+            node.remove_super(None);
+            for super_id in node.supers() {
+                if !node_ids.contains(&super_id) {
+                    node.remove_super(Some(super_id));
+                }
+            }
+
+            if let Some(payload) = payload {
+                node.add_super(None);
+                node.set_payload(None, payload);
+            }
+
+            subtree.nodes.insert(id.clone(), node);
+        }
+
+        subtree.node0.push_back(node_id.clone());
+        subtree.last_updated_node = Some(node_id);
+        if self.reachability.is_some() {
+            subtree.enable_reachability_index();
+        }
+
+        Ok(subtree)
+    }
+
+    /// Runs `f` as a transaction: either all changes made by `f` are applied or none.
+    ///
+    /// If `f` returns `Err`, the taxonomy is rolled back to its state before the transaction and the error is
+    /// returned. Transactions might be nested, rolling back a nested transaction keeps the changes made by the
+    /// enclosing transaction so far. Nodes are recorded right before they are updated for the first time, so the
+    /// overhead depends on the number of nodes changed, not on the size of the taxonomy.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error returned by `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    /// use kodiak_taxonomy::{Identity, Taxonomy, TaxonomyError};
+    ///
+    /// #[derive(Clone)]
+    /// struct Class {
+    ///     name: String,
+    /// }
+    ///
+    /// impl Identity<String> for Class {
+    ///     fn id(&self) -> String {
+    ///         self.name.clone()
+    ///     }
+    /// }
+    ///
+    /// fn main() -> Result<(), TaxonomyError<String>> {
+    ///     let mut tax: Taxonomy<String, Class> = Taxonomy::new();
+    ///     tax.add(None, Class{name: "Device".to_string()})?;
+    ///
+    ///     // Second step fails as Server does not exist, thus Router is not added either
+    ///     let result = tax.transaction(|tx| {
+    ///         tx.add(Some("Device".to_string()), Class{name: "Router".to_string()})?
+    ///           .append(Some("Server".to_string()), "Router".to_string())?;
+    ///         Ok(())
+    ///     });
+    ///     assert_eq!(result, Err(TaxonomyError::NodeNotFound(Rc::new("Server".to_string()))));
+    ///     assert!(tax.get(Rc::new("Router".to_string())).is_none());
+    ///
+    ///     tax.transaction(|tx| {
+    ///         tx.add(Some("Device".to_string()), Class{name: "Router".to_string()})?
+    ///           .add(Some("Device".to_string()), Class{name: "Server".to_string()})?;
+    ///         Ok::<(), TaxonomyError<String>>(())
+    ///     })?;
+    ///     assert_eq!(tax.iter().count(), 3);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn transaction<F, T, Er>(&mut self, f: F) -> Result<T, Er>
+    where
+        F: FnOnce(&mut Self) -> Result<T, Er>,
+        V: Clone,
+        M: Clone,
+        E: Clone,
+    {
+        self.journal.push(self._journal(Node::snapshot));
+
+        let result = f(self);
+
+        let mut journal = self.journal.pop().unwrap();
+        if result.is_err() {
+            self._rollback(journal);
+        } else if let Some(enclosing) = self.journal.last_mut() {
+            // Changes of a nested transaction are rolled back together with the enclosing transaction
+            enclosing.merge(journal);
+        } else {
+            let events = std::mem::take(&mut journal.events);
+
+            // Changes of the outermost transaction are undone as one operation
+            if let Some(history) = self.history.as_mut() {
+                history.record(Operation::Transaction, journal);
+            }
+            self._notify(events);
+        }
+
+        result
+    }
+
+    /// Enables the history of operations, keeping the latest `capacity` operations to [`undo`](Self::undo) and
+    /// [`redo`](Self::redo). Operations recorded before are discarded.
+    ///
+    /// Each call of a public function changing the taxonomy is recorded as one [`Operation`], a
+    /// [`transaction`](Self::transaction) is recorded as one operation, too. Changes made through the mutable
+    /// references handed out by [`get_mut`](Self::get_mut), [`metadata_mut`](Self::metadata_mut) or
+    /// [`iter_mut`](Self::iter_mut) are recorded once the references are dropped, references only read are not
+    /// recorded at all, see [`RefMut`]. Like transactions, the history keeps copies of changed nodes only, thus
+    /// elements, metadata and payloads have to implement `Clone`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    /// use kodiak_taxonomy::{Identity, Operation, Taxonomy, TaxonomyError};
+    ///
+    /// #[derive(Clone)]
+    /// struct Class {
+    ///     name: String,
+    /// }
+    ///
+    /// impl Identity<String> for Class {
+    ///     fn id(&self) -> String {
+    ///         self.name.clone()
+    ///     }
+    /// }
+    ///
+    /// fn main() -> Result<(), TaxonomyError<String>> {
+    ///     let mut tax: Taxonomy<String, Class> = Taxonomy::new();
+    ///     tax.enable_history(100);
+    ///
+    ///     tax.add(None, Class{name: "Device".to_string()})?
+    ///        .add(Some("Device".to_string()), Class{name: "Router".to_string()})?;
+    ///     let device = Rc::new("Device".to_string());
+    ///     let router = Rc::new("Router".to_string());
+    ///     assert_eq!(tax.history(), vec![
+    ///         &Operation::Add(None, device.clone()),
+    ///         &Operation::Add(Some(device.clone()), router.clone()),
+    ///     ]);
+    ///
+    ///     tax.get_mut(router.clone()).unwrap().name = "Switch".to_string();
+    ///     assert_eq!(tax.undo(), Some(&Operation::Update(router.clone())));
+    ///     assert_eq!(tax.get(router.clone()).unwrap().name, "Router");
+    ///
+    ///     assert_eq!(tax.undo(), Some(&Operation::Add(Some(device), router.clone())));
+    ///     assert!(tax.get(router.clone()).is_none());
+    ///
+    ///     tax.redo();
+    ///     assert!(tax.get(router).is_some());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn enable_history(&mut self, capacity: usize) -> &mut Self
+    where
+        V: Clone,
+        M: Clone,
+        E: Clone,
+    {
+        self.history = Some(History::new(Node::snapshot, capacity));
+        self
+    }
+
+    /// Disables the history of operations, discarding all operations recorded, see [`enable_history`](Self::enable_history).
+    // Test coverage: { unit = done, integration = none, doc = none } -> ok
+    pub fn disable_history(&mut self) -> &mut Self {
+        self.history = None;
+        self
+    }
+
+    /// Returns true if the history of operations is enabled, see [`enable_history`](Self::enable_history).
+    // Test coverage: { unit = done, integration = none, doc = none } -> ok
+    pub fn has_history(&self) -> bool {
+        self.history.is_some()
+    }
+
+    /// Returns the operations which might be undone, oldest first, see [`enable_history`](Self::enable_history).
+    ///
+    /// Returns an empty list if the history is disabled.
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn history(&self) -> Vec<&Operation<K>> {
+        match &self.history {
+            None => Vec::new(),
+            Some(history) => history.operations(),
+        }
+    }
+
+    /// Undoes the latest operation and returns it or `None` if there is nothing to undo.
+    ///
+    /// Undone operations might be redone by [`redo`](Self::redo) until a new operation is recorded. Nothing is
+    /// undone within a [`transaction`](Self::transaction). The reachability index is kept enabled or disabled.
+    ///
+    /// # Examples
+    ///
+    /// See [`enable_history`](Self::enable_history).
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn undo(&mut self) -> Option<&Operation<K>> {
+        if !self.journal.is_empty() {
+            return None;
+        }
+
+        let (operation, journal) = self.history.as_mut()?.pop_undo()?;
+        let inverse = self._restore(journal);
+        let history = self.history.as_mut().unwrap();
+        history.push_redo(operation, inverse);
+
+        history.last_redo()
+    }
+
+    /// Redoes the latest operation undone and returns it or `None` if there is nothing to redo.
+    ///
+    /// # Examples
+    ///
+    /// See [`enable_history`](Self::enable_history).
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn redo(&mut self) -> Option<&Operation<K>> {
+        if !self.journal.is_empty() {
+            return None;
+        }
+
+        let (operation, journal) = self.history.as_mut()?.pop_redo()?;
+        let inverse = self._restore(journal);
+        let history = self.history.as_mut().unwrap();
+        history.push_undo(operation, inverse);
+
+        history.last_undo()
+    }
+
+    /// Subscribes an observer to the changes of the taxonomy and returns the id of the subscription, see
+    /// [`unsubscribe`](Self::unsubscribe).
+    ///
+    /// Observers receive an [`Event`] for each change, in the order of the changes, e.g. to keep a search index,
+    /// a cache or a user interface in sync. Changes made within a [`transaction`](Self::transaction) are sent
+    /// once the transaction is committed, changes rolled back are never sent. [`undo`](Self::undo) and
+    /// [`redo`](Self::redo) send the changes of edges and nodes they restore.
+    ///
+    /// Changes made through the mutable references handed out by [`get_mut`](Self::get_mut),
+    /// [`metadata_mut`](Self::metadata_mut) or [`iter_mut`](Self::iter_mut) are reported as
+    /// [`ElementMutated`](Event::ElementMutated) once the references are dropped, references only read are not
+    /// reported, see [`RefMut`]. Observers are neither compared nor cloned along with the taxonomy.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::cell::RefCell;
+    /// use std::rc::Rc;
+    /// use kodiak_taxonomy::{Event, Identity, Taxonomy, TaxonomyError};
+    ///
+    /// struct Class {
+    ///     name: String,
+    /// }
+    ///
+    /// impl Identity<String> for Class {
+    ///     fn id(&self) -> String {
+    ///         self.name.clone()
+    ///     }
+    /// }
+    ///
+    /// fn main() -> Result<(), TaxonomyError<String>> {
+    ///     let mut tax: Taxonomy<String, Class> = Taxonomy::new();
+    ///     let events = Rc::new(RefCell::new(Vec::new()));
+    ///     let observer = events.clone();
+    ///     let subscription = tax.subscribe(move |event: &Event<String>| observer.borrow_mut().push(event.clone()));
+    ///
+    ///     tax.add(None, Class{name: "Device".to_string()})?
+    ///        .add(None, Class{name: "Router".to_string()})?
+    ///        .move_to(Rc::new("Router".to_string()), None, Some(Rc::new("Device".to_string())), 0)?;
+    ///
+    ///     let device = Rc::new("Device".to_string());
+    ///     let router = Rc::new("Router".to_string());
+    ///     assert_eq!(*events.borrow(), vec![
+    ///         Event::NodeAdded(None, device.clone()),
+    ///         Event::NodeAdded(None, router.clone()),
+    ///         Event::NodeMoved(router.clone(), None, Some(device.clone())),
+    ///     ]);
+    ///
+    ///     assert!(tax.unsubscribe(subscription));
+    ///     tax.remove(router)?;
+    ///     assert_eq!(events.borrow().len(), 3);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn subscribe<F>(&mut self, observer: F) -> usize
+    where
+        F: FnMut(&Event<K>) + 'static,
+    {
+        self.observers.subscribe(observer)
+    }
+
+    /// Unsubscribes an observer, returns false if there is no subscription with the id provided, see
+    /// [`subscribe`](Self::subscribe).
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn unsubscribe(&mut self, subscription: usize) -> bool {
+        self.observers.unsubscribe(subscription)
+    }
+
+    /// Traverses the taxonomy from first to last node, returning immutable references to its elements.
+    ///
+    /// `traverse` keeps its position within the taxonomy, thus it requires a mutable reference. Once the last
+    /// node has been returned, `None` is returned and the next call starts over with the first node.
+    /// Use [`iter`](Self::iter) to traverse the taxonomy with an [`Iterator`].
+    ///
+    /// # Examples
+    /// todo
+    /// ```text
+    // Test coverage: { unit = done, integration = missing, doc = missing } -> not ok
+    pub fn traverse(&mut self) -> Option<&V> {
+        if let Some(node_id) = self._next() {
+            return self.get(node_id);
+        }
+        None
+    }
+
+    /// Traverses the taxonomy from first to last node, returning mutable references to its elements.
+    ///
+    /// # Examples
+    /// todo
+    /// ```text
+    // Test coverage: { unit = done, integration = missing, doc = missing } -> not ok
+    pub fn traverse_mut(&mut self) -> Option<RefMut<'_, K, V, M, E>> {
+        if let Some(node_id) = self._next() {
+            return self.get_mut(node_id);
+        }
+        None
+    }
+
+    /// Returns an iterator over immutable references to the elements, depth-first and pre-order.
+    ///
+    /// A node with more than one super-node is returned once per super-node. In contrast to
+    /// [`traverse`](Self::traverse), the iterator keeps its own position, so any number of iterators
+    /// might be used at the same time.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kodiak_taxonomy::{Identity, Taxonomy, TaxonomyError};
+    ///
+    /// struct Class {
+    ///     name: String,
+    /// }
+    ///
+    /// impl Identity<String> for Class {
+    ///     fn id(&self) -> String {
+    ///         self.name.clone()
+    ///     }
+    /// }
+    ///
+    /// fn main() -> Result<(), TaxonomyError<String>> {
+    ///     let mut tax: Taxonomy<String, Class> = Taxonomy::new();
+    ///     tax.add(None, Class{name: "Animal".to_string()})?
+    ///        .add(Some("Animal".to_string()), Class{name: "Mammal".to_string()})?
+    ///        .add(None, Class{name: "Plant".to_string()})?;
+    ///
+    ///     let names = tax.iter().map(|class| class.name.as_str()).collect::<Vec<&str>>();
+    ///     assert_eq!(names, vec!["Animal", "Mammal", "Plant"]);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn iter(&self) -> Iter<'_, K, V, M, E> {
+        Iter::new(self, TraversalOrder::PreOrder)
+    }
+
+    /// Returns an iterator over mutable references to the elements, depth-first and pre-order.
+    ///
+    /// Because mutable references have to be unique, a node with more than one super-node is returned
+    /// only once, at its first occurrence.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kodiak_taxonomy::{Identity, Taxonomy, TaxonomyError};
+    ///
+    /// struct Class {
+    ///     name: String,
+    ///     count: usize,
+    /// }
+    ///
+    /// impl Identity<String> for Class {
+    ///     fn id(&self) -> String {
+    ///         self.name.clone()
+    ///     }
+    /// }
+    ///
+    /// fn main() -> Result<(), TaxonomyError<String>> {
+    ///     let mut tax: Taxonomy<String, Class> = Taxonomy::new();
+    ///     tax.add(None, Class{name: "Animal".to_string(), count: 0})?
+    ///        .add(None, Class{name: "Plant".to_string(), count: 0})?;
+    ///
+    ///     for mut class in tax.iter_mut() {
+    ///         class.count += 1;
+    ///     }
+    ///     assert!(tax.iter().all(|class| class.count == 1));
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V, M, E> {
+        let order = self._enumerate_nodes();
+        let (nodes, updates) = self._updates(Operation::UpdateAll);
+        let nodes = nodes.iter_mut().map(|(node_id, node)| (node_id.clone(), node)).collect();
+
+        IterMut::new(order, nodes, updates)
+    }
+
+    /// Returns an iterator over immutable references to the elements in the [`TraversalOrder`] given.
+    ///
+    /// A node with more than one super-node is returned once per super-node.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kodiak_taxonomy::{Identity, Taxonomy, TaxonomyError, TraversalOrder};
+    ///
+    /// struct Class {
+    ///     name: String,
+    /// }
+    ///
+    /// impl Identity<String> for Class {
+    ///     fn id(&self) -> String {
+    ///         self.name.clone()
+    ///     }
+    /// }
+    ///
+    /// fn main() -> Result<(), TaxonomyError<String>> {
+    ///     let mut tax: Taxonomy<String, Class> = Taxonomy::new();
+    ///     tax.add(None, Class{name: "Animal".to_string()})?
+    ///        .add(Some("Animal".to_string()), Class{name: "Mammal".to_string()})?
+    ///        .add(None, Class{name: "Plant".to_string()})?;
+    ///
+    ///     let names = tax.iter_in(TraversalOrder::BreadthFirst).map(|class| class.name.as_str()).collect::<Vec<&str>>();
+    ///     assert_eq!(names, vec!["Animal", "Plant", "Mammal"]);
+    ///
+    ///     let names = tax.iter_in(TraversalOrder::PostOrder).map(|class| class.name.as_str()).collect::<Vec<&str>>();
+    ///     assert_eq!(names, vec!["Mammal", "Animal", "Plant"]);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn iter_in(&self, order: TraversalOrder) -> Iter<'_, K, V, M, E> {
+        Iter::new(self, order)
+    }
+
+    /// Returns an iterator over immutable references to the elements, depth-first and pre-order, visiting each node once.
+    ///
+    /// The [`Occurrence`] policy decides which occurrence of a node with more than one super-node is returned:
+    /// the first one or the one below its [primary super-node](Self::primary_super). Other occurrences are
+    /// skipped including their sub-nodes, which are returned at their own chosen occurrence.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    /// use kodiak_taxonomy::{Identity, Occurrence, Taxonomy, TaxonomyError};
+    ///
+    /// struct Class {
+    ///     name: String,
+    /// }
+    ///
+    /// impl Identity<String> for Class {
+    ///     fn id(&self) -> String {
+    ///         self.name.clone()
+    ///     }
+    /// }
+    ///
+    /// fn main() -> Result<(), TaxonomyError<String>> {
+    ///     let mut tax: Taxonomy<String, Class> = Taxonomy::new();
+    ///     tax.add(None, Class{name: "Network Device".to_string()})?
+    ///        .add(Some("Network Device".to_string()), Class{name: "Firewall".to_string()})?
+    ///        .add(None, Class{name: "Security Device".to_string()})?
+    ///        .append(Some("Security Device".to_string()), "Firewall".to_string())?;
+    ///     tax.set_primary_super(Rc::new("Firewall".to_string()), Some(Rc::new("Security Device".to_string())))?;
+    ///
+    ///     let names = tax.iter_unique(Occurrence::First).map(|class| class.name.as_str()).collect::<Vec<&str>>();
+    ///     assert_eq!(names, vec!["Network Device", "Firewall", "Security Device"]);
+    ///
+    ///     let names = tax.iter_unique(Occurrence::Primary).map(|class| class.name.as_str()).collect::<Vec<&str>>();
+    ///     assert_eq!(names, vec!["Network Device", "Security Device", "Firewall"]);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn iter_unique(&self, occurrence: Occurrence) -> IterUnique<'_, K, V, M, E> {
+        IterUnique::new(self, occurrence)
+    }
+
+    /// Returns an iterator over the levels of the taxonomy, each level being a `Vec` of immutable references
+    /// to its elements.
+    ///
+    /// The first level holds the root-nodes, the next level their sub-nodes and so on.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kodiak_taxonomy::{Identity, Taxonomy, TaxonomyError};
+    ///
+    /// struct Class {
+    ///     name: String,
+    /// }
+    ///
+    /// impl Identity<String> for Class {
+    ///     fn id(&self) -> String {
+    ///         self.name.clone()
+    ///     }
+    /// }
+    ///
+    /// fn main() -> Result<(), TaxonomyError<String>> {
+    ///     let mut tax: Taxonomy<String, Class> = Taxonomy::new();
+    ///     tax.add(None, Class{name: "Animal".to_string()})?
+    ///        .add(Some("Animal".to_string()), Class{name: "Mammal".to_string()})?
+    ///        .add(None, Class{name: "Plant".to_string()})?;
+    ///
+    ///     for (depth, level) in tax.levels().enumerate() {
+    ///         println!("{}: {} element(s)", depth, level.len());
+    ///     }
+    ///     assert_eq!(tax.levels().count(), 2);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn levels(&self) -> Levels<'_, K, V, M, E> {
+        Levels::new(self)
+    }
+
+    /// Returns an iterator over the elements, depth-first and pre-order, together with their position in the taxonomy.
+    ///
+    /// Each [`Visit`](crate::Visit) provides the element, its depth, the [`Edge`] the node has been reached through and
+    /// the path of ids from the root-node down to the node. A node with more than one super-node is returned once
+    /// per super-node, so the edge and the path tell which occurrence is visited.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kodiak_taxonomy::{Identity, Taxonomy, TaxonomyError};
+    ///
+    /// struct Class {
+    ///     name: String,
+    /// }
+    ///
+    /// impl Identity<String> for Class {
+    ///     fn id(&self) -> String {
+    ///         self.name.clone()
+    ///     }
+    /// }
+    ///
+    /// fn main() -> Result<(), TaxonomyError<String>> {
+    ///     let mut tax: Taxonomy<String, Class> = Taxonomy::new();
+    ///     tax.add(None, Class{name: "Network Device".to_string()})?
+    ///        .add(Some("Network Device".to_string()), Class{name: "Firewall".to_string()})?
+    ///        .add(None, Class{name: "Security Device".to_string()})?
+    ///        .append(Some("Security Device".to_string()), "Firewall".to_string())?;
+    ///
+    ///     for visit in tax.visits().filter(|visit| visit.element().name == "Firewall") {
+    ///         assert_eq!(visit.depth(), 1);
+    ///         println!("{:?} reached through {:?}", visit.path(), visit.edge().super_id());
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn visits(&self) -> Visits<'_, K, V, M, E> {
+        Visits::new(self)
+    }
+
+    /// Returns true if the node identified by `node_id` is a descendant of the node identified by `ancestor_id`,
+    /// i.e. there is a path from the ancestor down to the node. A node is not a descendant of itself.
+    ///
+    /// With the [reachability index](Self::enable_reachability_index) enabled, the check is a hash set lookup.
+    /// Otherwise the ancestors of the node are searched breadth-first.
+    ///
+    /// # Errors
+    ///
+    /// - [`NodeNotFound`]: taxonomy does not contain either of the nodes provided.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    /// use kodiak_taxonomy::{Identity, Taxonomy, TaxonomyError};
+    ///
+    /// struct Class {
+    ///     name: String,
+    /// }
+    ///
+    /// impl Identity<String> for Class {
+    ///     fn id(&self) -> String {
+    ///         self.name.clone()
+    ///     }
+    /// }
+    ///
+    /// fn main() -> Result<(), TaxonomyError<String>> {
+    ///     let mut tax: Taxonomy<String, Class> = Taxonomy::new();
+    ///     tax.enable_reachability_index();
+    ///     tax.add(None, Class{name: "Device".to_string()})?
+    ///        .add(Some("Device".to_string()), Class{name: "Security Device".to_string()})?
+    ///        .add(Some("Security Device".to_string()), Class{name: "Firewall".to_string()})?;
+    ///
+    ///     let device = Rc::new("Device".to_string());
+    ///     let firewall = Rc::new("Firewall".to_string());
+    ///     assert!(tax.is_descendant_of(firewall.clone(), device.clone())?);
+    ///     assert!(!tax.is_descendant_of(device, firewall)?);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn is_descendant_of(&self, node_id: Rc<K>, ancestor_id: Rc<K>) -> Result<bool, TaxonomyError<K>> {
+        // Input validation: node_id and ancestor_id
+        self._err_node_not_found(node_id.clone())?;
+        self._err_node_not_found(ancestor_id.clone())?;
+
+        match &self.reachability {
+            Some(reachability) => Ok(reachability
+                .get(&node_id)
+                .is_some_and(|ancestors| ancestors.contains(&ancestor_id))),
+            None => Ok(self.ancestors(node_id, None)?.any(|id| id == ancestor_id)),
+        }
+    }
+
+    /// Returns true if the node identified by `node_id` is an ancestor of the node identified by `descendant_id`,
+    /// see [`is_descendant_of`](Self::is_descendant_of).
+    ///
+    /// # Errors
+    ///
+    /// - [`NodeNotFound`]: taxonomy does not contain either of the nodes provided.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    /// use kodiak_taxonomy::{Identity, Taxonomy, TaxonomyError};
+    ///
+    /// struct Class {
+    ///     name: String,
+    /// }
+    ///
+    /// impl Identity<String> for Class {
+    ///     fn id(&self) -> String {
+    ///         self.name.clone()
+    ///     }
+    /// }
+    ///
+    /// fn main() -> Result<(), TaxonomyError<String>> {
+    ///     let mut tax: Taxonomy<String, Class> = Taxonomy::new();
+    ///     tax.enable_reachability_index();
+    ///     tax.add(None, Class{name: "Device".to_string()})?
+    ///        .add(Some("Device".to_string()), Class{name: "Security Device".to_string()})?
+    ///        .add(Some("Security Device".to_string()), Class{name: "Firewall".to_string()})?;
+    ///
+    ///     let device = Rc::new("Device".to_string());
+    ///     let firewall = Rc::new("Firewall".to_string());
+    ///     assert!(tax.is_ancestor_of(device, firewall)?);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn is_ancestor_of(&self, node_id: Rc<K>, descendant_id: Rc<K>) -> Result<bool, TaxonomyError<K>> {
+        self.is_descendant_of(descendant_id, node_id)
+    }
+
+    /// Returns true if the node identified by `node_id` is a kind of the node identified by `super_id`,
+    /// i.e. both are identical or the node is a descendant of the other one.
+    ///
+    /// # Errors
+    ///
+    /// - [`NodeNotFound`]: taxonomy does not contain either of the nodes provided.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    /// use kodiak_taxonomy::{Identity, Taxonomy, TaxonomyError};
+    ///
+    /// struct Class {
+    ///     name: String,
+    /// }
+    ///
+    /// impl Identity<String> for Class {
+    ///     fn id(&self) -> String {
+    ///         self.name.clone()
+    ///     }
+    /// }
+    ///
+    /// fn main() -> Result<(), TaxonomyError<String>> {
+    ///     let mut tax: Taxonomy<String, Class> = Taxonomy::new();
+    ///     tax.enable_reachability_index();
+    ///     tax.add(None, Class{name: "Device".to_string()})?
+    ///        .add(Some("Device".to_string()), Class{name: "Security Device".to_string()})?
+    ///        .add(Some("Security Device".to_string()), Class{name: "Firewall".to_string()})?;
+    ///
+    ///     let device = Rc::new("Device".to_string());
+    ///     let firewall = Rc::new("Firewall".to_string());
+    ///     assert!(tax.is_a(firewall.clone(), device)?);
+    ///     assert!(tax.is_a(firewall.clone(), firewall)?);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    // Test coverage: { unit = done, integration = none, doc = done } -> ok
+    pub fn is_a(&self, node_id: Rc<K>, super_id: Rc<K>) -> Result<bool, TaxonomyError<K>> {
+        if node_id == super_id {
+            self._err_node_not_found(node_id)?;
+            return Ok(true);
+        }
+
+        self.is_descendant_of(node_id, super_id)
+    }
+
+    /// Returns the ids of the lowest common ancestors of the nodes provided.
+    ///
+    /// A common ancestor is a node which all nodes provided are a [kind of](Self::is_a), so a node is considered its own
+    /// ancestor here. A common ancestor is lowest, if none of its descendants is a common ancestor as well.
+    /// Because a node might have more than one super-node, there might be more than one lowest common ancestor.
+    ///
+    /// The taxonomy has no single root-node. If nodes share no ancestor, e.g. because they belong to different
+    /// root-nodes, the set returned is empty. It's also empty if no node is provided.
+    ///
+    /// # Errors
+    ///
+    /// - [`NodeNotFound`]: taxonomy does not contain one of the nodes provided.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::collections::HashSet;
+    /// use std::rc::Rc;
+    /// use kodiak_taxonomy::{Identity, Taxonomy, TaxonomyError};
+    ///
+    /// struct Class {
+    ///     name: String,
+    /// }
+    ///
+    /// impl Identity<String> for Class {
+    ///     fn id(&self) -> String {
+    ///         self.name.clone()
+    ///     }
+    /// }
+    ///
+    /// fn main() -> Result<(), TaxonomyError<String>> {
+    ///     let mut tax: Taxonomy<String, Class> = Taxonomy::new();
+    ///     tax.add(None, Class{name: "Network Device".to_string()})?
+    ///        .add(None, Class{name: "Security Device".to_string()})?
+    ///        .add(Some("Network Device".to_string()), Class{name: "Firewall".to_string()})?
+    ///        .append(Some("Security Device".to_string()), "Firewall".to_string())?
+    ///        .add(Some("Network Device".to_string()), Class{name: "Router".to_string()})?
+    ///        .append(Some("Security Device".to_string()), "Router".to_string())?;
+    ///
+    ///     let lca = tax.lowest_common_ancestors(&[Rc::new("Firewall".to_string()), Rc::new("Router".to_string())])?;
+    ///     let expectation = HashSet::from([Rc::new("Network Device".to_string()), Rc::new("Security Device".to_string())]);
+    ///     assert_eq!(lca, expectation);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn lowest_common_ancestors(&self, node_ids: &[Rc<K>]) -> Result<HashSet<Rc<K>>, TaxonomyError<K>> {
+        // Input validation: node_ids
+        for node_id in node_ids {
+            self._err_node_not_found(node_id.clone())?;
+        }
+
+        // Intersect the ancestors of all nodes, each node counting as its own ancestor
+        let mut common: Option<HashSet<Rc<K>>> = None;
+        for node_id in node_ids {
+            let mut ancestors = self._ancestor_ids(node_id.clone());
+            ancestors.insert(node_id.clone());
+
+            common = match common {
+                None => Some(ancestors),
+                Some(common) => Some(common.intersection(&ancestors).cloned().collect()),
+            };
+        }
+        let common = common.unwrap_or_default();
+
+        // A common ancestor which is an ancestor of another common ancestor is not lowest
+        let not_lowest = common
+            .iter()
+            .flat_map(|node_id| self._ancestor_ids(node_id.clone()))
+            .collect::<HashSet<Rc<K>>>();
+
+        Ok(common.difference(&not_lowest).cloned().collect())
+    }
+
+    /// Returns every path from a root-node to a node. Each path is a sequence of ids, starting with a root-node and
+    /// ending with the node itself.
+    ///
+    /// Paths are ordered by the node's super-nodes in insertion order, with the path of a root-node coming first.
+    /// Paths through a super-node are ordered the same way, recursively.
+    ///
+    /// # Errors
+    ///
+    /// - [`NodeNotFound`]: taxonomy does not contain the node provided by `node_id`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    /// use kodiak_taxonomy::{Identity, Taxonomy, TaxonomyError};
+    ///
+    /// struct Class {
+    ///     name: String,
+    /// }
+    ///
+    /// impl Identity<String> for Class {
+    ///     fn id(&self) -> String {
+    ///         self.name.clone()
+    ///     }
+    /// }
+    ///
+    /// fn main() -> Result<(), TaxonomyError<String>> {
+    ///     let mut tax: Taxonomy<String, Class> = Taxonomy::new();
+    ///     tax.add(None, Class{name: "Network Device".to_string()})?
+    ///        .add(Some("Network Device".to_string()), Class{name: "Firewall".to_string()})?
+    ///        .add(None, Class{name: "Security Device".to_string()})?
+    ///        .append(Some("Security Device".to_string()), "Firewall".to_string())?;
+    ///
+    ///     let paths = tax.paths_to(Rc::new("Firewall".to_string()))?;
+    ///     assert_eq!(paths, vec![
+    ///         vec![Rc::new("Network Device".to_string()), Rc::new("Firewall".to_string())],
+    ///         vec![Rc::new("Security Device".to_string()), Rc::new("Firewall".to_string())],
+    ///     ]);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn paths_to(&self, node_id: Rc<K>) -> Result<Vec<Vec<Rc<K>>>, TaxonomyError<K>> {
+        // Input validation: node_id
+        self._err_node_not_found(node_id.clone())?;
+
+        Ok(self._paths_to(node_id))
+    }
+
+    /// Returns one path from a root-node to a node. The path is a sequence of ids, starting with a root-node and
+    /// ending with the node itself.
+    ///
+    /// The path is selected by following the [primary super-node](Self::primary_super) of each node up to a root-node.
+    /// Unless designated otherwise by [`set_primary_super`](Self::set_primary_super), the primary super-node is the
+    /// first super-node in insertion order, so the canonical path is stable as long as the taxonomy's edges are.
+    ///
+    /// # Errors
+    ///
+    /// - [`NodeNotFound`]: taxonomy does not contain the node provided by `node_id`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    /// use kodiak_taxonomy::{Identity, Taxonomy, TaxonomyError};
+    ///
+    /// struct Class {
+    ///     name: String,
+    /// }
+    ///
+    /// impl Identity<String> for Class {
+    ///     fn id(&self) -> String {
+    ///         self.name.clone()
+    ///     }
+    /// }
+    ///
+    /// fn main() -> Result<(), TaxonomyError<String>> {
+    ///     let mut tax: Taxonomy<String, Class> = Taxonomy::new();
+    ///     tax.add(None, Class{name: "Network Device".to_string()})?
+    ///        .add(Some("Network Device".to_string()), Class{name: "Firewall".to_string()})?
+    ///        .add(None, Class{name: "Security Device".to_string()})?
+    ///        .append(Some("Security Device".to_string()), "Firewall".to_string())?;
+    ///
+    ///     let firewall = Rc::new("Firewall".to_string());
+    ///     let path = tax.canonical_path(firewall.clone())?;
+    ///     assert_eq!(path, vec![Rc::new("Network Device".to_string()), firewall.clone()]);
+    ///
+    ///     tax.set_primary_super(firewall.clone(), Some(Rc::new("Security Device".to_string())))?;
+    ///     let path = tax.canonical_path(firewall.clone())?;
+    ///     assert_eq!(path, vec![Rc::new("Security Device".to_string()), firewall]);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn canonical_path(&self, node_id: Rc<K>) -> Result<Vec<Rc<K>>, TaxonomyError<K>> {
+        // Input validation: node_id
+        let mut node = self._get_node_res(node_id)?;
+
+        let mut path = vec![node.id()];
+        while let Some(super_id) = node.primary_super() {
+            node = self._get_node_opt(super_id).unwrap();
+            path.push(node.id());
+        }
+        path.reverse();
+
+        Ok(path)
+    }
+
+    /// Builds an index of all ancestors per node, making [`is_descendant_of`](Self::is_descendant_of) and
+    /// [`is_ancestor_of`](Self::is_ancestor_of) a hash set lookup.
+    ///
+    /// Once enabled, the index is kept up to date by all functions changing the structure of the taxonomy.
+    /// The index trades memory and slower updates for fast checks, its size grows with the number of nodes
+    /// times their average number of ancestors.
+    ///
+    /// # Examples
+    ///
+    /// See [`is_descendant_of`](Self::is_descendant_of).
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn enable_reachability_index(&mut self) -> &mut Self {
+        let mut reachability = HashMap::with_capacity(self.nodes.len());
+        for node_id in self.nodes.keys() {
+            self._collect_ancestors(node_id.clone(), &mut reachability);
+        }
+        self.reachability = Some(reachability);
+
+        self
+    }
+
+    /// Drops the index of ancestors, see [`enable_reachability_index`](Self::enable_reachability_index).
+    // Test coverage: { unit = done, integration = none, doc = none } -> ok
+    pub fn disable_reachability_index(&mut self) -> &mut Self {
+        self.reachability = None;
+        self
+    }
+
+    /// Returns true if the reachability index is enabled, see [`enable_reachability_index`](Self::enable_reachability_index).
+    // Test coverage: { unit = done, integration = none, doc = none } -> ok
+    pub fn has_reachability_index(&self) -> bool {
+        self.reachability.is_some()
+    }
+
+    /// Returns an iterator over the ids of a node's ancestors: its super-nodes, their super-nodes and so on.
+    ///
+    /// Ids are returned breadth-first, i.e. ordered by their distance to the node, each id only once.
+    /// `max_depth` limits the distance, `Some(1)` returns the super-nodes only, `None` returns all ancestors.
+    /// The root of the taxonomy is not an ancestor.
+    ///
+    /// # Errors
+    ///
+    /// - [`NodeNotFound`]: taxonomy does not contain the node provided by `node_id`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    /// use kodiak_taxonomy::{Identity, Taxonomy, TaxonomyError};
+    ///
+    /// struct Class {
+    ///     name: String,
+    /// }
+    ///
+    /// impl Identity<String> for Class {
+    ///     fn id(&self) -> String {
+    ///         self.name.clone()
+    ///     }
+    /// }
+    ///
+    /// fn main() -> Result<(), TaxonomyError<String>> {
+    ///     let mut tax: Taxonomy<String, Class> = Taxonomy::new();
+    ///     tax.add(None, Class{name: "Device".to_string()})?
+    ///        .add(Some("Device".to_string()), Class{name: "Network Device".to_string()})?
+    ///        .add(Some("Device".to_string()), Class{name: "Security Device".to_string()})?
+    ///        .add(Some("Network Device".to_string()), Class{name: "Firewall".to_string()})?
+    ///        .append(Some("Security Device".to_string()), "Firewall".to_string())?;
+    ///
+    ///     let firewall = Rc::new("Firewall".to_string());
+    ///     let ancestors = tax.ancestors(firewall.clone(), None)?.collect::<Vec<Rc<String>>>();
+    ///     assert_eq!(ancestors.len(), 3);
+    ///     assert_eq!(*ancestors[2], "Device".to_string());
+    ///
+    ///     assert_eq!(tax.ancestors(firewall, Some(1))?.count(), 2);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn ancestors(&self, node_id: Rc<K>, max_depth: Option<usize>) -> Result<Relatives<'_, K, V, M, E>, TaxonomyError<K>> {
+        self._err_node_not_found(node_id.clone())?;
+        Ok(Relatives::new(self, node_id, Direction::Supers, max_depth))
+    }
+
+    /// Returns the ids of a node's ancestors as a set, see [`ancestors`](Self::ancestors).
+    ///
+    /// # Errors
+    ///
+    /// - [`NodeNotFound`]: taxonomy does not contain the node provided by `node_id`.
+    // Test coverage: { unit = done, integration = none, doc = none } -> ok
+    pub fn ancestor_set(&self, node_id: Rc<K>, max_depth: Option<usize>) -> Result<HashSet<Rc<K>>, TaxonomyError<K>> {
+        Ok(self.ancestors(node_id, max_depth)?.collect())
+    }
+
+    /// Returns an iterator over the ids of a node's descendants: its sub-nodes, their sub-nodes and so on.
+    ///
+    /// Ids are returned breadth-first, i.e. ordered by their distance to the node, each id only once.
+    /// `max_depth` limits the distance, `Some(1)` returns the sub-nodes only, `None` returns all descendants.
+    ///
+    /// # Errors
+    ///
+    /// - [`NodeNotFound`]: taxonomy does not contain the node provided by `node_id`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    /// use kodiak_taxonomy::{Identity, Taxonomy, TaxonomyError};
+    ///
+    /// struct Class {
+    ///     name: String,
+    /// }
+    ///
+    /// impl Identity<String> for Class {
+    ///     fn id(&self) -> String {
+    ///         self.name.clone()
+    ///     }
+    /// }
+    ///
+    /// fn main() -> Result<(), TaxonomyError<String>> {
+    ///     let mut tax: Taxonomy<String, Class> = Taxonomy::new();
+    ///     tax.add(None, Class{name: "Device".to_string()})?
+    ///        .add(Some("Device".to_string()), Class{name: "Network Device".to_string()})?
+    ///        .add(Some("Device".to_string()), Class{name: "Security Device".to_string()})?
+    ///        .add(Some("Network Device".to_string()), Class{name: "Firewall".to_string()})?
+    ///        .append(Some("Security Device".to_string()), "Firewall".to_string())?;
+    ///
+    ///     let device = Rc::new("Device".to_string());
+    ///     assert_eq!(tax.descendants(device.clone(), None)?.count(), 3);
+    ///     assert_eq!(tax.descendants(device, Some(1))?.count(), 2);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn descendants(&self, node_id: Rc<K>, max_depth: Option<usize>) -> Result<Relatives<'_, K, V, M, E>, TaxonomyError<K>> {
+        self._err_node_not_found(node_id.clone())?;
+        Ok(Relatives::new(self, node_id, Direction::Subs, max_depth))
+    }
+
+    /// Returns the ids of a node's descendants as a set, see [`descendants`](Self::descendants).
+    ///
+    /// # Errors
+    ///
+    /// - [`NodeNotFound`]: taxonomy does not contain the node provided by `node_id`.
+    // Test coverage: { unit = done, integration = none, doc = none } -> ok
+    pub fn descendant_set(&self, node_id: Rc<K>, max_depth: Option<usize>) -> Result<HashSet<Rc<K>>, TaxonomyError<K>> {
+        Ok(self.descendants(node_id, max_depth)?.collect())
+    }
+
+    /// Returns an iterator over the ids of a node's super-nodes, in the order they have been added.
+    ///
+    /// A root-node without any other super-node has no parents.
+    ///
+    /// # Errors
+    ///
+    /// - [`NodeNotFound`]: taxonomy does not contain the node provided by `node_id`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    /// use kodiak_taxonomy::{Identity, Taxonomy, TaxonomyError};
+    ///
+    /// struct Class {
+    ///     name: String,
+    /// }
+    ///
+    /// impl Identity<String> for Class {
+    ///     fn id(&self) -> String {
+    ///         self.name.clone()
+    ///     }
+    /// }
+    ///
+    /// fn main() -> Result<(), TaxonomyError<String>> {
+    ///     let mut tax: Taxonomy<String, Class> = Taxonomy::new();
+    ///     tax.add(None, Class{name: "Device".to_string()})?
+    ///        .add(Some("Device".to_string()), Class{name: "Network Device".to_string()})?
+    ///        .add(Some("Device".to_string()), Class{name: "Security Device".to_string()})?
+    ///        .add(Some("Network Device".to_string()), Class{name: "Firewall".to_string()})?
+    ///        .append(Some("Security Device".to_string()), "Firewall".to_string())?;
+    ///
+    ///     let parents = tax.parents(Rc::new("Firewall".to_string()))?.collect::<Vec<Rc<String>>>();
+    ///     assert_eq!(parents, vec![Rc::new("Network Device".to_string()), Rc::new("Security Device".to_string())]);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn parents(&self, node_id: Rc<K>) -> Result<Relatives<'_, K, V, M, E>, TaxonomyError<K>> {
+        self.ancestors(node_id, Some(1))
+    }
+
+    /// Returns the ids of a node's super-nodes as a set, see [`parents`](Self::parents).
+    ///
+    /// # Errors
+    ///
+    /// - [`NodeNotFound`]: taxonomy does not contain the node provided by `node_id`.
+    // Test coverage: { unit = done, integration = none, doc = none } -> ok
+    pub fn parent_set(&self, node_id: Rc<K>) -> Result<HashSet<Rc<K>>, TaxonomyError<K>> {
+        self.ancestor_set(node_id, Some(1))
+    }
+
+    /// Returns an iterator over the ids of a node's sub-nodes, in their order.
+    ///
+    /// # Errors
+    ///
+    /// - [`NodeNotFound`]: taxonomy does not contain the node provided by `node_id`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    /// use kodiak_taxonomy::{Identity, Taxonomy, TaxonomyError};
+    ///
+    /// struct Class {
+    ///     name: String,
+    /// }
+    ///
+    /// impl Identity<String> for Class {
+    ///     fn id(&self) -> String {
+    ///         self.name.clone()
+    ///     }
+    /// }
+    ///
+    /// fn main() -> Result<(), TaxonomyError<String>> {
+    ///     let mut tax: Taxonomy<String, Class> = Taxonomy::new();
+    ///     tax.add(None, Class{name: "Device".to_string()})?
+    ///        .add(Some("Device".to_string()), Class{name: "Network Device".to_string()})?
+    ///        .add(Some("Device".to_string()), Class{name: "Security Device".to_string()})?
+    ///        .add(Some("Network Device".to_string()), Class{name: "Firewall".to_string()})?
+    ///        .append(Some("Security Device".to_string()), "Firewall".to_string())?;
+    ///
+    ///     let children = tax.children(Rc::new("Device".to_string()))?.collect::<Vec<Rc<String>>>();
+    ///     assert_eq!(children, vec![Rc::new("Network Device".to_string()), Rc::new("Security Device".to_string())]);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn children(&self, node_id: Rc<K>) -> Result<Relatives<'_, K, V, M, E>, TaxonomyError<K>> {
+        self.descendants(node_id, Some(1))
+    }
+
+    /// Returns the ids of a node's sub-nodes as a set, see [`children`](Self::children).
+    ///
+    /// # Errors
+    ///
+    /// - [`NodeNotFound`]: taxonomy does not contain the node provided by `node_id`.
+    // Test coverage: { unit = done, integration = none, doc = none } -> ok
+    pub fn child_set(&self, node_id: Rc<K>) -> Result<HashSet<Rc<K>>, TaxonomyError<K>> {
+        self.descendant_set(node_id, Some(1))
+    }
+
+    /// Returns an iterator over the ids of a node's co-nodes, i.e. the nodes sharing at least one super-node with it.
+    ///
+    /// For a root-node, the other root-nodes are co-nodes as well. Each id is returned once, the node itself is not returned.
+    ///
+    /// # Errors
+    ///
+    /// - [`NodeNotFound`]: taxonomy does not contain the node provided by `node_id`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    /// use kodiak_taxonomy::{Identity, Taxonomy, TaxonomyError};
+    ///
+    /// struct Class {
+    ///     name: String,
+    /// }
+    ///
+    /// impl Identity<String> for Class {
+    ///     fn id(&self) -> String {
+    ///         self.name.clone()
+    ///     }
+    /// }
+    ///
+    /// fn main() -> Result<(), TaxonomyError<String>> {
+    ///     let mut tax: Taxonomy<String, Class> = Taxonomy::new();
+    ///     tax.add(None, Class{name: "Device".to_string()})?
+    ///        .add(Some("Device".to_string()), Class{name: "Network Device".to_string()})?
+    ///        .add(Some("Device".to_string()), Class{name: "Security Device".to_string()})?
+    ///        .add(Some("Network Device".to_string()), Class{name: "Firewall".to_string()})?
+    ///        .append(Some("Security Device".to_string()), "Firewall".to_string())?;
+    ///
+    ///     let siblings = tax.siblings(Rc::new("Network Device".to_string()))?.collect::<Vec<Rc<String>>>();
+    ///     assert_eq!(siblings, vec![Rc::new("Security Device".to_string())]);
+    ///
+    ///     assert_eq!(tax.siblings(Rc::new("Firewall".to_string()))?.count(), 0);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn siblings(&self, node_id: Rc<K>) -> Result<Siblings<'_, K, V, M, E>, TaxonomyError<K>> {
+        let node = self._get_node_res(node_id.clone())?;
+
+        let mut supers = node.supers().into_iter().map(Some).collect::<Vec<Option<Rc<K>>>>();
+        if node.is_root() {
+            supers.insert(0, None);
+        }
+
+        Ok(Siblings::new(self, node_id, supers))
+    }
+
+    /// Returns the ids of a node's co-nodes as a set, see [`siblings`](Self::siblings).
+    ///
+    /// # Errors
+    ///
+    /// - [`NodeNotFound`]: taxonomy does not contain the node provided by `node_id`.
+    // Test coverage: { unit = done, integration = none, doc = none } -> ok
+    pub fn sibling_set(&self, node_id: Rc<K>) -> Result<HashSet<Rc<K>>, TaxonomyError<K>> {
+        Ok(self.siblings(node_id)?.collect())
+    }
+}
+
+impl<K, V, M, E> IntoIterator for Taxonomy<K, V, M, E>
+where
+    K: Hash + Eq,
+    V: Identity<K>,
+{
+    type Item = V;
+    type IntoIter = IntoIter<K, V, M, E>;
+
+    /// Consumes the taxonomy and returns its elements, depth-first and pre-order.
+    ///
+    /// A node with more than one super-node is returned only once, at its first occurrence.
+    fn into_iter(self) -> Self::IntoIter {
+        let order = self._enumerate_nodes();
+        let elements = self
+            .nodes
+            .into_iter()
+            .map(|(node_id, node)| (node_id, node.into_element()))
+            .collect();
+
+        IntoIter::new(order, elements)
+    }
+}
+
+impl<'a, K, V, M, E> IntoIterator for &'a Taxonomy<K, V, M, E>
+where
+    K: Hash + Eq,
+    V: Identity<K>,
+{
+    type Item = &'a V;
+    type IntoIter = Iter<'a, K, V, M, E>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K, V, M, E> IntoIterator for &'a mut Taxonomy<K, V, M, E>
+where
+    K: Hash + Eq,
+    V: Identity<K>,
+{
+    type Item = RefMut<'a, K, V, M, E>;
+    type IntoIter = IterMut<'a, K, V, M, E>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+//
+// Private functions
+//
+#[doc(hidden)]
+impl<K, V, M, E> Taxonomy<K, V, M, E>
+where
+    K: Hash + Eq,
+    V: Identity<K>,
+{
+    /// Adds a non-root-node to the taxonomy, with payload attached to the edge to its super-node.
+    // Test coverage: { unit = done, integration = none, doc = none } -> ok
+    fn _add_non_root_node(&mut self, super_id: Rc<K>, node: Node<K, V, M, E>, payload: E) -> &mut Self {
+        let node_id = node.id();
+
+        self._pre_update(node_id.clone());
+
+        // Add node to taxonomy
+        self.nodes.insert(node_id.clone(), node);
+
+        // Append node to super-node as the last node of all sub-nodes
+        let index = self._get_node_opt(super_id.clone()).unwrap().count_subs();
+        self._append_at(super_id, node_id.clone(), index, payload);
+
+        self._post_update(node_id);
+
+        self
+    }
+
+    /// Adds a root-node to the taxonomy, with payload attached to the edge to the root of the taxonomy.
+    // Test coverage: { unit = done, integration = none, doc = none } -> ok
+    fn _add_root_node(&mut self, mut node: Node<K, V, M, E>, payload: E) -> &mut Self {
+        let node_id = node.id();
+
         self._pre_update(node_id.clone());
+        node.set_payload(None, payload);
         self.nodes.insert(node_id.clone(), node);
-        self.node0.push_back(node_id.clone());
+        let index = self._sorted_index(None, node_id.clone(), self.node0.len());
+        let mut remain = self.node0.split_off(index);
+        remain.push_front(node_id.clone());
+        self.node0.append(&mut remain);
+        self._reindex(node_id.clone());
         self._post_update(node_id);
 
         self
     }
 
-    /// Appends a node to a super-node.
+    /// Returns the ids of all ancestors of a node, from the reachability index if enabled.
+    /// Returns an empty set if node is not found.
+    // Test coverage: { unit = done, integration = none, doc = none } -> ok
+    fn _ancestor_ids(&self, node_id: Rc<K>) -> HashSet<Rc<K>> {
+        match &self.reachability {
+            Some(reachability) => reachability.get(&node_id).cloned().unwrap_or_default(),
+            None => Relatives::new(self, node_id, Direction::Supers, None).collect(),
+        }
+    }
+
+    /// Appends a node to a super-node, with payload attached to the new edge.
     // Test coverage: { unit = done, integration = none, doc = none } -> ok
-    fn _append_at(&mut self, super_id: Rc<K>, node_id: Rc<K>, index: usize) -> &mut Self {
+    fn _append_at(&mut self, super_id: Rc<K>, node_id: Rc<K>, index: usize, payload: E) -> &mut Self {
+        let index = self._sorted_index(Some(super_id.clone()), node_id.clone(), index);
+
         // Append node as sub-node to super-node.
         self._pre_update(super_id.clone());
         self._get_node_mut_opt(super_id.clone())
@@ -673,15 +3124,20 @@ where
 
         // Add super-node to node as super-node.
         self._pre_update(node_id.clone());
-        self._get_node_mut_opt(node_id.clone()).unwrap().add_super(Some(super_id));
+        let node = self._get_node_mut_opt(node_id.clone()).unwrap();
+        node.add_super(Some(super_id.clone()));
+        node.set_payload(Some(super_id), payload);
+        self._reindex(node_id.clone());
         self._post_update(node_id);
 
         self
     }
 
-    /// Appends a node to the root of the taxonomy at a specified position.
+    /// Appends a node to the root of the taxonomy at a specified position, with payload attached to the new edge.
     // Test coverage: { unit = done, integration = none, doc = none } -> ok
-    fn _append_root_at(&mut self, node_id: Rc<K>, index: usize) -> &mut Self {
+    fn _append_root_at(&mut self, node_id: Rc<K>, index: usize, payload: E) -> &mut Self {
+        let index = self._sorted_index(None, node_id.clone(), index);
+
         let mut remain = if index <= self.node0.len() {
             self.node0.split_off(index)
         } else {
@@ -692,10 +3148,85 @@ where
 
         self._pre_update(node_id.clone());
 
-        // Add super node to node
-        self._get_node_mut_opt(node_id.clone()).unwrap().add_super(None);
+        // Add super node to node
+        let node = self._get_node_mut_opt(node_id.clone()).unwrap();
+        node.add_super(None);
+        node.set_payload(None, payload);
+
+        self._post_update(node_id);
+
+        self
+    }
+
+    /// Collects the ids of all ancestors of a node, using and extending `reachability` as a cache.
+    // Test coverage: { unit = done, integration = none, doc = none } -> ok
+    fn _collect_ancestors(&self, node_id: Rc<K>, reachability: &mut HashMap<Rc<K>, HashSet<Rc<K>>>) -> HashSet<Rc<K>> {
+        if let Some(ancestors) = reachability.get(&node_id) {
+            return ancestors.clone();
+        }
+
+        let mut ancestors = HashSet::new();
+        if let Some(node) = self._get_node_opt(node_id.clone()) {
+            for super_id in node.supers() {
+                ancestors.extend(self._collect_ancestors(super_id.clone(), reachability));
+                ancestors.insert(super_id);
+            }
+        }
+        reachability.insert(node_id, ancestors.clone());
+
+        ancestors
+    }
+
+    /// Pushes the first sub-node to cursor until cursor points to a node without sub-nodes.
+    // Test coverage: { unit = done, integration = none, doc = none } -> ok
+    fn _descend_to_first_leaf(&self, cursor: &mut Vec<Cursor<K>>) {
+        while let Some(node_id) = cursor.last().and_then(|last| self._get_node_id_at(last).1) {
+            if self._get_node_opt(node_id.clone()).unwrap().has_sub() {
+                cursor.push(Cursor::new(Some(node_id), 0));
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// Removes the edge between a super-node and a node which has another super-node, thus the node remains in the
+    /// taxonomy. Panics if the node is not found.
+    // Test coverage: { unit = done, integration = none, doc = none } -> ok
+    fn _detach(&mut self, super_id: Option<Rc<K>>, node_id: Rc<K>) -> &mut Self {
+        self._pre_update(node_id.clone());
+        self._get_node_mut_opt(node_id.clone())
+            .unwrap()
+            .remove_super(super_id.clone());
+
+        match super_id {
+            None => {
+                if let Some(index) = self.node0.iter().position(|root_node_id| *root_node_id == node_id) {
+                    let mut remain = self.node0.split_off(index);
+                    remain.pop_front();
+                    self.node0.append(&mut remain);
+                }
+            }
+            Some(super_id) => {
+                self._pre_update(super_id.clone());
+                self._get_node_mut_opt(super_id.clone()).unwrap().remove_sub(node_id.clone());
+                self._post_update(super_id);
+            }
+        }
 
-        self._post_update(node_id);
+        self._reindex(node_id.clone());
+        self._post_update(node_id)
+    }
+
+    /// Sends an event to the observers, see [`subscribe`](Self::subscribe). While a transaction or an operation
+    /// is open, the event is kept back by its journal until the changes are committed.
+    // Test coverage: { unit = done, integration = none, doc = none } -> ok
+    fn _emit(&mut self, event: Event<K>) -> &mut Self {
+        if !self.observers.is_empty() {
+            match self.journal.last_mut() {
+                Some(journal) => journal.events.push(event),
+                None => self.observers.notify(&event),
+            }
+        }
 
         self
     }
@@ -723,6 +3254,22 @@ where
         }
     }
 
+    /// Collects ids of all nodes depth-first and pre-order, each node at its first occurrence only.
+    // Test coverage: { unit = done, integration = none, doc = none } -> ok
+    fn _enumerate_nodes(&self) -> Vec<Rc<K>> {
+        let mut cursor = Vec::new();
+        let mut visited = HashSet::new();
+        let mut node_ids = Vec::with_capacity(self.nodes.len());
+
+        while let Some(node_id) = self._next_from(&mut cursor) {
+            if visited.insert(node_id.clone()) {
+                node_ids.push(node_id);
+            }
+        }
+
+        node_ids
+    }
+
     /// Err(DuplicateNode)
     // Test coverage: { unit = done, integration = none, doc = none } -> ok
     fn _err_duplicate_node(&self, node_id: Rc<K>) -> Result<&Self, TaxonomyError<K>> {
@@ -780,7 +3327,7 @@ where
 
     /// Err(EdgeNotFound)
     // Test coverage: { unit = done, integration = none, doc = none } -> ok
-    fn _err_edge_not_found(&self, edge: &Edge<K>) -> Result<&Self, TaxonomyError<K>> {
+    fn _err_edge_not_found<P>(&self, edge: &Edge<K, P>) -> Result<&Self, TaxonomyError<K>> {
         match (edge.super_id(), edge.node_id()) {
             // Root node identified by id
             (None, node_id) => {
@@ -837,7 +3384,7 @@ where
 
     /// Err(NodeHasSubNodes)
     // Test coverage: { unit = done, integration = none, doc = none } -> ok
-    fn _err_node_has_sub(&self, node: &Node<K, V>) -> Result<&Self, TaxonomyError<K>> {
+    fn _err_node_has_sub(&self, node: &Node<K, V, M, E>) -> Result<&Self, TaxonomyError<K>> {
         if node.has_sub() {
             Err(NodeHasSubNode(node.id()))
         } else {
@@ -858,19 +3405,25 @@ where
     /// Returns node id cursor points to.
     // Test coverage: { unit = done, integration = none, doc = none } -> ok
     fn _get_node_id_from_cursor(&self) -> (Option<Rc<K>>, Option<Rc<K>>) {
-        match &self.cursor.last() {
+        match self.cursor.last() {
             None => (None, None),
-            Some(cursor) => match (cursor.super_id(), cursor.node_index()) {
-                (None, node_index) => {
-                    let node_id = self._get_root_node_id_at(node_index);
-                    (None, node_id)
-                }
-                (Some(super_id), node_index) => {
-                    let super_node = self._get_node_opt(super_id.clone()).unwrap();
-                    let node_id = super_node.sub_at(node_index);
-                    (Some(super_id), node_id)
-                }
-            },
+            Some(cursor) => self._get_node_id_at(cursor),
+        }
+    }
+
+    /// Returns super-node's id and node's id a given cursor points to.
+    // Test coverage: { unit = done, integration = none, doc = none } -> ok
+    pub(crate) fn _get_node_id_at(&self, cursor: &Cursor<K>) -> (Option<Rc<K>>, Option<Rc<K>>) {
+        match (cursor.super_id(), cursor.node_index()) {
+            (None, node_index) => {
+                let node_id = self._get_root_node_id_at(node_index);
+                (None, node_id)
+            }
+            (Some(super_id), node_index) => {
+                let super_node = self._get_node_opt(super_id.clone()).unwrap();
+                let node_id = super_node.sub_at(node_index);
+                (Some(super_id), node_id)
+            }
         }
     }
 
@@ -879,7 +3432,7 @@ where
     /// None
     ///
     // Test coverage: { unit = done, integration = none, doc = none } -> ok
-    fn _get_node_opt(&self, id: Rc<K>) -> Option<&Node<K, V>> {
+    fn _get_node_opt(&self, id: Rc<K>) -> Option<&Node<K, V, M, E>> {
         self.nodes.get(&id)
     }
 
@@ -888,7 +3441,7 @@ where
     /// Err(NodeNotFound)
     ///
     // Test coverage: { unit = done, integration = missing, doc = done } -> ok
-    fn _get_node_res(&self, node_id: Rc<K>) -> Result<&Node<K, V>, TaxonomyError<K>> {
+    fn _get_node_res(&self, node_id: Rc<K>) -> Result<&Node<K, V, M, E>, TaxonomyError<K>> {
         match self._get_node_opt(node_id.clone()) {
             None => Err(NodeNotFound(node_id)),
             Some(node) => Ok(node),
@@ -900,7 +3453,7 @@ where
     /// None
     ///
     // Test coverage: { unit = done, integration = none, doc = none } -> ok
-    fn _get_node_mut_opt(&mut self, id: Rc<K>) -> Option<&mut Node<K, V>> {
+    fn _get_node_mut_opt(&mut self, id: Rc<K>) -> Option<&mut Node<K, V, M, E>> {
         self.nodes.get_mut(&id)
     }
 
@@ -909,7 +3462,7 @@ where
     /// Err(NodeNotFound)
     ///
     // Test coverage: { unit = done, integration = missing, doc = done } -> ok
-    fn _get_node_mut_res(&mut self, node_id: Rc<K>) -> Result<&mut Node<K, V>, TaxonomyError<K>> {
+    fn _get_node_mut_res(&mut self, node_id: Rc<K>) -> Result<&mut Node<K, V, M, E>, TaxonomyError<K>> {
         match self._get_node_mut_opt(node_id.clone()) {
             None => Err(NodeNotFound(node_id)),
             Some(node) => Ok(node),
@@ -925,21 +3478,144 @@ where
         self.node0.iter().nth(index).cloned()
     }
 
+    /// Returns a new journal recording the current state of the taxonomy except for nodes, which are recorded
+    /// lazily by [`_pre_update`](Self::_pre_update).
+    // Test coverage: { unit = done, integration = none, doc = none } -> ok
+    fn _journal(&self, snapshot: SnapshotFn<K, V, M, E>) -> Journal<K, V, M, E> {
+        let cursor = self
+            .cursor
+            .iter()
+            .map(|cursor| Cursor::new(cursor.super_id(), cursor.node_index()))
+            .collect();
+
+        Journal::new(
+            snapshot,
+            self.node0.clone(),
+            self.last_updated_node.clone(),
+            cursor,
+            self.reachability.is_some(),
+            self.order.clone(),
+        )
+    }
+
+    /// Returns the id of the next node breadth-first or None if there is no more node in taxonomy.
+    ///
+    /// `queue` has to be initialized with the ids of the root-nodes. The sub-nodes of the node
+    /// returned are queued.
+    // Test coverage: { unit = done, integration = none, doc = none } -> ok
+    pub(crate) fn _next_breadth_first_from(&self, queue: &mut VecDeque<Rc<K>>) -> Option<Rc<K>> {
+        let node_id = queue.pop_front()?;
+        queue.extend(self._get_node_opt(node_id.clone()).unwrap().subs().iter().cloned());
+
+        Some(node_id)
+    }
+
+    /// Returns the ids of all sub-nodes of the nodes given, in order.
+    // Test coverage: { unit = done, integration = none, doc = none } -> ok
+    pub(crate) fn _next_level(&self, level: &[Rc<K>]) -> Vec<Rc<K>> {
+        level
+            .iter()
+            .filter_map(|node_id| self._get_node_opt(node_id.clone()))
+            .flat_map(|node| node.subs().iter().cloned())
+            .collect()
+    }
+
+    /// Advances a cursor stack owned by the caller in post-order and returns the id of the node
+    /// it points to afterwards or None if there is no more node in taxonomy.
+    // Test coverage: { unit = done, integration = none, doc = none } -> ok
+    pub(crate) fn _next_post_order_from(&self, cursor: &mut Vec<Cursor<K>>) -> Option<Rc<K>> {
+        match cursor.pop() {
+            // Cursor is None => init cursor with the first leaf below the first root-node if available
+            #[rustfmt::skip]
+            None => { // tarpaulin: exclude false positive from code coverage
+                if !self.node0.is_empty() {
+                    cursor.push(Cursor::new(None, 0));
+                    self._descend_to_first_leaf(cursor);
+                }
+            }
+            // Cursor pointed to a node which has been returned already.
+            // - if node has a co-node => push the co-node's first leaf to cursor
+            // - else => cursor points to the super-node, which is next
+            Some(last) => {
+                let count = match last.super_id() {
+                    None => self.node0.len(),
+                    Some(super_id) => self._get_node_opt(super_id).unwrap().count_subs(),
+                };
+
+                if last.node_index() + 1 < count {
+                    cursor.push(Cursor::new(last.super_id(), last.node_index() + 1));
+                    self._descend_to_first_leaf(cursor);
+                }
+            }
+        }
+
+        match cursor.last() {
+            None => None,
+            Some(last) => self._get_node_id_at(last).1,
+        }
+    }
+
+    /// Advances a cursor stack owned by the caller in pre-order, returning each node only once.
+    ///
+    /// Occurrences of a node not chosen by `occurrence` are skipped including their sub-nodes. `visited` collects
+    /// the ids of all nodes returned so far.
+    // Test coverage: { unit = done, integration = none, doc = none } -> ok
+    pub(crate) fn _next_unique_from(
+        &self,
+        cursor: &mut Vec<Cursor<K>>,
+        visited: &mut HashSet<Rc<K>>,
+        occurrence: Occurrence,
+    ) -> Option<Rc<K>> {
+        let mut node_id = self._next_from(cursor)?;
+
+        loop {
+            let chosen = match occurrence {
+                Occurrence::First => !visited.contains(&node_id),
+                Occurrence::Primary => {
+                    let super_id = cursor.last().and_then(|last| last.super_id());
+                    self._get_node_opt(node_id.clone()).unwrap().primary_super() == super_id
+                }
+            };
+
+            if chosen {
+                visited.insert(node_id.clone());
+                return Some(node_id);
+            }
+
+            // Skip this occurrence and its sub-nodes
+            self._skip_subs_from(cursor);
+            node_id = cursor.last().and_then(|last| self._get_node_id_at(last).1)?;
+        }
+    }
+
     /// Returns next node's id or None if there is no more node in taxonomy.
     // Test coverage: { unit = done, integration = none, doc = none } -> ok
     fn _next(&mut self) -> Option<Rc<K>> {
+        let mut cursor = std::mem::take(&mut self.cursor);
+        let node_id = self._next_from(&mut cursor);
+        self.cursor = cursor;
+
+        node_id
+    }
+
+    /// Advances a cursor stack owned by the caller in pre-order and returns the id of the node
+    /// it points to afterwards or None if there is no more node in taxonomy.
+    ///
+    /// Iterators keep their own cursor stack, so any number of them can traverse the taxonomy at the same time.
+    // Test coverage: { unit = done, integration = none, doc = none } -> ok
+    pub(crate) fn _next_from(&self, cursor: &mut Vec<Cursor<K>>) -> Option<Rc<K>> {
         // Start with last node in cursor
-        match self.cursor.last() {
+        match cursor.last() {
             // Cursor is None => init cursor with first node from node0 if available
             #[rustfmt::skip]
             None => { // tarpaulin: exclude false positive from code coverage
                 if !self.node0.is_empty() {
-                    self.cursor.push(Cursor::new(None, 0));
+                    cursor.push(Cursor::new(None, 0));
                 }
             }
             // Cursor points to a node => determine the next node
-            Some(cursor) => {
-                match (cursor.super_id(), cursor.node_index()) {
+            Some(last) => {
+                match (last.super_id(), last.node_index()) {
                     // Cursor points to a root-node.
                     // - if root-node has a sub-node => push first sub-node to cursor
                     // - else pop root-node from cursor and
@@ -950,11 +3626,11 @@ where
                         let node = self._get_node_opt(node_id.clone()).unwrap();
 
                         if node.has_sub() {
-                            self.cursor.push(Cursor::new(Some(node_id), 0));
+                            cursor.push(Cursor::new(Some(node_id), 0));
                         } else {
-                            self.cursor.pop();
+                            cursor.pop();
                             if node_index + 1 < self.node0.len() {
-                                self.cursor.push(Cursor::new(None, node_index + 1));
+                                cursor.push(Cursor::new(None, node_index + 1));
                             }
                         }
                     }
@@ -970,20 +3646,20 @@ where
                         let node = self._get_node_opt(node_id.clone()).unwrap();
 
                         if node.has_sub() {
-                            self.cursor.push(Cursor::new(Some(node_id), 0));
+                            cursor.push(Cursor::new(Some(node_id), 0));
                         } else {
-                            self.cursor.pop();
+                            cursor.pop();
                             let super_node = self._get_node_opt(super_id.clone()).unwrap();
 
                             if node_index + 1 < super_node.subs().len() {
-                                self.cursor.push(Cursor::new(Some(super_id), node_index + 1));
+                                cursor.push(Cursor::new(Some(super_id), node_index + 1));
                             } else {
-                                while let Some(cursor) = self.cursor.pop() {
-                                    match (cursor.super_id(), cursor.node_index()) {
+                                while let Some(popped) = cursor.pop() {
+                                    match (popped.super_id(), popped.node_index()) {
                                         // Root node found, set cursor to co-node if available
                                         (None, node_index) => {
                                             if node_index + 1 < self.node0.len() {
-                                                self.cursor.push(Cursor::new(None, node_index + 1));
+                                                cursor.push(Cursor::new(None, node_index + 1));
                                                 break; // found next Node, break while loop
                                             }
                                         }
@@ -991,7 +3667,7 @@ where
                                         (Some(super_id), node_index) => {
                                             let super_node = self._get_node_opt(super_id.clone()).unwrap();
                                             if node_index + 1 < super_node.count_subs() {
-                                                self.cursor.push(Cursor::new(Some(super_id), node_index + 1));
+                                                cursor.push(Cursor::new(Some(super_id), node_index + 1));
                                                 break; // found next Node, break while loop
                                             }
                                         }
@@ -1004,10 +3680,47 @@ where
             }
         }
 
-        match self.cursor.last() {
+        match cursor.last() {
             None => None,
-            Some(_) => self._get_node_id_from_cursor().1,
+            Some(last) => self._get_node_id_at(last).1,
+        }
+    }
+
+    /// Sends events kept back by a journal to the observers, see [`_emit`](Self::_emit).
+    // Test coverage: { unit = done, integration = none, doc = none } -> ok
+    fn _notify(&mut self, events: Vec<Event<K>>) -> &mut Self {
+        for event in events {
+            self.observers.notify(&event);
+        }
+
+        self
+    }
+
+    /// Returns an immutable reference to the payload of an edge, `None` stands for the root of the taxonomy.
+    /// Returns None if node is not found or the edge does not exist.
+    // Test coverage: { unit = done, integration = none, doc = none } -> ok
+    pub(crate) fn _payload_of(&self, super_id: Option<Rc<K>>, node_id: Rc<K>) -> Option<&E> {
+        self._get_node_opt(node_id)?.payload(super_id)
+    }
+
+    /// Returns every path from a root-node to a node, see [`paths_to`](Self::paths_to).
+    /// Panics if node is not found.
+    // Test coverage: { unit = done, integration = none, doc = none } -> ok
+    fn _paths_to(&self, node_id: Rc<K>) -> Vec<Vec<Rc<K>>> {
+        let node = self._get_node_opt(node_id.clone()).unwrap();
+
+        let mut paths = Vec::new();
+        if node.is_root() {
+            paths.push(vec![node_id.clone()]);
+        }
+        for super_id in node.supers() {
+            for mut path in self._paths_to(super_id) {
+                path.push(node_id.clone());
+                paths.push(path);
+            }
         }
+
+        paths
     }
 
     /// Post function to any node update, e.g. add, append, move, remove.
@@ -1022,27 +3735,233 @@ where
     /// Pre function to any node update, e.g. add, append, move, remove.
     // Test coverage: { unit = done, integration = none, doc = none } -> ok
     fn _pre_update(&mut self, node_id: Rc<K>) -> &mut Self {
-        if self._get_node_opt(node_id).is_some() {}
+        // Record the node's state for rollback or undo if a transaction or an operation is open
+        if let Some(journal) = self.journal.last_mut() {
+            let node = self.nodes.get(&node_id);
+            journal.record(node_id, node);
+        }
+
         self // return &mut Taxonomy
     }
 
-    /// Removes a node.
+    /// Runs `f` as an operation recorded in the history, if enabled, see [`enable_history`](Self::enable_history).
+    ///
+    /// If `f` returns `Err`, the taxonomy is rolled back and nothing is recorded. Operations called by other
+    /// operations or within a transaction are recorded by the enclosing journal only.
+    // Test coverage: { unit = done, integration = none, doc = none } -> ok
+    fn _record<F, T>(&mut self, operation: Operation<K>, f: F) -> Result<T, TaxonomyError<K>>
+    where
+        F: FnOnce(&mut Self) -> Result<T, TaxonomyError<K>>,
+    {
+        let snapshot = match &self.history {
+            Some(history) if self.journal.is_empty() => history.snapshot(),
+            _ => return f(self),
+        };
+        self.journal.push(self._journal(snapshot));
+
+        let result = f(self);
+
+        let mut journal = self.journal.pop().unwrap();
+        let events = std::mem::take(&mut journal.events);
+        match (&result, self.history.as_mut()) {
+            (Ok(_), Some(history)) => history.record(operation, journal),
+            (Ok(_), None) => (),
+            (Err(_), _) => {
+                self._rollback(journal);
+            }
+        }
+        if result.is_ok() {
+            self._notify(events);
+        }
+
+        result
+    }
+
+    /// Returns a mutable reference to the element or metadata of a node, recording the node once it is mutably
+    /// dereferenced, see [`RefMut`]. Returns `None` if node is not found.
+    // Test coverage: { unit = done, integration = none, doc = none } -> ok
+    fn _ref_mut<T>(
+        &mut self,
+        node_id: Rc<K>,
+        operation: Operation<K>,
+        get: fn(&Node<K, V, M, E>) -> &T,
+        get_mut: fn(&mut Node<K, V, M, E>) -> &mut T,
+    ) -> Option<RefMut<'_, K, V, M, E, T>> {
+        if !self.nodes.contains_key(&node_id) {
+            return None;
+        }
+
+        let (nodes, updates) = self._updates(operation);
+        let node = nodes.get_mut(&node_id).unwrap();
+        Some(RefMut::new(node_id, node, get, get_mut, updates))
+    }
+
+    /// Splits the taxonomy into its nodes and the updates shared by the references handed out by one call, see
+    /// [`RefMut`]. The call is recorded as `operation` if the history is enabled and no transaction is open.
+    // Test coverage: { unit = done, integration = none, doc = none } -> ok
+    #[allow(clippy::type_complexity)]
+    fn _updates(&mut self, operation: Operation<K>) -> (&mut HashMap<Rc<K>, Node<K, V, M, E>>, SharedUpdates<'_, K, V, M, E>) {
+        let entry = match &self.history {
+            Some(history) if self.journal.is_empty() => Some((operation, self._journal(history.snapshot()))),
+            _ => None,
+        };
+        let updates = Updates::new(entry, &mut self.journal, &mut self.history, &mut self.observers);
+
+        (&mut self.nodes, Rc::new(RefCell::new(updates)))
+    }
+
+    /// Returns the ids of a node's direct super-nodes or sub-nodes, depending on direction.
+    /// Returns an empty list if node is not found.
+    // Test coverage: { unit = done, integration = none, doc = none } -> ok
+    pub(crate) fn _relatives_of(&self, node_id: Rc<K>, direction: Direction) -> Vec<Rc<K>> {
+        match (self._get_node_opt(node_id), direction) {
+            (None, _) => Vec::new(),
+            (Some(node), Direction::Supers) => node.supers().into_iter().collect(),
+            (Some(node), Direction::Subs) => node.subs().iter().cloned().collect(),
+        }
+    }
+
+    /// Updates the reachability index, if enabled, for a node and all its sub-nodes recursively.
+    /// Has to be called whenever a node's super-nodes have changed.
+    // Test coverage: { unit = done, integration = none, doc = none } -> ok
+    fn _reindex(&mut self, node_id: Rc<K>) -> &mut Self {
+        self._reindex_nodes([node_id])
+    }
+
+    /// Updates the reachability index, if enabled, for several nodes and all their sub-nodes recursively.
+    /// Nodes not found are dropped from the index.
+    // Test coverage: { unit = done, integration = none, doc = none } -> ok
+    fn _reindex_nodes<I>(&mut self, node_ids: I) -> &mut Self
+    where
+        I: IntoIterator<Item = Rc<K>>,
+    {
+        if let Some(mut reachability) = self.reachability.take() {
+            // All entries have to be dropped before collecting, as they serve as a cache
+            let mut affected = HashSet::new();
+            for node_id in node_ids {
+                affected.extend(self._enumerate_subs(node_id.clone()));
+                affected.insert(node_id);
+            }
+
+            for node_id in affected.iter() {
+                reachability.remove(node_id);
+            }
+            for node_id in affected.into_iter().filter(|node_id| self.nodes.contains_key(node_id)) {
+                self._collect_ancestors(node_id, &mut reachability);
+            }
+
+            self.reachability = Some(reachability);
+        }
+
+        self
+    }
+
+    /// Returns the ids of a node and its descendants to remove as decided by `policy`, the node itself comes last.
+    /// Panics if node is not found.
+    // Test coverage: { unit = done, integration = none, doc = none } -> ok
+    fn _removal_set(&self, node_id: Rc<K>, policy: RemovalPolicy) -> Vec<Rc<K>> {
+        let mut descendants = self._enumerate_subs(node_id.clone());
+
+        if policy == RemovalPolicy::Orphans {
+            // Descendants with a super-node outside of the subtree remain reachable, so do their sub-nodes
+            let mut queue = descendants
+                .iter()
+                .filter(|id| {
+                    let node = self._get_node_opt((*id).clone()).unwrap();
+                    node.has_super_id(None)
+                        || node
+                            .supers()
+                            .iter()
+                            .any(|super_id| *super_id != node_id && !descendants.contains(super_id))
+                })
+                .cloned()
+                .collect::<Vec<Rc<K>>>();
+
+            let mut reachable = HashSet::new();
+            while let Some(id) = queue.pop() {
+                if reachable.insert(id.clone()) {
+                    queue.extend(self._sub_ids_of(Some(id)));
+                }
+            }
+
+            descendants.retain(|id| !reachable.contains(id));
+        }
+
+        let mut node_ids = descendants.into_iter().collect::<Vec<Rc<K>>>();
+        node_ids.push(node_id);
+
+        node_ids
+    }
+
+    /// Removes a set of nodes and returns them. Edges from and to nodes remaining in the taxonomy are removed
+    /// on the remaining nodes only, the returned nodes keep them. Nodes are removed in the order given.
+    /// Panics if a node is not found.
+    // Test coverage: { unit = done, integration = none, doc = none } -> ok
+    fn _remove_nodes(&mut self, node_ids: &[Rc<K>]) -> HashMap<Rc<K>, Node<K, V, M, E>> {
+        let removed = node_ids.iter().cloned().collect::<HashSet<Rc<K>>>();
+        let mut survivors = Vec::new();
+
+        for node_id in node_ids {
+            // Detach node from super-nodes remaining in the taxonomy
+            for super_id in self._relatives_of(node_id.clone(), Direction::Supers) {
+                if !removed.contains(&super_id) {
+                    self._pre_update(super_id.clone());
+                    self._get_node_mut_opt(super_id.clone()).unwrap().remove_sub(node_id.clone());
+                    self._post_update(super_id);
+                }
+            }
+
+            // Detach node from sub-nodes remaining in the taxonomy
+            for sub_id in self._relatives_of(node_id.clone(), Direction::Subs) {
+                if !removed.contains(&sub_id) {
+                    self._pre_update(sub_id.clone());
+                    self._get_node_mut_opt(sub_id.clone())
+                        .unwrap()
+                        .remove_super(Some(node_id.clone()));
+                    self._post_update(sub_id.clone());
+                    survivors.push(sub_id);
+                }
+            }
+        }
+
+        let mut nodes = HashMap::with_capacity(node_ids.len());
+        for node_id in node_ids {
+            let node = match self._get_node_opt(node_id.clone()).unwrap().has_super_id(None) {
+                true => self._remove_root_node(node_id.clone()),
+                false => self._remove_non_root_node(node_id.clone()),
+            };
+            nodes.insert(node_id.clone(), node.unwrap());
+            self._emit(Event::NodeRemoved(node_id.clone()));
+        }
+
+        // Ancestors of remaining sub-nodes have changed
+        for node_id in survivors {
+            self._reindex(node_id);
+        }
+
+        nodes
+    }
+
+    /// Removes a node and returns it, returns None if id is missing.
     // Test coverage: { unit = done, integration = none, doc = none } -> ok
-    fn _remove_non_root_node(&mut self, node_id: Rc<K>) -> &mut Self {
+    fn _remove_non_root_node(&mut self, node_id: Rc<K>) -> Option<Node<K, V, M, E>> {
         self._pre_update(node_id.clone());
 
         // Post update required before node_id is removed from taxonomy.
         self._post_update(node_id.clone());
 
         // Delete node from taxonomy
-        self.nodes.remove(&node_id);
+        let node = self.nodes.remove(&node_id);
+        if let Some(reachability) = &mut self.reachability {
+            reachability.remove(&node_id);
+        }
 
-        self // return &mut Taxonomy
+        node
     }
 
-    /// Removes a root-node identified by id, silently ignores if id is missing.
+    /// Removes a root-node identified by id and returns it, returns None if id is missing.
     // Test coverage: { unit = done, integration = none, doc = none } -> ok
-    fn _remove_root_node(&mut self, node_id: Rc<K>) -> &mut Self {
+    fn _remove_root_node(&mut self, node_id: Rc<K>) -> Option<Node<K, V, M, E>> {
         self._pre_update(node_id.clone());
 
         // Determine index of root-node in node0 and remove it
@@ -1063,8 +3982,218 @@ where
         self._post_update(node_id.clone());
 
         // Delete node from taxonomy
-        self.nodes.remove(&node_id);
+        let node = self.nodes.remove(&node_id);
+        if let Some(reachability) = &mut self.reachability {
+            reachability.remove(&node_id);
+        }
 
-        self // return &mut Taxonomy
+        node
+    }
+
+    /// Restores the state recorded by a journal and returns a journal recording the state replaced, see
+    /// [`undo`](Self::undo). The reachability index is kept enabled or disabled. Panics if the history is disabled.
+    // Test coverage: { unit = done, integration = none, doc = none } -> ok
+    fn _restore(&mut self, mut journal: Journal<K, V, M, E>) -> Journal<K, V, M, E> {
+        let mut inverse = self._journal(self.history.as_ref().unwrap().snapshot());
+        let mut events = Vec::new();
+        for (node_id, node) in journal.nodes.iter() {
+            let current = self.nodes.get(node_id);
+            inverse.record(node_id.clone(), current);
+            events.extend(Self::_restore_events(node_id.clone(), current, node.as_ref()));
+        }
+
+        journal.reachability = inverse.reachability;
+        self._rollback(journal);
+        self._notify(events);
+
+        inverse
+    }
+
+    /// Returns the events reporting the changes made to a node by restoring it from a journal, see
+    /// [`undo`](Self::undo). Elements, metadata and payloads are not compared, thus a node remaining in the taxonomy
+    /// is reported as mutated.
+    // Test coverage: { unit = done, integration = none, doc = none } -> ok
+    fn _restore_events(node_id: Rc<K>, current: Option<&Node<K, V, M, E>>, restored: Option<&Node<K, V, M, E>>) -> Vec<Event<K>> {
+        let super_ids = |node: Option<&Node<K, V, M, E>>| node.map(|node| node.super_ids()).unwrap_or_default();
+        let (current_super_ids, restored_super_ids) = (super_ids(current), super_ids(restored));
+
+        let mut events = Vec::new();
+        match (current, restored) {
+            (_, None) => {
+                if current.is_some() {
+                    events.push(Event::NodeRemoved(node_id));
+                }
+            }
+            (None, Some(node)) => {
+                let primary_super = node.primary_super();
+                events.push(Event::NodeAdded(primary_super.clone(), node_id.clone()));
+                for super_id in restored_super_ids.into_iter().filter(|super_id| *super_id != primary_super) {
+                    events.push(Event::EdgeAdded(super_id, node_id.clone()));
+                }
+            }
+            (Some(_), Some(_)) => {
+                for super_id in current_super_ids
+                    .iter()
+                    .filter(|super_id| !restored_super_ids.contains(super_id))
+                {
+                    events.push(Event::EdgeRemoved(super_id.clone(), node_id.clone()));
+                }
+                for super_id in restored_super_ids
+                    .iter()
+                    .filter(|super_id| !current_super_ids.contains(super_id))
+                {
+                    events.push(Event::EdgeAdded(super_id.clone(), node_id.clone()));
+                }
+                events.push(Event::ElementMutated(node_id));
+            }
+        }
+
+        events
+    }
+
+    /// Restores the state recorded by a journal, see [`transaction`](Self::transaction).
+    // Test coverage: { unit = done, integration = none, doc = none } -> ok
+    fn _rollback(&mut self, journal: Journal<K, V, M, E>) -> &mut Self {
+        let node_ids = journal.nodes.keys().cloned().collect::<Vec<_>>();
+        for (node_id, node) in journal.nodes {
+            match node {
+                None => self.nodes.remove(&node_id),
+                Some(node) => self.nodes.insert(node_id, node),
+            };
+        }
+
+        self.node0 = journal.node0;
+        self.last_updated_node = journal.last_updated_node;
+        self.cursor = journal.cursor;
+        self.order = journal.order;
+
+        // Only nodes recorded by the journal and their sub-nodes might have other ancestors now, unless the index has
+        // been disabled meanwhile and has to be rebuilt
+        match (journal.reachability, self.has_reachability_index()) {
+            (true, true) => self._reindex_nodes(node_ids),
+            (true, false) => self.enable_reachability_index(),
+            (false, _) => self.disable_reachability_index(),
+        }
+    }
+
+    /// Returns the index to insert a node at among the sub-nodes of a super-node or among the root-nodes if
+    /// `super_id` is `None`. If the taxonomy is kept sorted, the index given by the comparator is returned, which
+    /// is behind all sub-nodes comparing less or equal. Otherwise, `index` is returned unchanged.
+    /// Panics if the node is not found.
+    // Test coverage: { unit = done, integration = none, doc = none } -> ok
+    fn _sorted_index(&self, super_id: Option<Rc<K>>, node_id: Rc<K>, index: usize) -> usize {
+        match &self.order {
+            None => index,
+            Some(comparator) => {
+                let element = self._get_node_opt(node_id).unwrap().get();
+                let sub_ids = self._sub_ids_of(super_id);
+                sub_ids
+                    .iter()
+                    .position(|id| comparator.compare(self._get_node_opt(id.clone()).unwrap().get(), element) == Ordering::Greater)
+                    .unwrap_or(sub_ids.len())
+            }
+        }
+    }
+
+    /// Sorts the sub-nodes of a super-node or the root-nodes if `super_id` is `None`.
+    /// Panics if super-node is not found.
+    // Test coverage: { unit = done, integration = none, doc = none } -> ok
+    fn _sort_subs_by<F>(&mut self, super_id: Option<Rc<K>>, cmp: &mut F) -> &mut Self
+    where
+        F: FnMut(&V, &V) -> Ordering,
+    {
+        let mut sub_ids = self._sub_ids_of(super_id.clone());
+        let unsorted = sub_ids.clone();
+        sub_ids.sort_by(|a, b| {
+            cmp(
+                self._get_node_opt(a.clone()).unwrap().get(),
+                self._get_node_opt(b.clone()).unwrap().get(),
+            )
+        });
+
+        // Sub-nodes which changed their position are reported as moved
+        for (before, after) in unsorted.into_iter().zip(sub_ids.iter()) {
+            if before != *after {
+                self._emit(Event::NodeMoved(after.clone(), super_id.clone(), super_id.clone()));
+            }
+        }
+
+        match super_id {
+            None => self.node0 = sub_ids.into_iter().collect(),
+            Some(super_id) => {
+                self._pre_update(super_id.clone());
+                self._get_node_mut_opt(super_id.clone())
+                    .unwrap()
+                    .set_subs(sub_ids.into_iter().collect());
+                self._post_update(super_id);
+            }
+        }
+
+        self
+    }
+
+    /// Returns the ids of a super-node's sub-nodes or of the root-nodes if super_id is None.
+    /// Returns an empty list if super-node is not found.
+    // Test coverage: { unit = done, integration = none, doc = none } -> ok
+    pub(crate) fn _sub_ids_of(&self, super_id: Option<Rc<K>>) -> Vec<Rc<K>> {
+        match super_id {
+            None => self.node0.iter().cloned().collect(),
+            Some(super_id) => self._relatives_of(super_id, Direction::Subs),
+        }
+    }
+
+    /// Moves a cursor stack owned by the caller to the next co-node without visiting sub-nodes.
+    /// If there is no co-node, cursor is popped until it points to a node with a next co-node or is empty.
+    // Test coverage: { unit = done, integration = none, doc = none } -> ok
+    fn _skip_subs_from(&self, cursor: &mut Vec<Cursor<K>>) {
+        while let Some(last) = cursor.pop() {
+            let count = match last.super_id() {
+                None => self.node0.len(),
+                Some(super_id) => self._get_node_opt(super_id).unwrap().count_subs(),
+            };
+
+            if last.node_index() + 1 < count {
+                cursor.push(Cursor::new(last.super_id(), last.node_index() + 1));
+                break; // found next node, break while loop
+            }
+        }
+    }
+
+    /// Moves a node to `index` among the sub-nodes of a super-node or among the root-nodes if `super_id` is `None`.
+    /// If `index` is out of bound, node is moved to the back. Panics if super-node is not found.
+    // Test coverage: { unit = done, integration = none, doc = none } -> ok
+    fn _reorder(&mut self, super_id: Option<Rc<K>>, node_id: Rc<K>, index: usize) -> &mut Self {
+        self._pre_update(node_id.clone());
+        self._emit(Event::NodeMoved(node_id.clone(), super_id.clone(), super_id.clone()));
+
+        match super_id {
+            None => {
+                if let Some(position) = self.node0.iter().position(|root_node_id| *root_node_id == node_id) {
+                    let mut remain = self.node0.split_off(position);
+                    remain.pop_front();
+                    self.node0.append(&mut remain);
+
+                    let mut remain = self.node0.split_off(index.min(self.node0.len()));
+                    remain.push_front(node_id.clone());
+                    self.node0.append(&mut remain);
+                }
+            }
+            Some(super_id) => {
+                self._pre_update(super_id.clone());
+                let super_node = self._get_node_mut_opt(super_id).unwrap();
+                super_node.remove_sub(node_id.clone());
+                super_node.append_sub_at(node_id.clone(), index);
+            }
+        }
+
+        self._post_update(node_id);
+
+        self
+    }
+
+    /// Returns the ids of the root-nodes, in order.
+    // Test coverage: { unit = none, integration = none, doc = none } -> ok
+    pub(crate) fn _root_node_ids(&self) -> impl Iterator<Item = Rc<K>> + '_ {
+        self.node0.iter().cloned()
     }
 }
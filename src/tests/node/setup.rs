@@ -46,7 +46,7 @@ pub(crate) fn setup_two_root_nodes_with_first_being_sub_of_second() -> (Node<Uui
     let mut root_node2 = Node::new(org);
     root_node2.append_sub(Rc::new(dep.id()));
 
-    root_node1.add_super(Some(Rc::new(org_id.clone())));
+    root_node1.add_super(Some(Rc::new(org_id)));
     root_node2.append_sub(Rc::new(empl_id));
 
     (root_node1, root_node2)
@@ -1,8 +1,11 @@
 #[cfg(test)]
+#[allow(clippy::module_inception)]
 mod tests {
     use crate::tests::node::*;
-    use crate::Identity;
+    use crate::{Identity, Node};
+    use std::collections::LinkedList;
     use std::rc::Rc;
+    use uuid::Uuid;
 
     #[test]
     fn test_root_only_node() {
@@ -10,13 +13,13 @@ mod tests {
 
         assert_eq!(node.subs().len(), 0);
         assert_eq!(node.count_subs(), 0);
-        assert_eq!(node.has_sub(), false);
+        assert!(!node.has_sub());
 
         assert_eq!(node.supers().len(), 0);
         assert_eq!(node.count_super(), 1);
-        assert_eq!(node.has_super(), true);
+        assert!(node.has_super());
 
-        assert_eq!(node.is_root(), true);
+        assert!(node.is_root());
     }
 
     #[test]
@@ -25,13 +28,13 @@ mod tests {
 
         assert_eq!(node.subs().len(), 1);
         assert_eq!(node.count_subs(), 1);
-        assert_eq!(node.has_sub(), true);
+        assert!(node.has_sub());
 
         assert_eq!(node.supers().len(), 0);
         assert_eq!(node.count_super(), 1);
-        assert_eq!(node.has_super(), true);
+        assert!(node.has_super());
 
-        assert_eq!(node.is_root(), true);
+        assert!(node.is_root());
     }
 
     #[test]
@@ -40,23 +43,23 @@ mod tests {
 
         assert_eq!(root_node1.subs().len(), 0);
         assert_eq!(root_node1.count_subs(), 0);
-        assert_eq!(root_node1.has_sub(), false);
+        assert!(!root_node1.has_sub());
 
         assert_eq!(root_node1.supers().len(), 1);
         assert_eq!(root_node1.count_super(), 2);
-        assert_eq!(root_node1.has_super(), true);
+        assert!(root_node1.has_super());
 
-        assert_eq!(root_node1.is_root(), true);
+        assert!(root_node1.is_root());
 
         assert_eq!(root_node2.subs().len(), 2);
         assert_eq!(root_node2.count_subs(), 2);
-        assert_eq!(root_node2.has_sub(), true);
+        assert!(root_node2.has_sub());
 
         assert_eq!(root_node2.supers().len(), 0);
         assert_eq!(root_node2.count_super(), 1);
-        assert_eq!(root_node2.has_super(), true);
+        assert!(root_node2.has_super());
 
-        assert_eq!(root_node2.is_root(), true);
+        assert!(root_node2.is_root());
 
         // Check if last sub of root_node2 is root_node1
         assert_eq!(*root_node2.subs().back().unwrap(), root_node1.id)
@@ -68,12 +71,10 @@ mod tests {
 
         assert_eq!(node.subs().len(), 4);
         assert_eq!(node.count_subs(), 4);
-        assert_eq!(node.has_sub(), true);
+        assert!(node.has_sub());
 
-        let mut k: usize = 0;
-        for sub in node.subs() {
+        for (k, sub) in node.subs().iter().enumerate() {
             assert_eq!(*sub, ids[k]);
-            k += 1;
         }
     }
 
@@ -87,7 +88,7 @@ mod tests {
         node.append_sub(sw_id.clone());
         assert_eq!(node.subs().len(), 5);
         assert_eq!(node.count_subs(), 5);
-        assert_eq!(node.has_sub(), true);
+        assert!(node.has_sub());
         assert_eq!(*node.subs().back().unwrap(), sw_id);
     }
 
@@ -114,16 +115,14 @@ mod tests {
         assert_eq!(*node.subs().iter().nth(index).unwrap(), sw_id);
 
         // Check order of sub-nodes
-        let mut k: usize = 0;
         let mut i: usize = 0;
-        for sub in node.subs() {
+        for (k, sub) in node.subs().iter().enumerate() {
             if k == index {
                 assert_eq!(*sub, sw_id);
             } else {
                 assert_eq!(*sub, ids[i]);
                 i += 1;
             }
-            k += 1;
         }
     }
 
@@ -139,16 +138,14 @@ mod tests {
         assert_eq!(*node.subs().iter().nth(node.subs().len() - 1).unwrap(), sw_id);
 
         // Check order of sub-nodes
-        let mut k: usize = 0;
         let mut i: usize = 0;
-        for sub in node.subs() {
+        for (k, sub) in node.subs().iter().enumerate() {
             if k == pos {
                 assert_eq!(*sub, sw_id);
             } else {
                 assert_eq!(*sub, ids[i]);
                 i += 1;
             }
-            k += 1;
         }
     }
 
@@ -162,7 +159,7 @@ mod tests {
         node.prepend_sub(sw_id.clone());
         assert_eq!(node.subs().len(), 5);
         assert_eq!(node.count_subs(), 5);
-        assert_eq!(node.has_sub(), true);
+        assert!(node.has_sub());
         assert_eq!(*node.subs().front().unwrap(), sw_id);
     }
 
@@ -170,20 +167,20 @@ mod tests {
     fn test_root_node_with_four_subs_is_root_ok() {
         let (root_node, sub_nodes) = setup_root_node_with_four_subs_in_vec();
 
-        assert_eq!(root_node.is_root(), true);
+        assert!(root_node.is_root());
         for sub in sub_nodes {
-            assert_eq!(sub.is_root(), false);
+            assert!(!sub.is_root());
         }
     }
 
     #[test]
     fn test_root_node_with_four_subs_remove_sub_ok() {
         let (mut root_node, sub_nodes) = setup_root_node_with_four_subs_in_vec();
-        let seq = vec![2, 3, 0, 1]; // Removes from middle pos, back pos, front pos and last pos
+        let seq = [2, 3, 0, 1]; // Removes from middle pos, back pos, front pos and last pos
         let mut k: usize = 0;
 
         while k <= 3 {
-            let node_id = sub_nodes.iter().nth(seq[k]).unwrap().id.clone();
+            let node_id = sub_nodes.get(seq[k]).unwrap().id.clone();
             root_node.remove_sub(node_id);
 
             assert_eq!(root_node.subs().len(), 3 - k);
@@ -197,11 +194,11 @@ mod tests {
     #[test]
     fn remove_super() {
         let (mut root_node, mut sub_nodes) = setup_root_node_with_four_subs_in_vec();
-        let seq = vec![2, 3, 0, 1]; // Removes from middle pos, back pos, front pos and last pos
+        let seq = [2, 3, 0, 1]; // Removes from middle pos, back pos, front pos and last pos
         let mut k: usize = 0;
 
         while k <= 3 {
-            let sub_node = sub_nodes.iter_mut().nth(seq[k]).unwrap();
+            let sub_node = sub_nodes.get_mut(seq[k]).unwrap();
             let root_node_id = root_node.id();
             sub_node.remove_super(Some(root_node_id));
 
@@ -211,18 +208,166 @@ mod tests {
         }
 
         root_node.remove_super(None);
-        assert_eq!(root_node.has_super(), false);
+        assert!(!root_node.has_super());
     }
 
     #[test]
     fn test_sub_at() {
         let (node, ids) = setup_root_node_with_four_subs();
 
-        let mut i: usize = 0;
-        for id in ids {
+        for (i, id) in ids.into_iter().enumerate() {
             assert_eq!(node.sub_at(i), Some(id));
-            i += 1;
         }
         assert_eq!(node.sub_at(node.count_subs()), None);
     }
+
+    #[test]
+    fn primary_super() {
+        let (root_node1, root_node2) = setup_two_root_nodes_with_first_being_sub_of_second();
+
+        // Root-nodes default to the root as primary super-node
+        assert_eq!(root_node1.primary_super(), None);
+        assert_eq!(root_node2.primary_super(), None);
+
+        let (_, sub_nodes) = setup_root_node_with_four_subs_in_vec();
+        let sub_node = sub_nodes.first().unwrap();
+        assert!(sub_node.primary_super().is_some());
+    }
+
+    #[test]
+    fn set_primary_super() {
+        let (mut root_node1, root_node2) = setup_two_root_nodes_with_first_being_sub_of_second();
+
+        assert!(root_node1.has_super_id(None));
+        assert!(root_node1.has_super_id(Some(root_node2.id())));
+        assert!(!root_node1.has_super_id(Some(root_node1.id())));
+
+        // Designating a super-node which is not a super-node is ignored
+        root_node1.set_primary_super(Some(root_node1.id()));
+        assert_eq!(root_node1.primary_super(), None);
+
+        root_node1.set_primary_super(Some(root_node2.id()));
+        assert_eq!(root_node1.primary_super(), Some(root_node2.id()));
+
+        // Removing the primary super-node removes the designation
+        root_node1.remove_super(Some(root_node2.id()));
+        assert_eq!(root_node1.primary_super(), None);
+        assert!(!root_node1.has_super_id(Some(root_node2.id())));
+    }
+
+    #[test]
+    fn set_subs() {
+        let (mut node, ids) = setup_root_node_with_four_subs();
+
+        let reversed = ids.iter().rev().cloned().collect::<LinkedList<_>>();
+        node.set_subs(reversed.clone());
+        assert_eq!(*node.subs(), reversed);
+        assert_eq!(node.count_subs(), 4);
+    }
+
+    #[test]
+    fn snapshot() {
+        let (root_node1, _) = setup_two_root_nodes_with_first_being_sub_of_second();
+
+        let snapshot = root_node1.snapshot();
+        assert_eq!(snapshot, root_node1);
+        assert_eq!(snapshot.supers(), root_node1.supers());
+    }
+
+    #[test]
+    fn super_ids() {
+        let (root_node1, root_node2) = setup_two_root_nodes_with_first_being_sub_of_second();
+
+        assert_eq!(root_node2.super_ids(), vec![None]);
+        assert_eq!(root_node1.super_ids(), vec![None, Some(root_node2.id())]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn from_parts() {
+        let (root_node1, root_node2) = setup_two_root_nodes_with_first_being_sub_of_second();
+        let org_id = root_node2.id();
+
+        let node: Node<Uuid, Concept, String, u8> = Node::from_parts(
+            root_node1.get().clone(),
+            vec![Some(org_id.clone()), None, Some(org_id.clone())],
+            Some(Some(org_id.clone())),
+            LinkedList::new(),
+            Some("created by admin".to_string()),
+            vec![(None, 1), (Some(org_id.clone()), 2)],
+        );
+        assert_eq!(node.id(), root_node1.id());
+        assert_eq!(node.super_ids(), vec![Some(org_id.clone()), None]);
+        assert_eq!(node.primary_super(), Some(org_id.clone()));
+        assert_eq!(node.metadata(), Some(&"created by admin".to_string()));
+        assert_eq!(node.payload(Some(org_id)), Some(&2));
+        assert!(node.is_root());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn designated_primary() {
+        let (mut root_node1, root_node2) = setup_two_root_nodes_with_first_being_sub_of_second();
+
+        assert_eq!(root_node1.designated_primary(), None);
+        root_node1.set_primary_super(Some(root_node2.id()));
+        assert_eq!(root_node1.designated_primary(), Some(Some(root_node2.id())));
+        root_node1.set_primary_super(None);
+        assert_eq!(root_node1.designated_primary(), Some(None));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn payloads() {
+        let mut node: Node<Uuid, Concept, (), u8> = Node::new(Concept::new("Devices"));
+        assert!(node.payloads().is_empty());
+
+        node.add_super(None);
+        node.set_payload(None, 1);
+        assert_eq!(node.payloads(), &[(None, 1)]);
+    }
+
+    #[test]
+    fn metadata() {
+        let mut node: Node<Uuid, Concept, String> = Node::new(Concept::new("Devices"));
+        assert_eq!(node.metadata(), None);
+        assert_eq!(node.metadata_mut(), None);
+
+        assert_eq!(node.set_metadata("created by admin".to_string()), None);
+        assert_eq!(node.metadata(), Some(&"created by admin".to_string()));
+
+        node.metadata_mut().unwrap().push_str(" and root");
+        assert_eq!(
+            node.set_metadata("created by root".to_string()),
+            Some("created by admin and root".to_string())
+        );
+
+        assert_eq!(node.remove_metadata(), Some("created by root".to_string()));
+        assert_eq!(node.remove_metadata(), None);
+        assert_eq!(node.metadata(), None);
+    }
+
+    #[test]
+    fn payload() {
+        let (mut root_node1, root_node2) = setup_two_root_nodes_with_first_being_sub_of_second();
+        let mut node: Node<Uuid, Concept, (), u8> = Node::new(Concept::new("Devices"));
+        assert_eq!(node.payload(None), None);
+        assert_eq!(node.payload_mut(None), None);
+
+        assert_eq!(node.set_payload(None, 1), None);
+        assert_eq!(node.set_payload(Some(root_node2.id()), 2), None);
+        assert_eq!(node.payload(None), Some(&1));
+        assert_eq!(node.payload(Some(root_node2.id())), Some(&2));
+
+        *node.payload_mut(None).unwrap() += 2;
+        assert_eq!(node.set_payload(None, 4), Some(3));
+
+        assert_eq!(node.remove_payload(None), Some(4));
+        assert_eq!(node.remove_payload(None), None);
+        assert_eq!(node.payload(Some(root_node2.id())), Some(&2));
+
+        // Removing a super-node removes the payload of the edge to it
+        root_node1.remove_super(Some(root_node2.id()));
+        assert_eq!(root_node1.payload(Some(root_node2.id())), None);
+    }
 }
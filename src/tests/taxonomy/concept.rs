@@ -1,7 +1,10 @@
 use crate::Identity;
 use uuid::Uuid;
 
+use std::fmt::Display;
+
 #[derive(Eq, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Concept {
     id: Uuid,
     #[allow(dead_code)]
@@ -16,6 +19,14 @@ impl Concept {
         }
     }
 
+    #[allow(dead_code)]
+    pub fn with_id(id: Uuid, name: &str) -> Concept {
+        Concept {
+            id,
+            name: name.to_owned(),
+        }
+    }
+
     #[allow(dead_code)]
     pub fn name(&self) -> String {
         self.name.clone()
@@ -27,3 +38,9 @@ impl Identity<Uuid> for Concept {
         self.id
     }
 }
+
+impl Display for Concept {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
@@ -47,6 +47,7 @@ pub fn setup_tax_empty() -> Taxonomy<Uuid, Concept> {
 /// │   └── Katzen (duplicate)
 /// └── Tierschutz
 ///
+#[allow(clippy::type_complexity)]
 pub(crate) fn setup_tax_animals() -> (
     Taxonomy<Uuid, Concept>,
     HashMap<&'static str, Uuid>,
@@ -171,38 +172,38 @@ pub(crate) fn setup_tax_animals() -> (
     let _ = tax.add(None, c_tierschutz.clone());
 
     // Traversal
-    list.push_back((id_tiere.clone(), c_tiere.clone()));
-    list.push_back((id_haustiere.clone(), c_haustiere.clone()));
-    list.push_back((id_fische.clone(), c_fische.clone()));
-    list.push_back((id_hunde.clone(), c_hunde.clone()));
-    list.push_back((id_doggen.clone(), c_doggen.clone()));
-    list.push_back((id_schaeferhunde.clone(), c_schaeferhunde.clone()));
-    list.push_back((id_katzen.clone(), c_katzen.clone()));
-    list.push_back((id_nutztiere.clone(), c_nutztiere.clone()));
-    list.push_back((id_gefluegel.clone(), c_gefluegel.clone()));
-    list.push_back((id_huehner.clone(), c_huehner.clone()));
-    list.push_back((id_puten.clone(), c_puten.clone()));
-    list.push_back((id_rind.clone(), c_rind.clone()));
-    list.push_back((id_zootiere.clone(), c_zootiere.clone()));
-    list.push_back((id_affen.clone(), c_affen.clone()));
-    list.push_back((id_schlangen.clone(), c_schlangen.clone()));
-    list.push_back((id_saeugetiere.clone(), c_saeugetiere.clone()));
-    list.push_back((id_affen.clone(), c_affen.clone()));
-    list.push_back((id_hunde.clone(), c_hunde.clone()));
-    list.push_back((id_doggen.clone(), c_doggen.clone()));
-    list.push_back((id_schaeferhunde.clone(), c_schaeferhunde.clone()));
-    list.push_back((id_katzen.clone(), c_katzen.clone()));
-    list.push_back((id_waale.clone(), c_waale.clone()));
-    list.push_back((id_voegel.clone(), c_voegel.clone()));
-    list.push_back((id_huehner.clone(), c_huehner.clone()));
-    list.push_back((id_puten.clone(), c_puten.clone()));
-    list.push_back((id_tierhalter.clone(), c_tierhalter.clone()));
-    list.push_back((id_tierheime.clone(), c_tierheime.clone()));
-    list.push_back((id_hunde.clone(), c_hunde.clone()));
-    list.push_back((id_doggen.clone(), c_doggen.clone()));
-    list.push_back((id_schaeferhunde.clone(), c_schaeferhunde.clone()));
-    list.push_back((id_katzen.clone(), c_katzen.clone()));
-    list.push_back((id_tierschutz.clone(), c_tierschutz.clone()));
+    list.push_back((id_tiere, c_tiere.clone()));
+    list.push_back((id_haustiere, c_haustiere.clone()));
+    list.push_back((id_fische, c_fische.clone()));
+    list.push_back((id_hunde, c_hunde.clone()));
+    list.push_back((id_doggen, c_doggen.clone()));
+    list.push_back((id_schaeferhunde, c_schaeferhunde.clone()));
+    list.push_back((id_katzen, c_katzen.clone()));
+    list.push_back((id_nutztiere, c_nutztiere.clone()));
+    list.push_back((id_gefluegel, c_gefluegel.clone()));
+    list.push_back((id_huehner, c_huehner.clone()));
+    list.push_back((id_puten, c_puten.clone()));
+    list.push_back((id_rind, c_rind.clone()));
+    list.push_back((id_zootiere, c_zootiere.clone()));
+    list.push_back((id_affen, c_affen.clone()));
+    list.push_back((id_schlangen, c_schlangen.clone()));
+    list.push_back((id_saeugetiere, c_saeugetiere.clone()));
+    list.push_back((id_affen, c_affen.clone()));
+    list.push_back((id_hunde, c_hunde.clone()));
+    list.push_back((id_doggen, c_doggen.clone()));
+    list.push_back((id_schaeferhunde, c_schaeferhunde.clone()));
+    list.push_back((id_katzen, c_katzen.clone()));
+    list.push_back((id_waale, c_waale.clone()));
+    list.push_back((id_voegel, c_voegel.clone()));
+    list.push_back((id_huehner, c_huehner.clone()));
+    list.push_back((id_puten, c_puten.clone()));
+    list.push_back((id_tierhalter, c_tierhalter.clone()));
+    list.push_back((id_tierheime, c_tierheime.clone()));
+    list.push_back((id_hunde, c_hunde.clone()));
+    list.push_back((id_doggen, c_doggen.clone()));
+    list.push_back((id_schaeferhunde, c_schaeferhunde.clone()));
+    list.push_back((id_katzen, c_katzen.clone()));
+    list.push_back((id_tierschutz, c_tierschutz.clone()));
 
     (tax, ids, list)
 }
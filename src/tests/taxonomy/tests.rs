@@ -1,11 +1,19 @@
 #[cfg(test)]
+#[allow(clippy::module_inception)]
 mod tests {
+    use crate::iter::Direction;
     use crate::tests::taxonomy::*;
     use crate::TaxonomyError::*;
-    use crate::{Edge, Identity, Node};
+    use crate::{
+        Cursor, Edge, Event, Glyphs, Identity, ImportError, Indent, Journal, Node, Occurrence, Operation, RemovalPolicy,
+        SkosConcept, Taxonomy, TaxonomyError, TraversalOrder, TreeStyle,
+    };
 
     use uuid::Uuid;
 
+    use std::cell::RefCell;
+    use std::cmp::Ordering;
+    use std::collections::{HashMap, HashSet, LinkedList, VecDeque};
     use std::rc::Rc;
 
     #[test]
@@ -16,6 +24,7 @@ mod tests {
         assert_eq!(tax.node0.len(), 0);
         assert!(tax.last_updated_node.is_none());
         assert_eq!(tax.cursor.len(), 0);
+        assert!(tax.reachability.is_none());
     }
 
     #[test]
@@ -54,24 +63,24 @@ mod tests {
         let id_nagetiere = Rc::new(c_nagetiere.id());
         let super_id = list.iter().nth(1).unwrap().0;
 
-        let counter_subs_pre = tax._get_node_opt(Rc::new(super_id.clone())).unwrap().count_subs();
+        let counter_subs_pre = tax._get_node_opt(Rc::new(super_id)).unwrap().count_subs();
 
-        let result = tax.add(Some(super_id.clone()), c_nagetiere.clone());
+        let result = tax.add(Some(super_id), c_nagetiere.clone());
         assert!(result.is_ok());
 
-        let counter_subs_post = tax._get_node_opt(Rc::new(super_id.clone())).unwrap().count_subs();
+        let counter_subs_post = tax._get_node_opt(Rc::new(super_id)).unwrap().count_subs();
 
         assert_eq!(tax.nodes.len(), counter + 2);
         assert_eq!(counter_subs_post, counter_subs_pre + 1);
         assert_eq!(tax.last_updated_node().unwrap(), id_nagetiere);
         assert_eq!(
-            *tax._get_node_opt(Rc::new(super_id.clone())).unwrap().subs().back().unwrap(),
+            *tax._get_node_opt(Rc::new(super_id)).unwrap().subs().back().unwrap(),
             id_nagetiere
         );
         assert_eq!(tax._get_node_opt(id_nagetiere.clone()).unwrap().supers().len(), 1);
 
         // Test adding a duplicate node
-        let result = tax.add(Some(super_id.clone()), c_nagetiere).err();
+        let result = tax.add(Some(super_id), c_nagetiere).err();
         let expectation = DuplicateNode(id_nagetiere);
         assert_eq!(result, Some(expectation));
     }
@@ -84,58 +93,58 @@ mod tests {
         // Append a non-existing node to a non-existing super-node
         let node_id = Uuid::new_v4();
         let super_id = Uuid::new_v4();
-        let result = tax.append(Some(super_id), node_id.clone()).err();
+        let result = tax.append(Some(super_id), node_id).err();
         let expectation = NodeNotFound(Rc::new(node_id));
         assert_eq!(result, Some(expectation));
         assert_eq!(tax.last_updated_node().unwrap(), last_updated_node);
 
         // Append an existing node to a non-existing super-node
-        let id_affen = ids.get("Affen").unwrap().clone();
+        let id_affen = *ids.get("Affen").unwrap();
         let super_id = Uuid::new_v4();
-        let result = tax.append_at(Some(super_id), id_affen.clone(), 0).err();
+        let result = tax.append_at(Some(super_id), id_affen, 0).err();
         let expectation = NodeNotFound(Rc::new(super_id));
         assert_eq!(result, Some(expectation));
         assert_eq!(tax.last_updated_node().unwrap(), last_updated_node);
 
         // Append an existing node to one of its own super-nodes
-        let id_affen = ids.get("Affen").unwrap().clone();
-        let id_zootiere = ids.get("Zootiere").unwrap().clone();
-        let result = tax.append(Some(id_zootiere), id_affen.clone()).err();
+        let id_affen = *ids.get("Affen").unwrap();
+        let id_zootiere = *ids.get("Zootiere").unwrap();
+        let result = tax.append(Some(id_zootiere), id_affen).err();
         let expectation = DuplicateSubNode(Rc::new(id_zootiere), Rc::new(id_affen));
         assert_eq!(result, Some(expectation));
         assert_eq!(tax.last_updated_node().unwrap(), last_updated_node);
 
         // Append an existing node to one of its own sub-nodes (loop detection)
-        let id_zootiere = ids.get("Zootiere").unwrap().clone();
-        let id_schlangen = ids.get("Schlangen").unwrap().clone();
-        let result = tax.append(Some(id_schlangen), id_zootiere.clone()).err();
+        let id_zootiere = *ids.get("Zootiere").unwrap();
+        let id_schlangen = *ids.get("Schlangen").unwrap();
+        let result = tax.append(Some(id_schlangen), id_zootiere).err();
         let expectation = LoopDetected(Rc::new(id_zootiere));
         assert_eq!(result, Some(expectation));
         assert_eq!(tax.last_updated_node().unwrap(), last_updated_node);
 
         // Append an existing root-node to root-nodes
-        let id_tiere = ids.get("Tiere").unwrap().clone();
+        let id_tiere = *ids.get("Tiere").unwrap();
         let super_id = None;
-        let result = tax.append(super_id, id_tiere.clone()).err();
+        let result = tax.append(super_id, id_tiere).err();
         let expectation = DuplicateRootNode(Rc::new(id_tiere));
         assert_eq!(result, Some(expectation));
         assert_eq!(tax.last_updated_node().unwrap(), last_updated_node);
 
         // Append an existing node to root-nodes
-        let id_nutztiere = ids.get("Nutztiere").unwrap().clone();
+        let id_nutztiere = *ids.get("Nutztiere").unwrap();
         let super_id = None;
-        assert!(tax.append(super_id, id_nutztiere.clone()).is_ok());
+        assert!(tax.append(super_id, id_nutztiere).is_ok());
         assert_eq!(*tax.node0.iter().last().unwrap(), Rc::new(id_nutztiere));
 
         assert_eq!(tax.last_updated_node().unwrap(), Rc::new(id_nutztiere));
 
         // Append an existing node to another existing nodes
-        let id_waale = ids.get("Waale & Delfine").unwrap().clone();
-        let id_tierschutz = ids.get("Tierschutz").unwrap().clone();
-        assert!(tax.append(Some(id_tierschutz), id_waale.clone()).is_ok());
+        let id_waale = *ids.get("Waale & Delfine").unwrap();
+        let id_tierschutz = *ids.get("Tierschutz").unwrap();
+        assert!(tax.append(Some(id_tierschutz), id_waale).is_ok());
 
-        let tierschutz = tax._get_node_opt(Rc::new(id_tierschutz.clone())).unwrap();
-        assert_eq!(tierschutz.subs().contains(&Rc::new(id_waale)), true);
+        let tierschutz = tax._get_node_opt(Rc::new(id_tierschutz)).unwrap();
+        assert!(tierschutz.subs().contains(&Rc::new(id_waale)));
         assert_eq!(tax.last_updated_node().unwrap(), Rc::new(id_waale));
     }
 
@@ -147,58 +156,58 @@ mod tests {
         // Append a non-existing node to a non-existing super-node
         let node_id = Uuid::new_v4();
         let super_id = Uuid::new_v4();
-        let result = tax.append_at(Some(super_id), node_id.clone(), 0).err();
+        let result = tax.append_at(Some(super_id), node_id, 0).err();
         let expectation = NodeNotFound(Rc::new(node_id));
         assert_eq!(result, Some(expectation));
         assert_eq!(tax.last_updated_node().unwrap(), last_updated_node);
 
         // Append an existing node to a non-existing super-node
-        let id_affen = ids.get("Affen").unwrap().clone();
+        let id_affen = *ids.get("Affen").unwrap();
         let super_id = Uuid::new_v4();
-        let result = tax.append_at(Some(super_id), id_affen.clone(), 0).err();
+        let result = tax.append_at(Some(super_id), id_affen, 0).err();
         let expectation = NodeNotFound(Rc::new(super_id));
         assert_eq!(result, Some(expectation));
         assert_eq!(tax.last_updated_node().unwrap(), last_updated_node);
 
         // Append an existing node to one of its own super-nodes
-        let id_affen = ids.get("Affen").unwrap().clone();
-        let id_zootiere = ids.get("Zootiere").unwrap().clone();
-        let result = tax.append_at(Some(id_zootiere), id_affen.clone(), 0).err();
+        let id_affen = *ids.get("Affen").unwrap();
+        let id_zootiere = *ids.get("Zootiere").unwrap();
+        let result = tax.append_at(Some(id_zootiere), id_affen, 0).err();
         let expectation = DuplicateSubNode(Rc::new(id_zootiere), Rc::new(id_affen));
         assert_eq!(result, Some(expectation));
         assert_eq!(tax.last_updated_node().unwrap(), last_updated_node);
 
         // Append an existing node to one of its own sub-nodes (loop detection)
-        let id_zootiere = ids.get("Zootiere").unwrap().clone();
-        let id_schlangen = ids.get("Schlangen").unwrap().clone();
-        let result = tax.append_at(Some(id_schlangen), id_zootiere.clone(), 0).err();
+        let id_zootiere = *ids.get("Zootiere").unwrap();
+        let id_schlangen = *ids.get("Schlangen").unwrap();
+        let result = tax.append_at(Some(id_schlangen), id_zootiere, 0).err();
         let expectation = LoopDetected(Rc::new(id_zootiere));
         assert_eq!(result, Some(expectation));
         assert_eq!(tax.last_updated_node().unwrap(), last_updated_node);
 
         // Append an existing root-node to root-nodes
-        let id_tiere = ids.get("Tiere").unwrap().clone();
+        let id_tiere = *ids.get("Tiere").unwrap();
         let super_id = None;
-        let result = tax.append_at(super_id, id_tiere.clone(), tax.node0.len() / 2).err();
+        let result = tax.append_at(super_id, id_tiere, tax.node0.len() / 2).err();
         let expectation = DuplicateRootNode(Rc::new(id_tiere));
         assert_eq!(result, Some(expectation));
         assert_eq!(tax.last_updated_node().unwrap(), last_updated_node);
 
         // Append an existing node to root-nodes
-        let id_nutztiere = ids.get("Nutztiere").unwrap().clone();
+        let id_nutztiere = *ids.get("Nutztiere").unwrap();
         let super_id = None;
-        assert!(tax.append_at(super_id, id_nutztiere.clone(), tax.node0.len() / 2).is_ok());
+        assert!(tax.append_at(super_id, id_nutztiere, tax.node0.len() / 2).is_ok());
         assert_eq!(*tax.node0.iter().nth(tax.node0.len() / 2).unwrap(), Rc::new(id_nutztiere));
 
         assert_eq!(tax.last_updated_node().unwrap(), Rc::new(id_nutztiere));
 
         // Append an existing node to another existing nodes
-        let id_waale = ids.get("Waale & Delfine").unwrap().clone();
-        let id_tierschutz = ids.get("Tierschutz").unwrap().clone();
-        assert!(tax.append_at(Some(id_tierschutz), id_waale.clone(), 5).is_ok());
+        let id_waale = *ids.get("Waale & Delfine").unwrap();
+        let id_tierschutz = *ids.get("Tierschutz").unwrap();
+        assert!(tax.append_at(Some(id_tierschutz), id_waale, 5).is_ok());
 
-        let tierschutz = tax._get_node_opt(Rc::new(id_tierschutz.clone())).unwrap();
-        assert_eq!(tierschutz.subs().contains(&Rc::new(id_waale)), true);
+        let tierschutz = tax._get_node_opt(Rc::new(id_tierschutz)).unwrap();
+        assert!(tierschutz.subs().contains(&Rc::new(id_waale)));
         assert_eq!(tax.last_updated_node().unwrap(), Rc::new(id_waale));
     }
 
@@ -216,9 +225,9 @@ mod tests {
         assert_eq!(tax.last_updated_node().unwrap(), last_updated_node);
 
         // Move an existing node from a non-existing node to an existing node
-        let id_affen = Rc::new(ids.get("Affen").unwrap().clone());
+        let id_affen = Rc::new(*ids.get("Affen").unwrap());
         let from_super_id = Rc::new(Uuid::new_v4());
-        let id_tierschutz = Rc::new(ids.get("Tierschutz").unwrap().clone());
+        let id_tierschutz = Rc::new(*ids.get("Tierschutz").unwrap());
         let result = tax
             .move_to(id_affen.clone(), Some(from_super_id.clone()), Some(id_tierschutz.clone()), 0)
             .err();
@@ -227,7 +236,7 @@ mod tests {
         assert_eq!(tax.last_updated_node().unwrap(), last_updated_node);
 
         // Move an existing node to a none-existing node
-        let id_affen = Rc::new(ids.get("Affen").unwrap().clone());
+        let id_affen = Rc::new(*ids.get("Affen").unwrap());
         let to_super_id = Rc::new(Uuid::new_v4());
         let result = tax.move_to(id_affen.clone(), None, Some(to_super_id.clone()), 0).err();
         let expectation = NodeNotFound(to_super_id);
@@ -235,9 +244,9 @@ mod tests {
         assert_eq!(tax.last_updated_node().unwrap(), last_updated_node);
 
         // Move with a non-existing source-edge, i. e. source super node and node exists but do not share a super-sub-relationship.
-        let id_affen = Rc::new(ids.get("Affen").unwrap().clone());
-        let id_voegel = Rc::new(ids.get("Vögel").unwrap().clone());
-        let id_tierschutz = Rc::new(ids.get("Tierschutz").unwrap().clone());
+        let id_affen = Rc::new(*ids.get("Affen").unwrap());
+        let id_voegel = Rc::new(*ids.get("Vögel").unwrap());
+        let id_tierschutz = Rc::new(*ids.get("Tierschutz").unwrap());
         let result = tax
             .move_to(id_affen.clone(), Some(id_voegel.clone()), Some(id_tierschutz.clone()), 0)
             .err();
@@ -246,9 +255,9 @@ mod tests {
         assert_eq!(tax.last_updated_node().unwrap(), last_updated_node);
 
         // Move to an already existing edge.
-        let id_affen = Rc::new(ids.get("Affen").unwrap().clone());
-        let id_zootiere = Rc::new(ids.get("Zootiere").unwrap().clone());
-        let id_saeugetiere = Rc::new(ids.get("Säugetiere").unwrap().clone());
+        let id_affen = Rc::new(*ids.get("Affen").unwrap());
+        let id_zootiere = Rc::new(*ids.get("Zootiere").unwrap());
+        let id_saeugetiere = Rc::new(*ids.get("Säugetiere").unwrap());
         let result = tax
             .move_to(id_affen.clone(), Some(id_zootiere.clone()), Some(id_saeugetiere.clone()), 0)
             .err();
@@ -256,35 +265,643 @@ mod tests {
         assert_eq!(result, Some(expectation));
         assert_eq!(tax.last_updated_node().unwrap(), last_updated_node);
 
+        // Move to another position at the same super-node
+        let id_affen = Rc::new(*ids.get("Affen").unwrap());
+        let id_zootiere = Rc::new(*ids.get("Zootiere").unwrap());
+        assert!(tax
+            .move_to(id_affen.clone(), Some(id_zootiere.clone()), Some(id_zootiere.clone()), 1)
+            .is_ok());
+
+        let zootiere = tax._get_node_opt(id_zootiere.clone()).unwrap();
+        assert_eq!(zootiere.subs().back(), Some(&id_affen));
+        assert_eq!(zootiere.count_subs(), 2);
+        assert_eq!(tax.last_updated_node().unwrap(), id_affen);
+
         // Move node to become a new root-node
-        let id_schlangen = Rc::new(ids.get("Schlangen").unwrap().clone());
-        let id_zootiere = Rc::new(ids.get("Zootiere").unwrap().clone());
+        let id_schlangen = Rc::new(*ids.get("Schlangen").unwrap());
+        let id_zootiere = Rc::new(*ids.get("Zootiere").unwrap());
         assert!(tax.move_to(id_schlangen.clone(), Some(id_zootiere.clone()), None, 0).is_ok());
 
         let zootiere = tax._get_node_opt(id_zootiere.clone()).unwrap();
-        assert_eq!(zootiere.subs().contains(&id_schlangen), false);
-        assert_eq!(tax.node0.contains(&id_schlangen), true);
+        assert!(!zootiere.subs().contains(&id_schlangen));
+        assert!(tax.node0.contains(&id_schlangen));
         assert_eq!(tax.last_updated_node().unwrap(), id_schlangen);
 
         // Move to a new super-node (non-root)
-        let id_affen = Rc::new(ids.get("Affen").unwrap().clone());
-        let id_zootiere = Rc::new(ids.get("Zootiere").unwrap().clone());
-        let id_tierschutz = Rc::new(ids.get("Tierschutz").unwrap().clone());
+        let id_affen = Rc::new(*ids.get("Affen").unwrap());
+        let id_zootiere = Rc::new(*ids.get("Zootiere").unwrap());
+        let id_tierschutz = Rc::new(*ids.get("Tierschutz").unwrap());
         assert!(tax
             .move_to(id_affen.clone(), Some(id_zootiere.clone()), Some(id_tierschutz.clone()), 0)
             .is_ok());
 
         let zootiere = tax._get_node_opt(id_zootiere.clone()).unwrap();
-        assert_eq!(zootiere.subs().contains(&id_affen), false);
+        assert!(!zootiere.subs().contains(&id_affen));
 
         let tierschutz = tax._get_node_opt(id_tierschutz.clone()).unwrap();
-        assert_eq!(tierschutz.subs().contains(&id_affen), true);
+        assert!(tierschutz.subs().contains(&id_affen));
 
         assert_eq!(tax.last_updated_node().unwrap(), id_affen);
 
         // todo: test move to a defined position
     }
 
+    #[test]
+    fn add_with_payload() {
+        let mut tax: Taxonomy<Uuid, Concept, (), &str> = Taxonomy::new();
+        let element = Concept::new("Tiere");
+        let tiere = Rc::new(element.id());
+        tax.add_with_payload(None, element, "root").unwrap();
+
+        let element = Concept::new("Hunde");
+        let hunde = Rc::new(element.id());
+        tax.add_with_payload(Some(*tiere), element, "sub").unwrap();
+
+        assert_eq!(tax.edge(None, tiere.clone()).unwrap().into_payload(), &"root");
+        assert_eq!(tax.edge(Some(tiere.clone()), hunde.clone()).unwrap().into_payload(), &"sub");
+
+        // Validation is the same as for add
+        let result = tax
+            .add_with_payload(None, tax.get(hunde.clone()).unwrap().clone(), "dup")
+            .err();
+        assert_eq!(result, Some(DuplicateNode(hunde)));
+    }
+
+    #[test]
+    fn append_with_payload() {
+        let (tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+
+        // Payload of edges without payload defaults to E::default()
+        assert_eq!(tax.edge(Some(id("Tiere")), id("Vögel")).unwrap().into_payload(), &());
+
+        let mut tax: Taxonomy<Uuid, Concept, (), u8> = Taxonomy::new();
+        let (tiere, hunde) = (Concept::new("Tiere"), Concept::new("Hunde"));
+        let (tiere_id, hunde_id) = (Rc::new(tiere.id()), Rc::new(hunde.id()));
+        tax.add(None, tiere).unwrap().add(None, hunde).unwrap();
+
+        tax.append_with_payload(Some(*tiere_id), *hunde_id, 7).unwrap();
+        assert_eq!(tax.edge(Some(tiere_id.clone()), hunde_id.clone()).unwrap().into_payload(), &7);
+        assert_eq!(tax.edge(None, hunde_id.clone()).unwrap().into_payload(), &0);
+
+        let result = tax.append_with_payload(Some(*tiere_id), *hunde_id, 8).err();
+        assert_eq!(result, Some(DuplicateSubNode(tiere_id.clone(), hunde_id.clone())));
+        assert_eq!(tax.edge(Some(tiere_id), hunde_id).unwrap().into_payload(), &7);
+    }
+
+    #[test]
+    fn append_at_with_payload() {
+        let mut tax: Taxonomy<Uuid, Concept, (), u8> = Taxonomy::new();
+        let (tiere, hunde, katzen) = (Concept::new("Tiere"), Concept::new("Hunde"), Concept::new("Katzen"));
+        let (tiere_id, hunde_id, katzen_id) = (Rc::new(tiere.id()), Rc::new(hunde.id()), Rc::new(katzen.id()));
+        tax.add(None, tiere).unwrap().add(None, hunde).unwrap();
+        tax.add(Some(*tiere_id), katzen).unwrap();
+
+        tax.append_at_with_payload(Some(*tiere_id), *hunde_id, 0, 3).unwrap();
+        assert_eq!(tax.index_of(Some(tiere_id.clone()), hunde_id.clone()), Ok(0));
+        assert_eq!(tax.edge(Some(tiere_id.clone()), hunde_id.clone()).unwrap().into_payload(), &3);
+
+        tax.append_at_with_payload(None, *katzen_id, 0, 4).unwrap();
+        assert_eq!(tax.index_of(None, katzen_id.clone()), Ok(0));
+        assert_eq!(tax.edge(None, katzen_id.clone()).unwrap().into_payload(), &4);
+
+        let result = tax.append_at_with_payload(None, *katzen_id, 0, 5).err();
+        assert_eq!(result, Some(DuplicateRootNode(katzen_id)));
+    }
+
+    #[test]
+    fn edge() {
+        let (tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+
+        let edge = tax.edge(Some(id("Säugetiere")), id("Hunde")).unwrap();
+        assert_eq!(edge.super_id(), Some(id("Säugetiere")));
+        assert_eq!(edge.node_id(), id("Hunde"));
+
+        let edge = tax.edge(None, id("Tiere")).unwrap();
+        assert_eq!(edge.super_id(), None);
+
+        let unknown = Rc::new(Uuid::new_v4());
+        assert_eq!(tax.edge(None, unknown.clone()).err(), Some(NodeNotFound(unknown.clone())));
+        assert_eq!(
+            tax.edge(Some(unknown.clone()), id("Hunde")).err(),
+            Some(NodeNotFound(unknown))
+        );
+        assert_eq!(tax.edge(None, id("Hunde")).err(), Some(EdgeNotFound(None, id("Hunde"))));
+        assert_eq!(
+            tax.edge(Some(id("Vögel")), id("Hunde")).err(),
+            Some(EdgeNotFound(Some(id("Vögel")), id("Hunde")))
+        );
+    }
+
+    #[test]
+    fn set_edge_payload() {
+        let mut tax: Taxonomy<Uuid, Concept, (), u8> = Taxonomy::new();
+        let (tiere, hunde) = (Concept::new("Tiere"), Concept::new("Hunde"));
+        let (tiere_id, hunde_id) = (Rc::new(tiere.id()), Rc::new(hunde.id()));
+        tax.add(None, tiere).unwrap().add(Some(*tiere_id), hunde).unwrap();
+
+        tax.set_edge_payload(Some(tiere_id.clone()), hunde_id.clone(), 9).unwrap();
+        assert_eq!(tax.edge(Some(tiere_id.clone()), hunde_id.clone()).unwrap().into_payload(), &9);
+        assert_eq!(tax.last_updated_node(), Some(hunde_id.clone()));
+
+        tax.set_edge_payload(None, tiere_id.clone(), 1).unwrap();
+        assert_eq!(tax.edge(None, tiere_id.clone()).unwrap().into_payload(), &1);
+
+        let result = tax.set_edge_payload(None, hunde_id.clone(), 2).err();
+        assert_eq!(result, Some(EdgeNotFound(None, hunde_id)));
+    }
+
+    #[test]
+    fn edges() {
+        let (tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+
+        let edges = tax.edges().map(|edge| (edge.super_id(), edge.node_id())).collect::<Vec<_>>();
+        // Each node has an edge from each of its super-nodes including the root of the taxonomy
+        let count = tax.nodes.values().map(|node| node.count_super()).sum::<usize>();
+        assert_eq!(edges.len(), count);
+        assert_eq!(tax.edges().len(), count);
+        assert_eq!(edges.iter().collect::<HashSet<_>>().len(), count);
+
+        // Root memberships first, then the edges to sub-nodes in pre-order
+        assert_eq!(edges[0], (None, id("Tiere")));
+        assert_eq!(edges[3], (None, id("Tierschutz")));
+        assert_eq!(edges[4], (Some(id("Tiere")), id("Haustiere")));
+        assert_eq!(edges[9], (Some(id("Haustiere")), id("Fische")));
+        assert!(edges.contains(&(Some(id("Tierheime")), id("Hunde"))));
+        assert!(edges.contains(&(Some(id("Hunde")), id("Doggen"))));
+
+        // Every edge carries its payload
+        assert!(tax.edges().all(|edge| edge.payload() == &&()));
+
+        let tax = setup_taxonomy_default();
+        assert_eq!(tax.edges().count(), 0);
+    }
+
+    #[test]
+    fn edges_into() {
+        let (mut tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+
+        let edges = tax
+            .edges_into(id("Hunde"))
+            .unwrap()
+            .map(|edge| edge.super_id())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            edges,
+            vec![Some(id("Haustiere")), Some(id("Säugetiere")), Some(id("Tierheime"))]
+        );
+        assert!(tax.edges_into(id("Hunde")).unwrap().all(|edge| edge.node_id() == id("Hunde")));
+
+        // Root membership comes first
+        tax.append(None, ids["Hunde"]).unwrap();
+        let edges = tax
+            .edges_into(id("Hunde"))
+            .unwrap()
+            .map(|edge| edge.super_id())
+            .collect::<Vec<_>>();
+        assert_eq!(edges[0], None);
+        assert_eq!(edges.len(), 4);
+
+        let unknown = Rc::new(Uuid::new_v4());
+        assert_eq!(tax.edges_into(unknown.clone()).err(), Some(NodeNotFound(unknown)));
+    }
+
+    #[test]
+    fn edges_from() {
+        let (tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+
+        let edges = tax
+            .edges_from(Some(id("Zootiere")))
+            .unwrap()
+            .map(|edge| (edge.super_id(), edge.node_id()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            edges,
+            vec![(Some(id("Zootiere")), id("Affen")), (Some(id("Zootiere")), id("Schlangen"))]
+        );
+
+        let edges = tax.edges_from(None).unwrap().map(|edge| edge.node_id()).collect::<Vec<_>>();
+        assert_eq!(edges, vec![id("Tiere"), id("Tierhalter"), id("Tierheime"), id("Tierschutz")]);
+
+        assert_eq!(tax.edges_from(Some(id("Doggen"))).unwrap().count(), 0);
+
+        let unknown = Rc::new(Uuid::new_v4());
+        assert_eq!(tax.edges_from(Some(unknown.clone())).err(), Some(NodeNotFound(unknown)));
+    }
+
+    #[test]
+    fn _payload_of() {
+        let mut tax: Taxonomy<Uuid, Concept, (), u8> = Taxonomy::new();
+        let (tiere, hunde) = (Concept::new("Tiere"), Concept::new("Hunde"));
+        let (tiere_id, hunde_id) = (Rc::new(tiere.id()), Rc::new(hunde.id()));
+        tax.add(None, tiere)
+            .unwrap()
+            .add_with_payload(Some(*tiere_id), hunde, 3)
+            .unwrap();
+
+        assert_eq!(tax._payload_of(Some(tiere_id.clone()), hunde_id.clone()), Some(&3));
+        assert_eq!(tax._payload_of(None, tiere_id.clone()), Some(&0));
+        assert_eq!(tax._payload_of(None, hunde_id), None);
+        assert_eq!(tax._payload_of(None, Rc::new(Uuid::new_v4())), None);
+    }
+
+    #[test]
+    fn remove_from_with_payload() {
+        let mut tax: Taxonomy<Uuid, Concept, (), u8> = Taxonomy::new();
+        let (tiere, hunde) = (Concept::new("Tiere"), Concept::new("Hunde"));
+        let (tiere_id, hunde_id) = (Rc::new(tiere.id()), Rc::new(hunde.id()));
+        tax.add(None, tiere)
+            .unwrap()
+            .add_with_payload(Some(*tiere_id), hunde, 3)
+            .unwrap();
+        tax.append(None, *hunde_id).unwrap();
+
+        // Edges carrying a payload are accepted, the payload is ignored
+        tax.remove_from(Edge::with_payload(Some(tiere_id.clone()), hunde_id.clone(), 7))
+            .unwrap();
+        assert_eq!(tax.edges_into(hunde_id.clone()).unwrap().count(), 1);
+
+        let edge = Edge::with_payload(Some(tiere_id.clone()), hunde_id.clone(), 7);
+        assert_eq!(tax.remove_from(edge).err(), Some(EdgeNotFound(Some(tiere_id), hunde_id)));
+    }
+
+    #[test]
+    fn move_to_with_payload() {
+        let mut tax: Taxonomy<Uuid, Concept, (), u8> = Taxonomy::new();
+        let (tiere, hunde, katzen) = (Concept::new("Tiere"), Concept::new("Hunde"), Concept::new("Katzen"));
+        let (tiere_id, hunde_id, katzen_id) = (Rc::new(tiere.id()), Rc::new(hunde.id()), Rc::new(katzen.id()));
+        tax.add(None, tiere).unwrap().add(None, katzen).unwrap();
+        tax.add_with_payload(Some(*tiere_id), hunde, 5).unwrap();
+
+        // Payload is carried along to another super-node
+        tax.move_to(hunde_id.clone(), Some(tiere_id.clone()), Some(katzen_id.clone()), 0)
+            .unwrap();
+        assert_eq!(
+            tax.edge(Some(katzen_id.clone()), hunde_id.clone()).unwrap().into_payload(),
+            &5
+        );
+        assert!(tax.edge(Some(tiere_id.clone()), hunde_id.clone()).is_err());
+
+        // to the root of the taxonomy
+        tax.move_to(hunde_id.clone(), Some(katzen_id.clone()), None, 0).unwrap();
+        assert_eq!(tax.edge(None, hunde_id.clone()).unwrap().into_payload(), &5);
+
+        // and within the same super-node
+        tax.move_to(hunde_id.clone(), None, None, 100).unwrap();
+        assert_eq!(tax.edge(None, hunde_id.clone()).unwrap().into_payload(), &5);
+
+        // A failed move keeps the payload at the source edge
+        tax.move_to(tiere_id.clone(), None, Some(hunde_id.clone()), 0).unwrap();
+        let result = tax.move_to(hunde_id.clone(), None, Some(tiere_id.clone()), 0).err();
+        assert_eq!(result, Some(LoopDetected(hunde_id.clone())));
+        assert_eq!(tax.edge(None, hunde_id).unwrap().into_payload(), &5);
+    }
+
+    #[test]
+    fn index_of() {
+        let (tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+
+        assert_eq!(tax.index_of(None, id("Tiere")), Ok(0));
+        assert_eq!(tax.index_of(None, id("Tierschutz")), Ok(3));
+        assert_eq!(tax.index_of(Some(id("Tiere")), id("Vögel")), Ok(4));
+        assert_eq!(tax.index_of(Some(id("Tierheime")), id("Katzen")), Ok(1));
+
+        let unknown = Rc::new(Uuid::new_v4());
+        assert_eq!(tax.index_of(None, unknown.clone()), Err(NodeNotFound(unknown.clone())));
+        assert_eq!(tax.index_of(Some(unknown.clone()), id("Tiere")), Err(NodeNotFound(unknown)));
+        assert_eq!(tax.index_of(None, id("Hunde")), Err(EdgeNotFound(None, id("Hunde"))));
+        assert_eq!(
+            tax.index_of(Some(id("Vögel")), id("Hunde")),
+            Err(EdgeNotFound(Some(id("Vögel")), id("Hunde")))
+        );
+    }
+
+    #[test]
+    fn reorder() {
+        let (mut tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+        let tiere = Some(id("Tiere"));
+
+        // Sub-nodes
+        tax.reorder(tiere.clone(), id("Vögel"), 1).unwrap();
+        let expectation = vec![
+            id("Haustiere"),
+            id("Vögel"),
+            id("Nutztiere"),
+            id("Zootiere"),
+            id("Säugetiere"),
+        ];
+        assert_eq!(tax._sub_ids_of(tiere.clone()), expectation);
+        assert_eq!(tax.last_updated_node(), Some(id("Vögel")));
+
+        tax.reorder(tiere.clone(), id("Haustiere"), 100).unwrap();
+        let expectation = vec![
+            id("Vögel"),
+            id("Nutztiere"),
+            id("Zootiere"),
+            id("Säugetiere"),
+            id("Haustiere"),
+        ];
+        assert_eq!(tax._sub_ids_of(tiere.clone()), expectation);
+
+        // Root-nodes
+        tax.reorder(None, id("Tiere"), 2).unwrap();
+        let expectation = vec![id("Tierhalter"), id("Tierheime"), id("Tiere"), id("Tierschutz")];
+        assert_eq!(tax._sub_ids_of(None), expectation);
+
+        // Super-nodes are not touched
+        assert!(tax._get_node_opt(id("Vögel")).unwrap().supers().contains(&id("Tiere")));
+        assert!(tax._get_node_opt(id("Tiere")).unwrap().is_root());
+
+        let last_updated_node = tax.last_updated_node();
+        let result = tax.reorder(Some(id("Vögel")), id("Hunde"), 0).err();
+        assert_eq!(result, Some(EdgeNotFound(Some(id("Vögel")), id("Hunde"))));
+        assert_eq!(tax.last_updated_node(), last_updated_node);
+    }
+
+    #[test]
+    fn swap() {
+        let (mut tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+        let tiere = Some(id("Tiere"));
+
+        tax.swap(tiere.clone(), id("Haustiere"), id("Säugetiere")).unwrap();
+        let expectation = vec![
+            id("Säugetiere"),
+            id("Nutztiere"),
+            id("Zootiere"),
+            id("Haustiere"),
+            id("Vögel"),
+        ];
+        assert_eq!(tax._sub_ids_of(tiere.clone()), expectation);
+
+        tax.swap(tiere.clone(), id("Vögel"), id("Nutztiere")).unwrap();
+        let expectation = vec![
+            id("Säugetiere"),
+            id("Vögel"),
+            id("Zootiere"),
+            id("Haustiere"),
+            id("Nutztiere"),
+        ];
+        assert_eq!(tax._sub_ids_of(tiere.clone()), expectation);
+
+        // Swapping a node with itself does nothing and is not recorded
+        tax.enable_history(10);
+        tax.swap(tiere.clone(), id("Vögel"), id("Vögel")).unwrap();
+        assert_eq!(tax._sub_ids_of(tiere.clone()), expectation);
+        assert!(tax.history().is_empty());
+
+        tax.swap(None, id("Tierschutz"), id("Tiere")).unwrap();
+        let expectation = vec![id("Tierschutz"), id("Tierhalter"), id("Tierheime"), id("Tiere")];
+        assert_eq!(tax._sub_ids_of(None), expectation);
+
+        let result = tax.swap(tiere, id("Vögel"), id("Hunde")).err();
+        assert_eq!(result, Some(EdgeNotFound(Some(id("Tiere")), id("Hunde"))));
+    }
+
+    #[test]
+    fn move_up() {
+        let (mut tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+        let haustiere = Some(id("Haustiere"));
+
+        tax.move_up(haustiere.clone(), id("Katzen")).unwrap();
+        assert_eq!(
+            tax._sub_ids_of(haustiere.clone()),
+            vec![id("Fische"), id("Katzen"), id("Hunde")]
+        );
+
+        tax.move_up(haustiere.clone(), id("Katzen"))
+            .unwrap()
+            .move_up(haustiere.clone(), id("Katzen"))
+            .unwrap();
+        assert_eq!(
+            tax._sub_ids_of(haustiere.clone()),
+            vec![id("Katzen"), id("Fische"), id("Hunde")]
+        );
+
+        tax.move_up(None, id("Tierheime")).unwrap();
+        assert_eq!(tax.index_of(None, id("Tierheime")), Ok(1));
+
+        let unknown = Rc::new(Uuid::new_v4());
+        assert_eq!(tax.move_up(haustiere, unknown.clone()).err(), Some(NodeNotFound(unknown)));
+    }
+
+    #[test]
+    fn move_down() {
+        let (mut tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+        let haustiere = Some(id("Haustiere"));
+
+        tax.move_down(haustiere.clone(), id("Fische")).unwrap();
+        assert_eq!(
+            tax._sub_ids_of(haustiere.clone()),
+            vec![id("Hunde"), id("Fische"), id("Katzen")]
+        );
+
+        tax.move_down(haustiere.clone(), id("Fische"))
+            .unwrap()
+            .move_down(haustiere.clone(), id("Fische"))
+            .unwrap();
+        assert_eq!(
+            tax._sub_ids_of(haustiere.clone()),
+            vec![id("Hunde"), id("Katzen"), id("Fische")]
+        );
+
+        // Moving the node in the back does nothing and is not recorded
+        tax.enable_history(10);
+        tax.move_down(haustiere.clone(), id("Fische")).unwrap();
+        assert_eq!(
+            tax._sub_ids_of(haustiere.clone()),
+            vec![id("Hunde"), id("Katzen"), id("Fische")]
+        );
+        assert!(tax.history().is_empty());
+
+        tax.move_down(None, id("Tiere")).unwrap();
+        assert_eq!(tax.index_of(None, id("Tiere")), Ok(1));
+
+        let unknown = Rc::new(Uuid::new_v4());
+        assert_eq!(
+            tax.move_down(Some(unknown.clone()), id("Fische")).err(),
+            Some(NodeNotFound(unknown))
+        );
+    }
+
+    #[test]
+    fn move_to_front() {
+        let (mut tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+
+        tax.move_to_front(Some(id("Tiere")), id("Zootiere")).unwrap();
+        assert_eq!(tax.index_of(Some(id("Tiere")), id("Zootiere")), Ok(0));
+        assert_eq!(tax.index_of(Some(id("Tiere")), id("Haustiere")), Ok(1));
+
+        tax.move_to_front(None, id("Tierschutz")).unwrap();
+        assert_eq!(tax.index_of(None, id("Tierschutz")), Ok(0));
+
+        assert_eq!(
+            tax.move_to_front(None, id("Hunde")).err(),
+            Some(EdgeNotFound(None, id("Hunde")))
+        );
+    }
+
+    #[test]
+    fn move_to_back() {
+        let (mut tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+
+        tax.move_to_back(Some(id("Tiere")), id("Zootiere")).unwrap();
+        assert_eq!(tax.index_of(Some(id("Tiere")), id("Zootiere")), Ok(4));
+        assert_eq!(tax.index_of(Some(id("Tiere")), id("Vögel")), Ok(3));
+
+        tax.move_to_back(None, id("Tiere")).unwrap();
+        assert_eq!(tax.index_of(None, id("Tiere")), Ok(3));
+
+        assert_eq!(
+            tax.move_to_back(None, id("Hunde")).err(),
+            Some(EdgeNotFound(None, id("Hunde")))
+        );
+    }
+
+    #[test]
+    fn sort_subs_by() {
+        let (mut tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+        let by_name = |a: &Concept, b: &Concept| a.name().cmp(&b.name());
+
+        tax.sort_subs_by(Some(id("Tiere")), by_name).unwrap();
+        let expectation = vec![
+            id("Haustiere"),
+            id("Nutztiere"),
+            id("Säugetiere"),
+            id("Vögel"),
+            id("Zootiere"),
+        ];
+        assert_eq!(tax._sub_ids_of(Some(id("Tiere"))), expectation);
+        assert_eq!(tax.last_updated_node(), Some(id("Tiere")));
+
+        // Sub-nodes of sub-nodes are not sorted
+        tax.sort_subs_by(Some(id("Säugetiere")), |a, b| b.name().cmp(&a.name()))
+            .unwrap();
+        let expectation = vec![id("Waale & Delfine"), id("Katzen"), id("Hunde"), id("Affen")];
+        assert_eq!(tax._sub_ids_of(Some(id("Säugetiere"))), expectation);
+        let expectation = vec![id("Fische"), id("Hunde"), id("Katzen")];
+        assert_eq!(tax._sub_ids_of(Some(id("Haustiere"))), expectation);
+
+        // Root-nodes
+        tax.sort_subs_by(None, |a, b| b.name().cmp(&a.name())).unwrap();
+        let expectation = vec![id("Tierschutz"), id("Tierheime"), id("Tierhalter"), id("Tiere")];
+        assert_eq!(tax._sub_ids_of(None), expectation);
+
+        let unknown = Rc::new(Uuid::new_v4());
+        assert_eq!(
+            tax.sort_subs_by(Some(unknown.clone()), by_name).err(),
+            Some(NodeNotFound(unknown))
+        );
+    }
+
+    #[test]
+    fn sort_all_by() {
+        let (mut tax, _, _) = setup_tax_animals();
+
+        tax.sort_all_by(|a, b| b.name().cmp(&a.name()));
+        for super_id in tax.nodes.keys().cloned().map(Some).chain([None]) {
+            let names = tax
+                ._sub_ids_of(super_id)
+                .into_iter()
+                .map(|id| tax.get(id).unwrap().name())
+                .collect::<Vec<_>>();
+            assert!(names.windows(2).all(|pair| pair[0] >= pair[1]));
+        }
+
+        // Structure is not changed
+        let (expectation, _, _) = setup_tax_animals();
+        assert_eq!(
+            tax.iter_unique(Occurrence::First).count(),
+            expectation.iter_unique(Occurrence::First).count()
+        );
+        assert_eq!(tax.iter().count(), expectation.iter().count());
+    }
+
+    #[test]
+    fn enable_keep_sorted() {
+        let (mut tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+
+        tax.enable_keep_sorted(|a: &Concept, b: &Concept| a.name().cmp(&b.name()));
+        assert!(tax.has_keep_sorted());
+        let expectation = vec![
+            id("Haustiere"),
+            id("Nutztiere"),
+            id("Säugetiere"),
+            id("Vögel"),
+            id("Zootiere"),
+        ];
+        assert_eq!(tax._sub_ids_of(Some(id("Tiere"))), expectation);
+
+        // add
+        let element = Concept::new("Insekten");
+        let insekten = Rc::new(element.id());
+        tax.add(Some(ids["Tiere"]), element).unwrap();
+        assert_eq!(tax.index_of(Some(id("Tiere")), insekten.clone()), Ok(1));
+
+        let element = Concept::new("Tierparks");
+        let tierparks = Rc::new(element.id());
+        tax.add(None, element).unwrap();
+        assert_eq!(tax.index_of(None, tierparks.clone()), Ok(3));
+
+        // append and append_at ignore the index
+        tax.append(Some(ids["Zootiere"]), ids["Fische"]).unwrap();
+        assert_eq!(tax.index_of(Some(id("Zootiere")), id("Fische")), Ok(1));
+        tax.append_at(Some(ids["Zootiere"]), ids["Rind"], 0).unwrap();
+        assert_eq!(tax.index_of(Some(id("Zootiere")), id("Rind")), Ok(2));
+        tax.append_at(None, ids["Hunde"], 0).unwrap();
+        assert_eq!(tax.index_of(None, id("Hunde")), Ok(0));
+
+        // move_to ignores the index
+        tax.move_to(id("Affen"), Some(id("Zootiere")), Some(id("Vögel")), 100)
+            .unwrap();
+        assert_eq!(tax.index_of(Some(id("Vögel")), id("Affen")), Ok(0));
+    }
+
+    #[test]
+    fn disable_keep_sorted() {
+        let (mut tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+
+        tax.enable_keep_sorted(|a: &Concept, b: &Concept| a.name().cmp(&b.name()));
+        tax.disable_keep_sorted();
+        assert!(!tax.has_keep_sorted());
+
+        // Order is kept, but new nodes are appended to the back
+        let expectation = vec![
+            id("Haustiere"),
+            id("Nutztiere"),
+            id("Säugetiere"),
+            id("Vögel"),
+            id("Zootiere"),
+        ];
+        assert_eq!(tax._sub_ids_of(Some(id("Tiere"))), expectation);
+
+        let element = Concept::new("Insekten");
+        let insekten = Rc::new(element.id());
+        tax.add(Some(ids["Tiere"]), element).unwrap();
+        assert_eq!(tax.index_of(Some(id("Tiere")), insekten), Ok(5));
+    }
+
+    #[test]
+    fn has_keep_sorted() {
+        let mut tax = setup_tax_empty();
+        assert!(!tax.has_keep_sorted());
+        tax.enable_keep_sorted(|a: &Concept, b: &Concept| a.name().cmp(&b.name()));
+        assert!(tax.has_keep_sorted());
+    }
+
     #[test]
     fn remove() {
         let (mut tax, ids, _) = setup_tax_animals();
@@ -298,51 +915,151 @@ mod tests {
         assert_eq!(tax.last_updated_node().unwrap(), last_updated_node);
 
         // Remove a node with sub-nodes
-        let id_hunde = Rc::new(ids.get("Hunde").unwrap().clone());
+        let id_hunde = Rc::new(*ids.get("Hunde").unwrap());
         let result = tax.remove(id_hunde.clone()).err();
         let expectation = NodeHasSubNode(id_hunde.clone());
         assert_eq!(result, Some(expectation));
         assert_eq!(tax.last_updated_node().unwrap(), last_updated_node);
 
         // Remove nodes without sub-nodes and only one super-node
-        let id_doggen = Rc::new(ids.get("Doggen").unwrap().clone());
+        let id_doggen = Rc::new(*ids.get("Doggen").unwrap());
         assert!(tax.remove(id_doggen.clone()).is_ok());
-        assert_eq!(tax.nodes.contains_key(&id_doggen), false);
+        assert!(!tax.nodes.contains_key(&id_doggen));
         assert_eq!(tax.last_updated_node().unwrap(), id_doggen);
 
-        let id_schaeferhunde = Rc::new(ids.get("Schäferhunde").unwrap().clone());
+        let id_schaeferhunde = Rc::new(*ids.get("Schäferhunde").unwrap());
         assert!(tax.remove(id_schaeferhunde.clone()).is_ok());
-        assert_eq!(tax.nodes.contains_key(&id_schaeferhunde), false);
+        assert!(!tax.nodes.contains_key(&id_schaeferhunde));
         assert_eq!(tax.last_updated_node().unwrap(), id_schaeferhunde);
 
-        assert_eq!(tax._get_node_opt(id_hunde.clone()).unwrap().has_sub(), false);
+        assert!(!tax._get_node_opt(id_hunde.clone()).unwrap().has_sub());
 
         // Remove nodes without sub-nodes and only one super-node (non-root-node)
         assert!(tax.remove(id_hunde.clone()).is_ok());
-        assert_eq!(tax.nodes.contains_key(&id_hunde), false);
+        assert!(!tax.nodes.contains_key(&id_hunde));
         assert_eq!(tax.last_updated_node().unwrap(), id_hunde);
 
         //
         // Remove node without sub-nodes and multiple super-nodes (non-root-node)
         //
-        let id_katzen = Rc::new(ids.get("Katzen").unwrap().clone());
+        let id_katzen = Rc::new(*ids.get("Katzen").unwrap());
 
         // Get a list of super-nodes of the to be removed node
         let supers = tax._get_node_opt(id_katzen.clone()).unwrap().supers();
 
         assert!(tax.remove(id_katzen.clone()).is_ok());
-        assert_eq!(tax.nodes.contains_key(&id_katzen), false);
+        assert!(!tax.nodes.contains_key(&id_katzen));
         assert_eq!(tax.last_updated_node().unwrap(), id_katzen);
 
         for super_node in supers {
-            assert_eq!(tax._get_node_opt(super_node).unwrap().subs().contains(&id_katzen), false);
+            assert!(!tax._get_node_opt(super_node).unwrap().subs().contains(&id_katzen));
         }
 
         // Remove node without sub-nodes and one super-node (root-node)
-        let id_tierheime = Rc::new(ids.get("Tierheime").unwrap().clone());
+        let id_tierheime = Rc::new(*ids.get("Tierheime").unwrap());
         assert!(tax.remove(id_tierheime.clone()).is_ok());
-        assert_eq!(tax.nodes.contains_key(&id_tierheime), false);
-        assert_eq!(tax.node0.contains(&id_tierheime), false);
+        assert!(!tax.nodes.contains_key(&id_tierheime));
+        assert!(!tax.node0.contains(&id_tierheime));
+    }
+
+    #[test]
+    fn take() {
+        let (mut tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+
+        let unknown = Rc::new(Uuid::new_v4());
+        assert_eq!(tax.take(unknown.clone()).err(), Some(NodeNotFound(unknown)));
+        assert_eq!(tax.take(id("Hunde")).err(), Some(NodeHasSubNode(id("Hunde"))));
+
+        // Node with multiple super-nodes
+        let katzen = tax.take(id("Katzen")).unwrap();
+        assert_eq!(katzen.id(), ids["Katzen"]);
+        assert!(!tax.nodes.contains_key(&id("Katzen")));
+        assert!(!tax._sub_ids_of(Some(id("Säugetiere"))).contains(&id("Katzen")));
+        assert!(!tax._sub_ids_of(Some(id("Tierheime"))).contains(&id("Katzen")));
+
+        // Root-node
+        let tierschutz = tax.take(id("Tierschutz")).unwrap();
+        assert_eq!(tierschutz.id(), ids["Tierschutz"]);
+        assert!(!tax.node0.contains(&id("Tierschutz")));
+    }
+
+    #[test]
+    fn take_subtree() {
+        let (mut tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+        let len = tax.nodes.len();
+
+        let unknown = Rc::new(Uuid::new_v4());
+        assert_eq!(tax.take_subtree(unknown.clone()).err(), Some(NodeNotFound(unknown)));
+
+        let subtree = tax.take_subtree(id("Säugetiere")).unwrap();
+        assert_eq!(subtree.nodes.len(), 7);
+        assert_eq!(tax.nodes.len(), len - 7);
+
+        // Removed nodes are detached from nodes remaining in the taxonomy
+        for name in [
+            "Säugetiere",
+            "Affen",
+            "Hunde",
+            "Katzen",
+            "Waale & Delfine",
+            "Doggen",
+            "Schäferhunde",
+        ] {
+            assert!(!tax.nodes.contains_key(&id(name)));
+            assert!(subtree.nodes.contains_key(&id(name)));
+        }
+        assert_eq!(tax._sub_ids_of(Some(id("Zootiere"))), vec![id("Schlangen")]);
+        assert_eq!(tax._sub_ids_of(Some(id("Haustiere"))), vec![id("Fische")]);
+        assert!(!tax._sub_ids_of(Some(id("Tiere"))).contains(&id("Säugetiere")));
+        assert!(!tax._get_node_opt(id("Tierheime")).unwrap().has_sub());
+
+        // Edges between removed nodes are kept in order, all others are dropped
+        assert_eq!(subtree._sub_ids_of(None), vec![id("Säugetiere")]);
+        assert_eq!(
+            subtree._sub_ids_of(Some(id("Säugetiere"))),
+            vec![id("Affen"), id("Hunde"), id("Katzen"), id("Waale & Delfine")]
+        );
+        assert_eq!(subtree._sub_ids_of(Some(id("Hunde"))), vec![id("Doggen"), id("Schäferhunde")]);
+        assert_eq!(subtree.edges().count(), 7);
+        assert!(subtree.nodes.values().all(|node| node.count_super() == 1));
+    }
+
+    #[test]
+    fn take_subtree_with_payload_and_metadata() {
+        let mut tax: Taxonomy<Uuid, Concept, String, u8> = Taxonomy::new();
+        let (tiere, hunde, doggen) = (Concept::new("Tiere"), Concept::new("Hunde"), Concept::new("Doggen"));
+        let (tiere_id, hunde_id, doggen_id) = (Rc::new(tiere.id()), Rc::new(hunde.id()), Rc::new(doggen.id()));
+        tax.add(None, tiere)
+            .unwrap()
+            .add_with_payload(Some(*tiere_id), hunde, 5)
+            .unwrap()
+            .add_with_payload(Some(*hunde_id), doggen, 2)
+            .unwrap()
+            .append_with_payload(None, *hunde_id, 1)
+            .unwrap();
+        tax.set_metadata(doggen_id.clone(), "groß".to_string()).unwrap();
+        tax.set_primary_super(hunde_id.clone(), Some(tiere_id.clone())).unwrap();
+        tax.enable_reachability_index();
+
+        let subtree = tax.take_subtree(hunde_id.clone()).unwrap();
+        assert_eq!(tax.nodes.len(), 1);
+        assert_eq!(tax._sub_ids_of(None), vec![tiere_id.clone()]);
+        assert!(!tax._get_node_opt(tiere_id).unwrap().has_sub());
+
+        // Root edge carries the payload of the edge to the primary super-node
+        assert_eq!(subtree.edge(None, hunde_id.clone()).unwrap().into_payload(), &5);
+        assert_eq!(
+            subtree
+                .edge(Some(hunde_id.clone()), doggen_id.clone())
+                .unwrap()
+                .into_payload(),
+            &2
+        );
+        assert_eq!(subtree.metadata(doggen_id.clone()).unwrap(), Some(&"groß".to_string()));
+        assert!(subtree.has_reachability_index());
+        assert!(subtree.is_descendant_of(doggen_id, hunde_id).unwrap());
     }
 
     #[test]
@@ -351,10 +1068,10 @@ mod tests {
 
         // Remove an existing edge: Edge(None, first root-node)
         let super_id = None;
-        let node_id = Rc::new(ids.get("Tiere").unwrap().clone());
+        let node_id = Rc::new(*ids.get("Tiere").unwrap());
         assert!(tax.remove_from(Edge::new(super_id, node_id.clone())).is_ok());
-        assert_eq!(tax.node0.contains(&node_id), false);
-        assert_eq!(tax.nodes.contains_key(&node_id), false);
+        assert!(!tax.node0.contains(&node_id));
+        assert!(!tax.nodes.contains_key(&node_id));
         assert_eq!(tax.last_updated_node().unwrap(), node_id);
     }
 
@@ -370,120 +1087,2611 @@ mod tests {
         assert_eq!(result, Some(expectation));
 
         // Remove a non-existing edge of an existing super-node and a non-existing sub-node
-        let id_hunde = Rc::new(ids.get("Hunde").unwrap().clone());
+        let id_hunde = Rc::new(*ids.get("Hunde").unwrap());
         let node_id = Rc::new(Uuid::new_v4());
         let result = tax.remove_from(Edge::new(Some(id_hunde.clone()), node_id.clone())).err();
         let expectation = EdgeNotFound(Some(id_hunde), node_id);
         assert_eq!(result, Some(expectation));
 
-        // Remove a non-existing edge of a non-existing super-node and an existing sub-node
-        let super_id = Rc::new(Uuid::new_v4());
-        let id_hunde = Rc::new(ids.get("Hunde").unwrap().clone());
-        let result = tax.remove_from(Edge::new(Some(super_id.clone()), id_hunde.clone())).err();
-        let expectation = EdgeNotFound(Some(super_id), id_hunde);
-        assert_eq!(result, Some(expectation));
+        // Remove a non-existing edge of a non-existing super-node and an existing sub-node
+        let super_id = Rc::new(Uuid::new_v4());
+        let id_hunde = Rc::new(*ids.get("Hunde").unwrap());
+        let result = tax.remove_from(Edge::new(Some(super_id.clone()), id_hunde.clone())).err();
+        let expectation = EdgeNotFound(Some(super_id), id_hunde);
+        assert_eq!(result, Some(expectation));
+
+        // Remove a non-existing edge of two existing nodes which don't share a super-sub relationship
+        let id_hunde = Rc::new(*ids.get("Hunde").unwrap());
+        let id_katzen = Rc::new(*ids.get("Katzen").unwrap());
+        let result = tax.remove_from(Edge::new(Some(id_hunde.clone()), id_katzen.clone())).err();
+        let expectation = EdgeNotFound(Some(id_hunde), id_katzen);
+        assert_eq!(result, Some(expectation));
+
+        // Remove an existing edge of a root-node with no other super-nodes and no sub-nodes.
+        let (mut tax, ids, _) = setup_tax_animals();
+        let id_tierhalter = Rc::new(*ids.get("Tierhalter").unwrap());
+        assert!(tax.remove_from(Edge::new(None, id_tierhalter.clone())).is_ok());
+        assert!(!tax.node0.contains(&id_tierhalter));
+        assert!(!tax.nodes.contains_key(&id_tierhalter));
+        assert_eq!(tax.last_updated_node().unwrap(), id_tierhalter);
+
+        // Remove an existing edge of a root-node with no other super-nodes but with sub-nodes which have other super-nodes as well
+        let id_tierheime = Rc::new(*ids.get("Tierheime").unwrap());
+        let id_katzen = Rc::new(*ids.get("Katzen").unwrap());
+        let id_hunde = Rc::new(*ids.get("Hunde").unwrap());
+        assert!(tax.remove_from(Edge::new(None, id_tierheime.clone())).is_ok());
+        assert!(!tax.node0.contains(&id_tierheime));
+        assert!(!tax.nodes.contains_key(&id_tierheime));
+        assert!(tax.nodes.contains_key(&id_hunde));
+        assert!(tax.nodes.contains_key(&id_katzen));
+        assert_eq!(tax.last_updated_node().unwrap(), id_tierheime);
+
+        // Remove an existing edge of a non-root-node with no other super-nodes but with sub-nodes which have and haven't other super-nodes as well
+        let id_tiere = Rc::new(*ids.get("Tiere").unwrap());
+        let id_saeugetiere = Rc::new(*ids.get("Säugetiere").unwrap());
+        let id_affen = Rc::new(*ids.get("Affen").unwrap());
+        let id_hunde = Rc::new(*ids.get("Hunde").unwrap());
+        let id_katzen = Rc::new(*ids.get("Katzen").unwrap());
+        let id_waale = Rc::new(*ids.get("Waale & Delfine").unwrap());
+        assert!(tax
+            .remove_from(Edge::new(Some(id_tiere.clone()), id_saeugetiere.clone()))
+            .is_ok());
+        assert!(!tax.node0.contains(&id_saeugetiere));
+        assert!(!tax.nodes.contains_key(&id_saeugetiere));
+        assert!(tax.nodes.contains_key(&id_affen));
+        assert!(tax.nodes.contains_key(&id_hunde));
+        assert!(tax.nodes.contains_key(&id_katzen));
+        assert!(!tax.nodes.contains_key(&id_waale));
+        assert_eq!(tax.last_updated_node().unwrap(), id_saeugetiere);
+    }
+
+    #[test]
+    fn remove_recursively() {
+        let (mut tax, ids, _) = setup_tax_animals();
+
+        // Remove a non-existing node.
+        let node_id = Rc::new(Uuid::new_v4());
+        let result = tax.remove_recursively(node_id.clone()).err();
+        let expectation = NodeNotFound(node_id);
+        assert_eq!(result, Some(expectation));
+
+        // Remove a node with multiple super- and sub-nodes
+        let id_hunde = Rc::new(*ids.get("Hunde").unwrap());
+        let id_doggen = Rc::new(*ids.get("Doggen").unwrap());
+        let id_schaeferhunde = Rc::new(*ids.get("Schäferhunde").unwrap());
+
+        // Get a list of super-nodes of the to be removed node
+        let supers = tax._get_node_opt(id_hunde.clone()).unwrap().supers();
+
+        assert!(tax.remove_recursively(id_hunde.clone()).is_ok());
+        for super_node in supers {
+            assert!(!tax._get_node_opt(super_node).unwrap().subs().contains(&id_hunde));
+        }
+        assert!(tax._get_node_opt(id_hunde.clone()).is_none());
+        assert!(tax._get_node_opt(id_doggen.clone()).is_none());
+        assert!(tax._get_node_opt(id_schaeferhunde.clone()).is_none());
+        assert_eq!(tax.last_updated_node().unwrap(), id_hunde);
+    }
+
+    #[test]
+    fn remove_recursively_diamond() {
+        let (mut tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+
+        // Hunde is a sub-node of Haustiere and Säugetiere, both sub-nodes of Tiere
+        assert!(tax.remove_recursively(id("Tiere")).is_ok());
+        for name in ["Tiere", "Haustiere", "Säugetiere", "Hunde", "Katzen", "Doggen"] {
+            assert!(!tax.nodes.contains_key(&id(name)));
+        }
+        assert!(!tax._get_node_opt(id("Tierheime")).unwrap().has_sub());
+        assert_eq!(
+            tax._sub_ids_of(None),
+            vec![id("Tierhalter"), id("Tierheime"), id("Tierschutz")]
+        );
+        assert_eq!(tax.last_updated_node(), Some(id("Tiere")));
+    }
+
+    #[test]
+    fn remove_subtree() {
+        let (mut tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+        tax.enable_reachability_index();
+
+        let unknown = Rc::new(Uuid::new_v4());
+        let before = tax.clone();
+        let result = tax.remove_subtree(unknown.clone(), RemovalPolicy::Orphans).err();
+        assert_eq!(result, Some(NodeNotFound(unknown)));
+        assert_eq!(tax, before);
+
+        // Descendants reachable via Tierheime remain, all others are removed
+        tax.remove_subtree(id("Tiere"), RemovalPolicy::Orphans).unwrap();
+        for name in [
+            "Tiere",
+            "Haustiere",
+            "Säugetiere",
+            "Zootiere",
+            "Affen",
+            "Fische",
+            "Waale & Delfine",
+        ] {
+            assert!(!tax.nodes.contains_key(&id(name)));
+        }
+        assert_eq!(tax._sub_ids_of(Some(id("Tierheime"))), vec![id("Hunde"), id("Katzen")]);
+        assert_eq!(tax._sub_ids_of(Some(id("Hunde"))), vec![id("Doggen"), id("Schäferhunde")]);
+        assert_eq!(tax._relatives_of(id("Hunde"), Direction::Supers), vec![id("Tierheime")]);
+        assert_eq!(tax.last_updated_node(), Some(id("Tiere")));
+
+        // Reachability index reflects the remaining super-nodes
+        assert_eq!(tax._ancestor_ids(id("Doggen")), HashSet::from([id("Hunde"), id("Tierheime")]));
+        let index = tax.reachability.clone();
+        tax.enable_reachability_index();
+        assert_eq!(tax.reachability, index);
+
+        // Removing everything leaves no orphans either
+        tax.remove_subtree(id("Tierheime"), RemovalPolicy::All).unwrap();
+        assert_eq!(tax.nodes.len(), 2);
+        assert_eq!(tax._sub_ids_of(None), vec![id("Tierhalter"), id("Tierschutz")]);
+    }
+
+    #[test]
+    fn _removal_set() {
+        let (tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+
+        let node_ids = tax._removal_set(id("Säugetiere"), RemovalPolicy::All);
+        assert_eq!(node_ids.len(), 7);
+        assert_eq!(node_ids.last(), Some(&id("Säugetiere")));
+
+        // Affen, Hunde and Katzen are reachable via Zootiere, Haustiere and Tierheime
+        let node_ids = tax._removal_set(id("Säugetiere"), RemovalPolicy::Orphans);
+        assert_eq!(node_ids, vec![id("Waale & Delfine"), id("Säugetiere")]);
+
+        let node_ids = tax._removal_set(id("Doggen"), RemovalPolicy::Orphans);
+        assert_eq!(node_ids, vec![id("Doggen")]);
+    }
+
+    #[test]
+    fn _remove_nodes() {
+        let (mut tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+
+        let nodes = tax._remove_nodes(&[id("Haustiere"), id("Hunde")]);
+        assert_eq!(nodes.len(), 2);
+        assert!(!tax.nodes.contains_key(&id("Haustiere")));
+        assert!(!tax.nodes.contains_key(&id("Hunde")));
+
+        // Remaining nodes lose their edges to removed nodes, removed nodes keep theirs
+        assert!(!tax._sub_ids_of(Some(id("Tiere"))).contains(&id("Haustiere")));
+        assert!(!tax._sub_ids_of(Some(id("Tierheime"))).contains(&id("Hunde")));
+        assert!(tax._relatives_of(id("Fische"), Direction::Supers).is_empty());
+        assert!(tax._relatives_of(id("Doggen"), Direction::Supers).is_empty());
+        assert!(nodes[&id("Hunde")].supers().contains(&id("Säugetiere")));
+        assert_eq!(nodes[&id("Haustiere")].count_subs(), 3);
+    }
+
+    #[test]
+    fn transaction() {
+        let (mut tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+        tax.enable_reachability_index();
+        let before = tax.clone();
+
+        // A failing step rolls back all steps before
+        let pflanzen = Concept::new("Pflanzen");
+        let pflanzen_id = pflanzen.id();
+        let result = tax.transaction(|tx| {
+            tx.add(None, pflanzen)?;
+            tx.append(Some(pflanzen_id), ids["Hunde"])?;
+            tx.move_to(id("Affen"), Some(id("Zootiere")), Some(id("Tierheime")), 0)?;
+            tx.remove_subtree(id("Haustiere"), RemovalPolicy::All)?;
+            tx.sort_all_by(|a, b| b.id().cmp(&a.id()));
+            tx.disable_reachability_index();
+            tx.remove(id("Tiere"))?;
+            Ok(())
+        });
+        assert_eq!(result, Err(NodeHasSubNode(id("Tiere"))));
+        assert_eq!(tax, before);
+        assert_eq!(tax.reachability, before.reachability);
+        assert!(tax.journal.is_empty());
+
+        // All steps are applied if no step fails
+        let pflanzen = Concept::new("Pflanzen");
+        let pflanzen_id = Rc::new(pflanzen.id());
+        let result = tax.transaction(|tx| {
+            tx.add(None, pflanzen)?.append(Some(*pflanzen_id), ids["Hunde"])?;
+            Ok::<usize, TaxonomyError<Uuid>>(tx.nodes.len())
+        });
+        assert_eq!(result, Ok(before.nodes.len() + 1));
+        assert!(tax._sub_ids_of(Some(pflanzen_id.clone())).contains(&id("Hunde")));
+        assert!(tax.is_descendant_of(id("Doggen"), pflanzen_id).unwrap());
+        assert!(tax.journal.is_empty());
+    }
+
+    #[test]
+    fn transaction_nested() {
+        let (mut tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+        let before = tax.clone();
+
+        // A failing nested transaction keeps the changes of the enclosing transaction
+        let (pflanzen, blumen) = (Concept::new("Pflanzen"), Concept::new("Blumen"));
+        let (pflanzen_id, blumen_id) = (Rc::new(pflanzen.id()), Rc::new(blumen.id()));
+        tax.transaction(|tx| {
+            tx.add(None, pflanzen)?;
+            let nested = tx.transaction(|tx| {
+                tx.add(Some(*pflanzen_id), blumen)?;
+                tx.remove(id("Tiere"))?;
+                Ok(())
+            });
+            assert_eq!(nested, Err(NodeHasSubNode(id("Tiere"))));
+            assert!(tx._sub_ids_of(Some(pflanzen_id.clone())).is_empty());
+            Ok::<(), TaxonomyError<Uuid>>(())
+        })
+        .unwrap();
+        assert!(tax.nodes.contains_key(&pflanzen_id));
+        assert!(!tax.nodes.contains_key(&blumen_id));
+
+        // A failing enclosing transaction rolls back the changes of a nested transaction
+        let mut tax = before.clone();
+        let pflanzen = Concept::new("Pflanzen");
+        let result = tax.transaction(|tx| {
+            tx.transaction(|tx| {
+                tx.add(None, pflanzen)?;
+                tx.remove_from(Edge::new(Some(id("Tierheime")), id("Hunde")))?;
+                Ok(())
+            })?;
+            tx.remove(id("Tiere"))?;
+            Ok(())
+        });
+        assert_eq!(result, Err(NodeHasSubNode(id("Tiere"))));
+        assert_eq!(tax, before);
+    }
+
+    #[test]
+    fn transaction_with_metadata_and_payload() {
+        let mut tax: Taxonomy<Uuid, Concept, String, u8> = Taxonomy::new();
+        let (tiere, hunde) = (Concept::new("Tiere"), Concept::new("Hunde"));
+        let (tiere_id, hunde_id) = (Rc::new(tiere.id()), Rc::new(hunde.id()));
+        tax.add(None, tiere)
+            .unwrap()
+            .add_with_payload(Some(*tiere_id), hunde, 3)
+            .unwrap();
+        tax.set_metadata(hunde_id.clone(), "bellen".to_string()).unwrap();
+        let before = tax.clone();
+
+        let result = tax.transaction(|tx| {
+            tx.metadata_mut(hunde_id.clone())?.unwrap().push_str(" laut");
+            tx.set_metadata(tiere_id.clone(), "alle".to_string())?;
+            tx.set_edge_payload(Some(tiere_id.clone()), hunde_id.clone(), 9)?;
+            tx.take(hunde_id.clone())?;
+            tx.take(hunde_id.clone())?;
+            Ok(())
+        });
+        assert_eq!(result, Err(NodeNotFound(hunde_id)));
+        assert_eq!(tax, before);
+    }
+
+    #[test]
+    fn _rollback() {
+        let (mut tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+        tax.enable_reachability_index();
+        let before = tax.clone();
+
+        tax.journal.push(Journal::new(
+            Node::snapshot,
+            tax.node0.clone(),
+            tax.last_updated_node.clone(),
+            Vec::new(),
+            true,
+            None,
+        ));
+        tax.remove_subtree(id("Tiere"), RemovalPolicy::All).unwrap();
+        tax.add(None, Concept::new("Pflanzen")).unwrap();
+
+        let journal = tax.journal.pop().unwrap();
+        tax._rollback(journal);
+        assert_eq!(tax.nodes, before.nodes);
+        assert_eq!(tax.node0, before.node0);
+        assert_eq!(tax.last_updated_node, before.last_updated_node);
+        assert_eq!(tax.reachability, before.reachability);
+
+        // An index disabled meanwhile is rebuilt
+        tax.journal.push(Journal::new(
+            Node::snapshot,
+            tax.node0.clone(),
+            tax.last_updated_node.clone(),
+            Vec::new(),
+            true,
+            None,
+        ));
+        tax.disable_reachability_index();
+        tax.remove_recursively(id("Hunde")).unwrap();
+
+        let journal = tax.journal.pop().unwrap();
+        tax._rollback(journal);
+        assert_eq!(tax.reachability, before.reachability);
+    }
+
+    #[test]
+    fn enable_history() {
+        let (mut tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+        assert!(!tax.has_history());
+
+        // Operations before enabling the history are not recorded
+        tax.enable_history(10);
+        assert!(tax.has_history());
+        assert!(tax.history().is_empty());
+
+        tax.move_down(None, id("Tiere")).unwrap();
+        tax.enable_history(10);
+        assert!(tax.history().is_empty());
+        assert_eq!(tax.undo(), None);
+    }
+
+    #[test]
+    fn disable_history() {
+        let (mut tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+
+        tax.enable_history(10).remove_recursively(id("Hunde")).unwrap();
+        tax.disable_history();
+        assert!(!tax.has_history());
+        assert!(tax.history().is_empty());
+        assert_eq!(tax.undo(), None);
+        assert_eq!(tax.redo(), None);
+    }
+
+    #[test]
+    fn has_history() {
+        let mut tax: Taxonomy<Uuid, Concept> = Taxonomy::new();
+        assert!(!tax.has_history());
+        assert!(tax.enable_history(0).has_history());
+        assert!(!tax.disable_history().has_history());
+    }
+
+    #[test]
+    fn history() {
+        let (mut tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+        tax.enable_history(100);
+
+        let pflanzen = Concept::new("Pflanzen");
+        let pflanzen_id = Rc::new(pflanzen.id());
+        tax.add(None, pflanzen).unwrap();
+        tax.append(Some(*pflanzen_id), ids["Hunde"]).unwrap();
+        tax.move_to(id("Affen"), Some(id("Zootiere")), Some(pflanzen_id.clone()), 0)
+            .unwrap();
+        tax.swap(None, id("Tiere"), pflanzen_id.clone()).unwrap();
+        tax.sort_subs_by(None, |a, b| a.id().cmp(&b.id())).unwrap();
+        tax.enable_keep_sorted(|a: &Concept, b: &Concept| a.id().cmp(&b.id()));
+        tax.disable_keep_sorted();
+        tax.remove_from(Edge::new(Some(pflanzen_id.clone()), id("Hunde"))).unwrap();
+        tax.remove_subtree(id("Affen"), RemovalPolicy::All).unwrap();
+        *tax.get_mut(id("Hunde")).unwrap() = Concept::with_id(ids["Hunde"], "Hund");
+        tax.iter_mut().for_each(|mut concept| *concept = concept.clone());
+        tax.remove(pflanzen_id.clone()).unwrap();
+
+        // Failing operations are not recorded
+        assert!(tax.remove(id("Tiere")).is_err());
+        assert!(tax.get_mut(Rc::new(Uuid::new_v4())).is_none());
+
+        assert_eq!(
+            tax.history(),
+            vec![
+                &Operation::Add(None, pflanzen_id.clone()),
+                &Operation::Append(Some(pflanzen_id.clone()), id("Hunde")),
+                &Operation::MoveTo(id("Affen"), Some(id("Zootiere")), Some(pflanzen_id.clone())),
+                &Operation::Reorder(None, id("Tiere")),
+                &Operation::Sort(None),
+                &Operation::KeepSorted(true),
+                &Operation::KeepSorted(false),
+                &Operation::RemoveFrom(Some(pflanzen_id.clone()), id("Hunde")),
+                &Operation::RemoveSubtree(id("Affen")),
+                &Operation::Update(id("Hunde")),
+                &Operation::UpdateAll,
+                &Operation::Remove(pflanzen_id),
+            ]
+        );
+    }
+
+    #[test]
+    fn history_capacity() {
+        let (mut tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+        tax.enable_history(2);
+
+        let before = tax._sub_ids_of(Some(id("Haustiere")));
+        tax.move_up(Some(id("Haustiere")), id("Hunde")).unwrap();
+        tax.move_down(Some(id("Haustiere")), id("Hunde")).unwrap();
+        tax.sort_all_by(|a, b| a.id().cmp(&b.id()));
+        assert_eq!(
+            tax.history(),
+            vec![&Operation::Reorder(Some(id("Haustiere")), id("Hunde")), &Operation::SortAll]
+        );
+
+        // The oldest operation has been dropped
+        assert!(tax.undo().is_some());
+        assert!(tax.undo().is_some());
+        assert_eq!(tax.undo(), None);
+        assert_ne!(tax._sub_ids_of(Some(id("Haustiere"))), before);
+        assert_eq!(tax.index_of(Some(id("Haustiere")), id("Hunde")), Ok(0));
+
+        // Nothing is recorded without capacity
+        tax.enable_history(0).remove_recursively(id("Hunde")).unwrap();
+        assert!(tax.history().is_empty());
+    }
+
+    #[test]
+    fn history_of_mutable_references() {
+        let (mut tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+        tax.enable_history(10);
+
+        // Reading through mutable references records nothing
+        assert_eq!(tax.get_mut(id("Hunde")).unwrap().name(), "Hunde");
+        assert!(tax.iter_mut().all(|concept| !concept.name().is_empty()));
+        while tax.traverse_mut().is_some() {}
+        assert!(tax.history().is_empty());
+
+        // Changes through the references handed out by one call are recorded as one operation
+        let mut concepts = tax.iter_mut().take(2).collect::<Vec<_>>();
+        for concept in concepts.iter_mut() {
+            **concept = Concept::with_id(concept.id(), "Neu");
+        }
+        drop(concepts);
+        assert_eq!(tax.history(), vec![&Operation::UpdateAll]);
+        assert_eq!(tax.get(id("Haustiere")).unwrap().name(), "Neu");
+
+        tax.undo();
+        assert_eq!(tax.get(id("Tiere")).unwrap().name(), "Tiere");
+        assert_eq!(tax.get(id("Haustiere")).unwrap().name(), "Haustiere");
+    }
+
+    #[test]
+    fn undo() {
+        let (mut tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+        tax.enable_reachability_index();
+        tax.enable_history(100);
+        let before = tax.clone();
+
+        let pflanzen = Concept::new("Pflanzen");
+        let pflanzen_id = Rc::new(pflanzen.id());
+        tax.add(None, pflanzen).unwrap();
+        tax.append_at(Some(*pflanzen_id), ids["Hunde"], 0).unwrap();
+        tax.move_to(id("Affen"), Some(id("Zootiere")), None, 0).unwrap();
+        tax.reorder(None, id("Affen"), 2).unwrap();
+        tax.enable_keep_sorted(|a: &Concept, b: &Concept| a.id().cmp(&b.id()));
+        tax.remove_from(Edge::new(Some(id("Haustiere")), id("Hunde"))).unwrap();
+        tax.remove_subtree(id("Tiere"), RemovalPolicy::Orphans).unwrap();
+        tax.take_subtree(pflanzen_id.clone()).unwrap();
+        tax.iter_mut().for_each(|mut concept| *concept = concept.clone());
+
+        let count = tax.history().len();
+        for _ in 0..count {
+            assert!(tax.undo().is_some());
+        }
+        assert_eq!(tax.undo(), None);
+
+        assert_eq!(tax.nodes, before.nodes);
+        assert_eq!(tax.node0, before.node0);
+        assert_eq!(tax.last_updated_node, before.last_updated_node);
+        assert_eq!(tax.reachability, before.reachability);
+        assert!(!tax.has_keep_sorted());
+        assert!(tax.history().is_empty());
+    }
+
+    #[test]
+    fn undo_keeps_reachability_index() {
+        let (mut tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+        tax.enable_history(10).remove_recursively(id("Hunde")).unwrap();
+
+        // Index enabled after the operation is kept and updated
+        tax.enable_reachability_index();
+        tax.undo();
+        assert!(tax.has_reachability_index());
+        assert!(tax.is_descendant_of(id("Doggen"), id("Tiere")).unwrap());
+
+        tax.disable_reachability_index();
+        tax.redo();
+        assert!(!tax.has_reachability_index());
+        assert!(tax.get(id("Doggen")).is_none());
+    }
+
+    #[test]
+    fn redo() {
+        let (mut tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+        tax.enable_history(100);
+
+        tax.move_to(id("Affen"), Some(id("Zootiere")), None, 0).unwrap();
+        tax.remove_recursively(id("Haustiere")).unwrap();
+        let after = tax.clone();
+
+        assert_eq!(tax.undo(), Some(&Operation::RemoveSubtree(id("Haustiere"))));
+        assert_eq!(tax.undo(), Some(&Operation::MoveTo(id("Affen"), Some(id("Zootiere")), None)));
+        assert!(tax.history().is_empty());
+
+        assert_eq!(tax.redo(), Some(&Operation::MoveTo(id("Affen"), Some(id("Zootiere")), None)));
+        assert_eq!(tax.redo(), Some(&Operation::RemoveSubtree(id("Haustiere"))));
+        assert_eq!(tax.redo(), None);
+        assert_eq!(tax.nodes, after.nodes);
+        assert_eq!(tax.node0, after.node0);
+        assert_eq!(tax.history().len(), 2);
+
+        // A new operation discards the operations to redo
+        tax.undo();
+        tax.move_down(None, id("Affen")).unwrap();
+        assert_eq!(tax.redo(), None);
+    }
+
+    #[test]
+    fn undo_metadata_mut() {
+        let mut tax: Taxonomy<Uuid, Concept, String> = Taxonomy::new();
+        let concept = Concept::new("Tiere");
+        let concept_id = Rc::new(concept.id());
+        tax.add(None, concept).unwrap();
+        tax.enable_history(10);
+
+        tax.set_metadata(concept_id.clone(), "created".to_string()).unwrap();
+        tax.metadata_mut(concept_id.clone())
+            .unwrap()
+            .unwrap()
+            .push_str(" and updated");
+        assert_eq!(tax.metadata(concept_id.clone()).unwrap().unwrap(), "created and updated");
+
+        assert_eq!(tax.undo(), Some(&Operation::Update(concept_id.clone())));
+        assert_eq!(tax.metadata(concept_id.clone()).unwrap().unwrap(), "created");
+        tax.undo();
+        assert!(tax.metadata(concept_id.clone()).unwrap().is_none());
+
+        tax.redo();
+        tax.redo();
+        assert_eq!(tax.metadata(concept_id).unwrap().unwrap(), "created and updated");
+    }
+
+    #[test]
+    fn undo_transaction() {
+        let (mut tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+        tax.enable_history(100);
+        let before = tax.clone();
+
+        tax.transaction(|tx| {
+            tx.remove_recursively(id("Hunde"))?;
+            tx.move_to(id("Affen"), Some(id("Zootiere")), None, 0)?;
+
+            // Nothing is undone within a transaction
+            assert_eq!(tx.undo(), None);
+            Ok::<(), TaxonomyError<Uuid>>(())
+        })
+        .unwrap();
+        assert_eq!(tax.history(), vec![&Operation::Transaction]);
+
+        // Transactions rolled back are not recorded
+        let result = tax.transaction(|tx| {
+            tx.remove(id("Affen"))?;
+            tx.remove(id("Tiere"))?;
+            Ok(())
+        });
+        assert_eq!(result, Err(NodeHasSubNode(id("Tiere"))));
+        assert_eq!(tax.history().len(), 1);
+
+        assert_eq!(tax.undo(), Some(&Operation::Transaction));
+        assert_eq!(tax.nodes, before.nodes);
+        assert_eq!(tax.node0, before.node0);
+    }
+
+    #[test]
+    fn _journal() {
+        let (mut tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+        tax.enable_reachability_index().traverse();
+
+        let journal = tax._journal(Node::snapshot);
+        assert!(journal.nodes.is_empty());
+        assert_eq!(journal.node0, tax.node0);
+        assert_eq!(journal.last_updated_node, tax.last_updated_node);
+        assert_eq!(journal.cursor, tax.cursor);
+        assert!(journal.reachability);
+        assert!(journal.order.is_none());
+        assert!(tax.node0.contains(&id("Tiere")));
+    }
+
+    #[test]
+    fn _record() {
+        let (mut tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+        let before = tax.clone();
+
+        // Without history, the operation is run only
+        let result = tax._record(Operation::Remove(id("Tiere")), |tax| tax.remove(id("Tiere")).map(|_| ()));
+        assert_eq!(result, Err(NodeHasSubNode(id("Tiere"))));
+
+        // A failing operation is rolled back
+        tax.enable_history(10);
+        let result = tax._record(Operation::UpdateAll, |tax| {
+            tax.remove_recursively(id("Hunde"))?;
+            tax.remove(id("Tiere"))?;
+            Ok(())
+        });
+        assert_eq!(result, Err(NodeHasSubNode(id("Tiere"))));
+        assert_eq!(tax.nodes, before.nodes);
+        assert!(tax.history().is_empty());
+        assert!(tax.journal.is_empty());
+
+        // Nested operations are recorded as one
+        let result = tax._record(Operation::UpdateAll, |tax| {
+            tax.remove_recursively(id("Hunde"))?;
+            tax.move_up(None, id("Tiere"))?;
+            Ok(tax.nodes.len())
+        });
+        assert_eq!(result, Ok(tax.nodes.len()));
+        assert_eq!(tax.history(), vec![&Operation::UpdateAll]);
+    }
+
+    #[test]
+    fn _restore() {
+        let (mut tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+        tax.enable_history(10);
+        let before = tax.clone();
+
+        tax.journal.push(tax._journal(Node::snapshot));
+        tax.remove_recursively(id("Hunde")).unwrap();
+        let journal = tax.journal.pop().unwrap();
+        let node_ids = journal.nodes.keys().cloned().collect::<HashSet<_>>();
+        let after = tax.clone();
+
+        // The inverse records the same nodes, nodes removed by the operation are recorded as missing
+        let inverse = tax._restore(journal);
+        assert_eq!(tax.nodes, before.nodes);
+        assert_eq!(inverse.nodes.keys().cloned().collect::<HashSet<_>>(), node_ids);
+        assert!(inverse.nodes[&id("Doggen")].is_none());
+
+        tax._restore(inverse);
+        assert_eq!(tax.nodes, after.nodes);
+        assert_eq!(tax.node0, after.node0);
+    }
+
+    #[test]
+    fn subscribe() {
+        let (mut tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let observer = events.clone();
+        tax.subscribe(move |event: &Event<Uuid>| observer.borrow_mut().push(event.clone()));
+
+        let pflanzen = Concept::new("Pflanzen");
+        let pflanzen_id = Rc::new(pflanzen.id());
+        tax.add(None, pflanzen).unwrap();
+        tax.append(Some(*pflanzen_id), ids["Hunde"]).unwrap();
+        tax.move_to(id("Affen"), Some(id("Zootiere")), Some(pflanzen_id.clone()), 0)
+            .unwrap();
+        tax.swap(None, id("Tiere"), pflanzen_id.clone()).unwrap();
+        tax.remove_from(Edge::new(Some(pflanzen_id.clone()), id("Hunde"))).unwrap();
+        tax.set_metadata(id("Hunde"), ()).unwrap();
+        *tax.get_mut(id("Hunde")).unwrap() = Concept::with_id(ids["Hunde"], "Hund");
+        tax.remove_subtree(pflanzen_id.clone(), RemovalPolicy::All).unwrap();
+
+        // Failing changes are not sent
+        assert!(tax.remove(id("Tiere")).is_err());
+        assert!(tax.get_mut(pflanzen_id.clone()).is_none());
+
+        assert_eq!(
+            *events.borrow(),
+            vec![
+                Event::NodeAdded(None, pflanzen_id.clone()),
+                Event::EdgeAdded(Some(pflanzen_id.clone()), id("Hunde")),
+                Event::NodeMoved(id("Affen"), Some(id("Zootiere")), Some(pflanzen_id.clone())),
+                Event::NodeMoved(pflanzen_id.clone(), None, None),
+                Event::NodeMoved(id("Tiere"), None, None),
+                Event::EdgeRemoved(Some(pflanzen_id.clone()), id("Hunde")),
+                Event::ElementMutated(id("Hunde")),
+                Event::ElementMutated(id("Hunde")),
+                Event::NodeRemoved(id("Affen")),
+                Event::NodeRemoved(pflanzen_id),
+            ]
+        );
+    }
+
+    #[test]
+    fn subscribe_mutable_references() {
+        let (mut tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let observer = events.clone();
+        tax.subscribe(move |event: &Event<Uuid>| observer.borrow_mut().push(event.clone()));
+
+        // References only read are not reported
+        tax.get_mut(id("Hunde")).unwrap();
+        assert!(tax.iter_mut().all(|concept| !concept.name().is_empty()));
+        assert!(events.borrow().is_empty());
+
+        // Changes are reported once the reference is dropped
+        let mut hunde = tax.get_mut(id("Hunde")).unwrap();
+        *hunde = Concept::with_id(ids["Hunde"], "Hund");
+        assert!(events.borrow().is_empty());
+        drop(hunde);
+        assert_eq!(*events.borrow(), vec![Event::ElementMutated(id("Hunde"))]);
+
+        // Changes through an iterator are reported in order, once the iterator is dropped
+        events.borrow_mut().clear();
+        tax.iter_mut()
+            .skip(1)
+            .take(2)
+            .for_each(|mut concept| *concept = concept.clone());
+        assert_eq!(
+            *events.borrow(),
+            vec![Event::ElementMutated(id("Haustiere")), Event::ElementMutated(id("Fische"))]
+        );
+    }
+
+    #[test]
+    fn subscribe_sort() {
+        let (mut tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let observer = events.clone();
+        tax.subscribe(move |event: &Event<Uuid>| observer.borrow_mut().push(event.clone()));
+
+        // Only nodes changing their position are reported
+        let before = tax._sub_ids_of(Some(id("Haustiere")));
+        tax.sort_subs_by(Some(id("Haustiere")), |a, b| b.name().cmp(&a.name()))
+            .unwrap();
+        let after = tax._sub_ids_of(Some(id("Haustiere")));
+        let moved = before.iter().zip(after.iter()).filter(|(a, b)| a != b).count();
+        assert_eq!(events.borrow().len(), moved);
+        assert!(events
+            .borrow()
+            .iter()
+            .all(|event| matches!(event, Event::NodeMoved(_, Some(a), Some(b)) if *a == id("Haustiere") && *b == id("Haustiere"))));
+
+        events.borrow_mut().clear();
+        tax.sort_subs_by(Some(id("Haustiere")), |a, b| b.name().cmp(&a.name()))
+            .unwrap();
+        assert!(events.borrow().is_empty());
+    }
+
+    #[test]
+    fn subscribe_transaction() {
+        let (mut tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let observer = events.clone();
+        tax.subscribe(move |event: &Event<Uuid>| observer.borrow_mut().push(event.clone()));
+
+        // Changes rolled back are not sent
+        let result = tax.transaction(|tx| {
+            tx.remove_recursively(id("Hunde"))?;
+            tx.remove(id("Tiere"))?;
+            Ok(())
+        });
+        assert_eq!(result, Err(NodeHasSubNode(id("Tiere"))));
+        assert!(events.borrow().is_empty());
+
+        // Changes committed are sent once the outermost transaction is committed
+        let observed = events.clone();
+        tax.transaction(|tx| {
+            tx.move_down(None, id("Tiere"))?;
+            let _ = tx.transaction(|tx| {
+                tx.move_down(None, id("Tiere"))?;
+                tx.remove(id("Tiere"))?;
+                Ok::<(), TaxonomyError<Uuid>>(())
+            });
+            tx.transaction(|tx| {
+                tx.remove_recursively(id("Hunde"))?;
+                Ok::<(), TaxonomyError<Uuid>>(())
+            })?;
+            assert!(observed.borrow().is_empty());
+            Ok::<(), TaxonomyError<Uuid>>(())
+        })
+        .unwrap();
+        assert_eq!(events.borrow()[0], Event::NodeMoved(id("Tiere"), None, None));
+        assert_eq!(events.borrow().last(), Some(&Event::NodeRemoved(id("Hunde"))));
+        assert_eq!(
+            events
+                .borrow()
+                .iter()
+                .filter(|event| matches!(event, Event::NodeMoved(..)))
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn subscribe_undo() {
+        let (mut tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let observer = events.clone();
+        tax.enable_history(10);
+        tax.subscribe(move |event: &Event<Uuid>| observer.borrow_mut().push(event.clone()));
+
+        tax.move_to(id("Affen"), Some(id("Zootiere")), None, 0).unwrap();
+        events.borrow_mut().clear();
+        tax.undo();
+        assert!(events.borrow().contains(&Event::EdgeRemoved(None, id("Affen"))));
+        assert!(events.borrow().contains(&Event::EdgeAdded(Some(id("Zootiere")), id("Affen"))));
+
+        tax.remove_recursively(id("Hunde")).unwrap();
+        events.borrow_mut().clear();
+        tax.undo();
+        assert!(events.borrow().contains(&Event::NodeAdded(Some(id("Hunde")), id("Doggen"))));
+        tax.redo();
+        assert!(events.borrow().contains(&Event::NodeRemoved(id("Doggen"))));
+    }
+
+    #[test]
+    fn unsubscribe() {
+        let (mut tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+        let count = Rc::new(RefCell::new(0));
+        let observer = count.clone();
+        let subscription = tax.subscribe(move |_: &Event<Uuid>| *observer.borrow_mut() += 1);
+        let other = tax.subscribe(|_: &Event<Uuid>| ());
+        assert_ne!(subscription, other);
+
+        tax.move_down(None, id("Tiere")).unwrap();
+        assert!(tax.unsubscribe(subscription));
+        assert!(!tax.unsubscribe(subscription));
+        tax.move_up(None, id("Tiere")).unwrap();
+        assert_eq!(*count.borrow(), 1);
+
+        // Observers are neither cloned nor compared
+        assert!(tax.clone().observers.is_empty());
+        assert_eq!(tax.clone(), tax);
+    }
+
+    #[test]
+    fn _detach() {
+        let (mut tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+        tax.enable_reachability_index();
+        tax.append(Some(ids["Tiere"]), ids["Hunde"]).unwrap();
+        tax.append(None, ids["Hunde"]).unwrap();
+
+        tax._detach(Some(id("Haustiere")), id("Hunde"));
+        assert!(!tax._sub_ids_of(Some(id("Haustiere"))).contains(&id("Hunde")));
+        assert!(!tax.is_descendant_of(id("Hunde"), id("Haustiere")).unwrap());
+
+        tax._detach(None, id("Hunde"));
+        assert!(!tax.node0.contains(&id("Hunde")));
+        assert!(tax.parent_set(id("Hunde")).unwrap().contains(&id("Tiere")));
+        assert!(!tax.parent_set(id("Hunde")).unwrap().contains(&id("Haustiere")));
+        assert_eq!(tax.last_updated_node, Some(id("Hunde")));
+    }
+
+    #[test]
+    fn _emit() {
+        let (mut tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let observer = events.clone();
+
+        // Without observers, nothing is kept back
+        tax.journal.push(tax._journal(Node::snapshot));
+        tax._emit(Event::NodeRemoved(id("Tiere")));
+        assert!(tax.journal[0].events.is_empty());
+
+        tax.subscribe(move |event: &Event<Uuid>| observer.borrow_mut().push(event.clone()));
+        tax._emit(Event::NodeRemoved(id("Tiere")));
+        assert_eq!(tax.journal[0].events, vec![Event::NodeRemoved(id("Tiere"))]);
+        assert!(events.borrow().is_empty());
+
+        tax.journal.pop();
+        tax._emit(Event::ElementMutated(id("Tiere")));
+        assert_eq!(*events.borrow(), vec![Event::ElementMutated(id("Tiere"))]);
+    }
+
+    #[test]
+    fn _notify() {
+        let (mut tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let observer = events.clone();
+        tax.subscribe(move |event: &Event<Uuid>| observer.borrow_mut().push(event.clone()));
+
+        tax._notify(vec![Event::NodeRemoved(id("Hunde")), Event::NodeRemoved(id("Tiere"))]);
+        assert_eq!(
+            *events.borrow(),
+            vec![Event::NodeRemoved(id("Hunde")), Event::NodeRemoved(id("Tiere"))]
+        );
+    }
+
+    #[test]
+    fn _restore_events() {
+        let (mut tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+        tax.append(None, ids["Hunde"]).unwrap();
+        let hunde = tax._get_node_opt(id("Hunde")).unwrap().snapshot();
+        let doggen = tax._get_node_opt(id("Doggen")).unwrap().snapshot();
+
+        type Tax = Taxonomy<Uuid, Concept>;
+        assert!(Tax::_restore_events(id("Hunde"), None, None).is_empty());
+        assert_eq!(
+            Tax::_restore_events(id("Hunde"), Some(&hunde), None),
+            vec![Event::NodeRemoved(id("Hunde"))]
+        );
+        let events = Tax::_restore_events(id("Hunde"), None, Some(&hunde));
+        assert_eq!(events.len(), hunde.count_super());
+        assert_eq!(events[0], Event::NodeAdded(Some(id("Haustiere")), id("Hunde")));
+        assert!(events[1..].iter().all(|event| matches!(event, Event::EdgeAdded(..))));
+        assert!(events.contains(&Event::EdgeAdded(None, id("Hunde"))));
+
+        tax.remove_from(Edge::new(None, id("Hunde"))).unwrap();
+        tax.append(Some(ids["Tiere"]), ids["Hunde"]).unwrap();
+        let current = tax._get_node_opt(id("Hunde")).unwrap();
+        assert_eq!(
+            Tax::_restore_events(id("Hunde"), Some(current), Some(&hunde)),
+            vec![
+                Event::EdgeRemoved(Some(id("Tiere")), id("Hunde")),
+                Event::EdgeAdded(None, id("Hunde")),
+                Event::ElementMutated(id("Hunde")),
+            ]
+        );
+        assert_eq!(
+            Tax::_restore_events(id("Doggen"), Some(&doggen), Some(&doggen)),
+            vec![Event::ElementMutated(id("Doggen"))]
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize() {
+        let (tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+
+        let value = serde_json::to_value(&tax).unwrap();
+        let uuids = |value: &serde_json::Value| serde_json::from_value::<Vec<Uuid>>(value.clone()).unwrap();
+        assert_eq!(
+            uuids(&value["node0"]),
+            vec![ids["Tiere"], ids["Tierhalter"], ids["Tierheime"], ids["Tierschutz"]]
+        );
+
+        // Nodes are serialized depth-first and pre-order, each node once
+        let nodes = value["nodes"].as_array().unwrap();
+        let node_ids = nodes
+            .iter()
+            .map(|node| serde_json::from_value::<Uuid>(node["element"]["id"].clone()).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            node_ids,
+            tax._enumerate_nodes().into_iter().map(|node_id| *node_id).collect::<Vec<_>>()
+        );
+
+        let haustiere = &nodes[1];
+        assert_eq!(haustiere["element"]["name"], "Haustiere");
+        assert_eq!(uuids(&haustiere["subs"]), vec![ids["Fische"], ids["Hunde"], ids["Katzen"]]);
+        assert_eq!(haustiere["supers"], serde_json::json!([ids["Tiere"]]));
+        assert_eq!(haustiere["payloads"], serde_json::json!([[ids["Tiere"], null]]));
+
+        // Serialization is stable
+        let hunde = tax._get_node_opt(id("Hunde")).unwrap();
+        let index = node_ids.iter().position(|node_id| *node_id == ids["Hunde"]).unwrap();
+        assert_eq!(nodes[index]["supers"], serde_json::to_value(hunde.super_ids()).unwrap());
+        assert_eq!(serde_json::to_value(&tax).unwrap(), value);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize() {
+        let (tax, _, list) = setup_tax_animals();
+
+        let json = serde_json::to_string(&tax).unwrap();
+        let deserialized: Taxonomy<Uuid, Concept> = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.nodes, tax.nodes);
+        assert_eq!(deserialized.node0, tax.node0);
+        assert_eq!(serde_json::to_string(&deserialized).unwrap(), json);
+        assert!(deserialized
+            .iter()
+            .map(|element| element.id())
+            .eq(list.iter().map(|(id, _)| *id)));
+
+        // Metadata, payloads and the designated primary super-node are kept
+        let mut tax: Taxonomy<Uuid, Concept, String, u8> = Taxonomy::new();
+        let devices = Concept::new("Devices");
+        let firewall = Concept::new("Firewall");
+        let security = Concept::new("Security");
+        let (id_devices, id_firewall, id_security) = (devices.id(), firewall.id(), security.id());
+        tax.add(None, devices).unwrap();
+        tax.add(None, security).unwrap();
+        tax.add_with_payload(Some(id_devices), firewall, 1).unwrap();
+        tax.append_with_payload(Some(id_security), id_firewall, 2).unwrap();
+        tax.set_primary_super(Rc::new(id_firewall), Some(Rc::new(id_security)))
+            .unwrap();
+        tax.set_metadata(Rc::new(id_firewall), "since 2023".to_string()).unwrap();
+
+        let json = serde_json::to_string(&tax).unwrap();
+        let deserialized: Taxonomy<Uuid, Concept, String, u8> = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.nodes, tax.nodes);
+        assert_eq!(deserialized.node0, tax.node0);
+        assert_eq!(
+            deserialized.primary_super(Rc::new(id_firewall)).unwrap(),
+            Some(Rc::new(id_security))
+        );
+        assert_eq!(
+            deserialized.metadata(Rc::new(id_firewall)).unwrap(),
+            Some(&"since 2023".to_string())
+        );
+        assert_eq!(
+            deserialized
+                .edge(Some(Rc::new(id_security)), Rc::new(id_firewall))
+                .unwrap()
+                .into_payload(),
+            &2
+        );
+
+        // The root of the taxonomy designated as primary super-node is kept, though another super-node comes first
+        tax.append(None, id_firewall).unwrap();
+        tax.set_primary_super(Rc::new(id_firewall), None).unwrap();
+
+        let json = serde_json::to_string(&tax).unwrap();
+        let deserialized: Taxonomy<Uuid, Concept, String, u8> = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.nodes, tax.nodes);
+        assert_eq!(deserialized.primary_super(Rc::new(id_firewall)).unwrap(), None);
+        assert_eq!(
+            deserialized.canonical_path(Rc::new(id_firewall)).unwrap(),
+            vec![Rc::new(id_firewall)]
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_invalid() {
+        let (tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+
+        let value = serde_json::to_value(&tax).unwrap();
+        let index = |name: &str| {
+            value["nodes"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .position(|node| node["element"]["name"] == name)
+                .unwrap()
+        };
+        let err = |value: serde_json::Value| {
+            serde_json::from_value::<Taxonomy<Uuid, Concept>>(value)
+                .unwrap_err()
+                .to_string()
+        };
+
+        // Duplicate node
+        let mut invalid = value.clone();
+        let tiere = invalid["nodes"][0].clone();
+        invalid["nodes"].as_array_mut().unwrap().push(tiere);
+        assert_eq!(err(invalid), DuplicateNode(id("Tiere")).to_string());
+
+        // Duplicate root-node
+        let mut invalid = value.clone();
+        invalid["node0"].as_array_mut().unwrap().push(serde_json::json!(ids["Tiere"]));
+        assert_eq!(err(invalid), DuplicateRootNode(id("Tiere")).to_string());
+
+        // Dangling sub-node
+        let mut invalid = value.clone();
+        let unknown = Uuid::new_v4();
+        invalid["nodes"][index("Tierschutz")]["subs"] = serde_json::json!([unknown]);
+        assert_eq!(err(invalid), NodeNotFound(Rc::new(unknown)).to_string());
+
+        // Dangling root-node
+        let mut invalid = value.clone();
+        invalid["node0"].as_array_mut().unwrap().push(serde_json::json!(unknown));
+        assert_eq!(err(invalid), NodeNotFound(Rc::new(unknown)).to_string());
+
+        // Super-node doesn't list node as sub-node
+        let mut invalid = value.clone();
+        invalid["nodes"][index("Haustiere")]["subs"] = serde_json::json!([ids["Fische"], ids["Hunde"]]);
+        assert_eq!(err(invalid), EdgeNotFound(Some(id("Haustiere")), id("Katzen")).to_string());
+
+        // Sub-node doesn't list node as super-node
+        let mut invalid = value.clone();
+        invalid["nodes"][index("Fische")]["supers"] = serde_json::json!([ids["Tierheime"]]);
+        invalid["nodes"][index("Fische")]["payloads"] = serde_json::json!([[ids["Tierheime"], null]]);
+        assert_eq!(err(invalid), EdgeNotFound(Some(id("Haustiere")), id("Fische")).to_string());
+
+        // Root-node doesn't list the root of the taxonomy as super-node
+        let mut invalid = value.clone();
+        invalid["node0"]
+            .as_array_mut()
+            .unwrap()
+            .push(serde_json::json!(ids["Fische"]));
+        assert_eq!(err(invalid), EdgeNotFound(None, id("Fische")).to_string());
+
+        // Duplicate sub-node
+        let mut invalid = value.clone();
+        invalid["nodes"][index("Haustiere")]["subs"] =
+            serde_json::json!([ids["Fische"], ids["Hunde"], ids["Katzen"], ids["Fische"]]);
+        assert_eq!(err(invalid), DuplicateSubNode(id("Haustiere"), id("Fische")).to_string());
+
+        // Edge without payload
+        let mut invalid = value.clone();
+        invalid["nodes"][index("Fische")]["payloads"] = serde_json::json!([]);
+        assert_eq!(err(invalid), EdgeNotFound(Some(id("Haustiere")), id("Fische")).to_string());
+
+        // Primary super-node isn't a super-node
+        let mut invalid = value.clone();
+        invalid["nodes"][index("Fische")]["primary"] = serde_json::json!(ids["Tierheime"]);
+        assert_eq!(err(invalid), EdgeNotFound(Some(id("Tierheime")), id("Fische")).to_string());
+
+        // Root of the taxonomy designated as primary super-node, but not a super-node
+        let mut invalid = value.clone();
+        invalid["nodes"][index("Fische")]["designated"] = serde_json::json!(true);
+        assert_eq!(err(invalid), EdgeNotFound(None, id("Fische")).to_string());
+
+        // Loop
+        let mut invalid = value.clone();
+        invalid["nodes"][index("Doggen")]["subs"] = serde_json::json!([ids["Tiere"]]);
+        invalid["nodes"][0]["supers"]
+            .as_array_mut()
+            .unwrap()
+            .push(serde_json::json!(ids["Doggen"]));
+        invalid["nodes"][0]["payloads"]
+            .as_array_mut()
+            .unwrap()
+            .push(serde_json::json!([ids["Doggen"], null]));
+        assert_eq!(err(invalid), LoopDetected(id("Tiere")).to_string());
+    }
+
+    fn skos_concept(concept: &Concept) -> SkosConcept {
+        SkosConcept::new(format!("urn:uuid:{}", concept.id()), Some(concept.name()))
+    }
+
+    fn skos_element(concept: &SkosConcept) -> Concept {
+        let id = Uuid::parse_str(concept.uri().trim_start_matches("urn:uuid:")).unwrap();
+        Concept::with_id(id, concept.label().unwrap())
+    }
+
+    #[test]
+    fn to_turtle() {
+        let (tax, ids, _) = setup_tax_animals();
+        let uri = |name: &str| format!("<urn:uuid:{}>", ids[name]);
+
+        let turtle = tax.to_turtle("http://example.org/animals", skos_concept);
+        assert!(turtle.starts_with("@prefix skos: <http://www.w3.org/2004/02/skos/core#> .\n"));
+        assert!(turtle.contains(&format!(
+            "<http://example.org/animals> a skos:ConceptScheme ;\n    skos:hasTopConcept {}, {}, {}, {} .\n",
+            uri("Tiere"),
+            uri("Tierhalter"),
+            uri("Tierheime"),
+            uri("Tierschutz")
+        )));
+        assert!(turtle.contains(&format!(
+            "{} a skos:Concept ;\n    skos:prefLabel \"Tiere\" ;\n    skos:inScheme <http://example.org/animals> ;\n    skos:topConceptOf <http://example.org/animals> ;\n",
+            uri("Tiere")
+        )));
+        assert!(turtle.contains(&format!(
+            "    skos:narrower {}, {}, {} .\n",
+            uri("Fische"),
+            uri("Hunde"),
+            uri("Katzen")
+        )));
+        assert!(turtle.contains(&format!(
+            "    skos:broader {}, {}, {} ;\n",
+            uri("Haustiere"),
+            uri("Säugetiere"),
+            uri("Tierheime")
+        )));
+
+        // Each concept is written once
+        assert_eq!(
+            turtle.matches(" a skos:Concept ;").count(),
+            tax.iter_unique(Occurrence::First).count()
+        );
+        assert_eq!(tax.to_turtle("http://example.org/animals", skos_concept), turtle);
+
+        let tax: Taxonomy<Uuid, Concept> = Taxonomy::new();
+        assert_eq!(
+            tax.to_turtle("http://example.org/empty", skos_concept),
+            "@prefix skos: <http://www.w3.org/2004/02/skos/core#> .\n\n<http://example.org/empty> a skos:ConceptScheme .\n"
+        );
+    }
+
+    #[test]
+    fn to_ntriples() {
+        let (tax, ids, _) = setup_tax_animals();
+        let uri = |name: &str| format!("<urn:uuid:{}>", ids[name]);
+
+        let ntriples = tax.to_ntriples("http://example.org/animals", skos_concept);
+        assert!(ntriples.lines().all(|line| line.starts_with('<') && line.ends_with(" .")));
+        assert!(ntriples.contains(&format!(
+            "{} <http://www.w3.org/2004/02/skos/core#broader> {} .\n",
+            uri("Hunde"),
+            uri("Tierheime")
+        )));
+        assert!(ntriples.contains(&format!(
+            "{} <http://www.w3.org/2004/02/skos/core#prefLabel> \"Waale & Delfine\" .\n",
+            uri("Waale & Delfine")
+        )));
+        assert_eq!(
+            ntriples
+                .matches("<http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <http://www.w3.org/2004/02/skos/core#Concept>")
+                .count(),
+            tax.iter_unique(Occurrence::First).count()
+        );
+    }
+
+    #[test]
+    fn to_turtle_escaped_iri() {
+        let (tax, ids, _) = setup_tax_animals();
+        let concept = |concept: &Concept| {
+            SkosConcept::new(
+                format!("http://example.org/{}<{}>\"|", concept.name(), concept.id()),
+                Some(concept.name()),
+            )
+        };
+        let element = |concept: &SkosConcept| {
+            let id = concept.uri().split("%3C").nth(1).unwrap().trim_end_matches("%3E%22%7C");
+            Concept::with_id(Uuid::parse_str(id).unwrap(), concept.label().unwrap())
+        };
+
+        // Characters IRIs must not contain are percent-encoded, so the export can be imported again
+        let turtle = tax.to_turtle("http://example.org/animals", concept);
+        let iri = format!(
+            "<http://example.org/Waale%20&%20Delfine%3C{}%3E%22%7C>",
+            ids["Waale & Delfine"]
+        );
+        assert!(turtle.contains(&iri));
+        let imported: Taxonomy<Uuid, Concept> = Taxonomy::from_turtle(&turtle, element).unwrap();
+        assert_eq!(imported.nodes, tax.nodes);
+
+        let ntriples = tax.to_ntriples("http://example.org/animals", concept);
+        assert!(ntriples.contains(&iri));
+        let imported: Taxonomy<Uuid, Concept> = Taxonomy::from_ntriples(&ntriples, element).unwrap();
+        assert_eq!(imported.nodes, tax.nodes);
+    }
+
+    #[test]
+    fn from_turtle() {
+        let (tax, _, _) = setup_tax_animals();
+
+        let turtle = tax.to_turtle("http://example.org/animals", skos_concept);
+        let imported: Taxonomy<Uuid, Concept> = Taxonomy::from_turtle(&turtle, skos_element).unwrap();
+        assert_eq!(imported.nodes, tax.nodes);
+        assert_eq!(imported.node0, tax.node0);
+        assert_eq!(imported.to_turtle("http://example.org/animals", skos_concept), turtle);
+
+        // Prefixes, comments, lists, narrower-only and broader-only edges
+        let turtle = r#"
+            PREFIX skos: <http://www.w3.org/2004/02/skos/core#>
+            @prefix ex: <http://example.org/> .
+            @base <http://example.org/> .
+
+            # Devices
+            ex:devices skos:prefLabel "Devices"@en, "Geräte"@de ;
+                skos:narrower ex:servers, <clients> ;
+                skos:topConceptOf ex:scheme ; .
+            ex:clients a skos:Concept ; skos:prefLabel "Client \"PC\"" .
+            ex:routers skos:broader ex:devices, ex:network.
+            ex:scheme skos:hasTopConcept ex:network .
+        "#;
+        let mut elements = Vec::new();
+        let imported: Taxonomy<Uuid, Concept> = Taxonomy::from_turtle(turtle, |concept| {
+            elements.push((concept.uri().to_string(), concept.label().map(|label| label.to_string())));
+            Concept::new(concept.label().unwrap_or(concept.uri()))
+        })
+        .unwrap();
+        assert_eq!(
+            elements,
+            vec![
+                ("http://example.org/devices".to_string(), Some("Devices".to_string())),
+                ("http://example.org/servers".to_string(), None),
+                ("http://example.org/clients".to_string(), Some("Client \"PC\"".to_string())),
+                ("http://example.org/routers".to_string(), None),
+                ("http://example.org/network".to_string(), None),
+            ]
+        );
+        let names = imported.iter().map(|concept| concept.name()).collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            vec![
+                "Devices",
+                "http://example.org/servers",
+                "Client \"PC\"",
+                "http://example.org/routers",
+                "http://example.org/network",
+                "http://example.org/routers",
+            ]
+        );
+    }
+
+    #[test]
+    fn from_ntriples() {
+        let (tax, _, _) = setup_tax_animals();
+
+        let ntriples = tax.to_ntriples("http://example.org/animals", skos_concept);
+        let imported: Taxonomy<Uuid, Concept> = Taxonomy::from_ntriples(&ntriples, skos_element).unwrap();
+        assert_eq!(imported.nodes, tax.nodes);
+        assert_eq!(imported.node0, tax.node0);
+        assert_eq!(imported.to_ntriples("http://example.org/animals", skos_concept), ntriples);
+    }
+
+    #[test]
+    fn from_turtle_invalid() {
+        struct Class {
+            name: String,
+        }
+
+        impl Identity<String> for Class {
+            fn id(&self) -> String {
+                self.name.clone()
+            }
+        }
+
+        let import = |turtle: &str| {
+            Taxonomy::<String, Class>::from_turtle(turtle, |concept| Class {
+                name: concept.uri().to_string(),
+            })
+        };
+        let syntax = |turtle: &str| match import(turtle) {
+            Err(ImportError::Syntax(line, _)) => line,
+            _ => panic!("syntax error expected"),
+        };
+
+        assert_eq!(syntax("ex:a a skos:Concept ."), 1);
+        assert_eq!(syntax("<a> <b> \"unterminated\n ."), 1);
+        assert_eq!(syntax("<a> <b> <c> .\n<a> <b> \"\\x\" ."), 2);
+        assert_eq!(syntax("<a> <b> <c>\n\n"), 3);
+        assert_eq!(syntax("<a> <b> <c> ;\n <d> [ <e> <f> ] ."), 2);
+        assert_eq!(syntax("@prefix ex <http://example.org/> ."), 1);
+        assert_eq!(syntax("@keywords a ."), 1);
+        assert_eq!(syntax("<a> <b> <c"), 1);
+        assert_eq!(import("# empty").unwrap().iter().count(), 0);
+
+        let skos = "http://www.w3.org/2004/02/skos/core#";
+        let turtle = format!("<a> <{0}broader> <b> .\n<b> <{0}broader> <c> .\n<c> <{0}broader> <a> .", skos);
+        assert_eq!(
+            import(&turtle).err(),
+            Some(ImportError::Taxonomy(1, LoopDetected(Rc::new("a".to_string()))))
+        );
+        let turtle = format!(
+            "<r> <{0}narrower> <a> .\n<a> <{0}narrower> <b> .\n<b> <{0}narrower> <a> .",
+            skos
+        );
+        assert_eq!(
+            import(&turtle).err(),
+            Some(ImportError::Taxonomy(3, LoopDetected(Rc::new("a".to_string()))))
+        );
+
+        let result = Taxonomy::<String, Class>::from_turtle(&format!("<a> <{0}broader> <b> .", skos), |_| Class {
+            name: "same".to_string(),
+        });
+        assert_eq!(
+            result.err().map(|err| err.to_string()),
+            Some(ImportError::Taxonomy(1, DuplicateNode(Rc::new("same".to_string()))).to_string())
+        );
+    }
+
+    #[test]
+    fn to_dot() {
+        let (tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+
+        let dot = tax.to_dot(None, |concept| concept.name(), |_, _| None).unwrap();
+        assert!(dot.starts_with("digraph taxonomy {\n    subgraph cluster_roots {\n        style=dashed;\n        label=\"\";\n"));
+        assert!(dot.contains(
+            "        n0 [label=\"Tiere\"];\n        n18 [label=\"Tierhalter\"];\n        n19 [label=\"Tierheime\"];\n        n20 [label=\"Tierschutz\"];\n    }\n"
+        ));
+        assert!(dot.ends_with("}\n"));
+
+        // Each node once, one arrow per edge
+        assert_eq!(dot.matches(" [label=").count(), tax.iter_unique(Occurrence::First).count());
+        assert_eq!(dot.matches("[label=\"Hunde\"]").count(), 1);
+        assert_eq!(dot.matches(" -> n3;\n").count(), 3);
+        assert_eq!(
+            dot.matches(" -> ").count(),
+            tax.edges().filter(|edge| edge.super_id().is_some()).count()
+        );
+
+        let dot = tax
+            .to_dot(
+                None,
+                |concept| format!("\"{}\"", concept.name()),
+                |super_concept, _| match super_concept.id() == *id("Tierheime") {
+                    true => Some("style=dashed".to_string()),
+                    false => None,
+                },
+            )
+            .unwrap();
+        assert!(dot.contains("        n0 [label=\"\\\"Tiere\\\"\"];\n"));
+        assert!(dot.contains("    n19 -> n3 [style=dashed];\n"));
+        assert!(dot.contains("    n1 -> n3;\n"));
+    }
+
+    #[test]
+    fn to_dot_subtree() {
+        let (tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+
+        let dot = tax.to_dot(Some(id("Hunde")), |concept| concept.name(), |_, _| None).unwrap();
+        assert_eq!(
+            dot,
+            "digraph taxonomy {\n    subgraph cluster_roots {\n        style=dashed;\n        label=\"\";\n        n0 [label=\"Hunde\"];\n    }\n    n1 [label=\"Doggen\"];\n    n2 [label=\"Schäferhunde\"];\n    n0 -> n1;\n    n0 -> n2;\n}\n"
+        );
+
+        let unknown = Rc::new(Uuid::new_v4());
+        assert_eq!(
+            tax.to_dot(Some(unknown.clone()), |concept| concept.name(), |_, _| None),
+            Err(NodeNotFound(unknown))
+        );
+
+        let tax: Taxonomy<Uuid, Concept> = Taxonomy::new();
+        assert_eq!(
+            tax.to_dot(None, |concept| concept.name(), |_, _| None).unwrap(),
+            "digraph taxonomy {\n    subgraph cluster_roots {\n        style=dashed;\n        label=\"\";\n    }\n}\n"
+        );
+    }
+
+    #[test]
+    fn to_mermaid() {
+        let (tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+
+        let mermaid = tax
+            .to_mermaid(
+                Some(id("Haustiere")),
+                |concept| format!("\"{}\"", concept.name()),
+                |_, sub_concept| match sub_concept.id() == *id("Schäferhunde") {
+                    true => Some("stroke:gray".to_string()),
+                    false => None,
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            mermaid,
+            "graph TD\n    subgraph roots [\" \"]\n        n0[\"#quot;Haustiere#quot;\"]\n    end\n    n1[\"#quot;Fische#quot;\"]\n    n2[\"#quot;Hunde#quot;\"]\n    n3[\"#quot;Doggen#quot;\"]\n    n4[\"#quot;Schäferhunde#quot;\"]\n    n5[\"#quot;Katzen#quot;\"]\n    n0 --> n1\n    n0 --> n2\n    n0 --> n5\n    n2 --> n3\n    n2 --> n4\n    linkStyle 4 stroke:gray\n"
+        );
+
+        let mermaid = tax.to_mermaid(None, |concept| concept.name(), |_, _| None).unwrap();
+        assert_eq!(mermaid.matches("[\"Hunde\"]").count(), 1);
+        assert_eq!(mermaid.matches(" --> n3\n").count(), 3);
+        assert!(mermaid.contains("        n20[\"Tierschutz\"]\n    end\n"));
+
+        let unknown = Rc::new(Uuid::new_v4());
+        assert_eq!(
+            tax.to_mermaid(Some(unknown.clone()), |concept| concept.name(), |_, _| None),
+            Err(NodeNotFound(unknown))
+        );
+    }
+
+    #[test]
+    fn render_tree() {
+        let (tax, _, list) = setup_tax_animals();
+
+        let tree = tax.render_tree(&TreeStyle::default(), |concept| concept.name());
+        assert!(tree.starts_with("├── Tiere\n│   ├── Haustiere\n│   │   ├── Fische\n│   │   ├── Hunde\n│   │   │   ├── Doggen\n│   │   │   └── Schäferhunde\n│   │   └── Katzen\n"));
+        assert!(tree.contains("│   └── Vögel\n│       ├── Hühner\n│       └── Puten\n├── Tierhalter\n"));
+        assert!(tree.ends_with(
+            "├── Tierheime\n│   ├── Hunde\n│   │   ├── Doggen\n│   │   └── Schäferhunde\n│   └── Katzen\n└── Tierschutz\n"
+        ));
+
+        // One line per occurrence, in order of traversal
+        let names = tree
+            .lines()
+            .map(|line| line.trim_start_matches(['│', '├', '└', '─', ' ']).to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(names, list.iter().map(|(_, concept)| concept.name()).collect::<Vec<_>>());
+
+        let tree = tax.render_tree(&TreeStyle::default().with_glyphs(Glyphs::Ascii), |concept| concept.name());
+        assert!(tree.starts_with("|-- Tiere\n|   |-- Haustiere\n|   |   |-- Fische\n"));
+        assert!(tree.ends_with("|   `-- Katzen\n`-- Tierschutz\n"));
+        assert!(!tree.contains(['│', '├', '└', '─']));
+
+        let tax: Taxonomy<Uuid, Concept> = Taxonomy::new();
+        assert_eq!(tax.render_tree(&TreeStyle::default(), |concept| concept.name()), "");
+    }
+
+    #[test]
+    fn render_tree_max_depth() {
+        let (tax, _, _) = setup_tax_animals();
+
+        let tree = tax.render_tree(&TreeStyle::default().with_max_depth(1), |concept| concept.name());
+        assert_eq!(tree, "├── Tiere\n├── Tierhalter\n├── Tierheime\n└── Tierschutz\n");
+
+        let tree = tax.render_tree(&TreeStyle::default().with_max_depth(2), |concept| concept.name());
+        assert_eq!(tree.lines().count(), 4 + 5 + 2);
+        assert!(tree.contains("├── Tierheime\n│   ├── Hunde\n│   └── Katzen\n"));
+
+        let tree = tax.render_tree(&TreeStyle::default().with_max_depth(0), |concept| concept.name());
+        assert_eq!(tree, "├── Tiere\n├── Tierhalter\n├── Tierheime\n└── Tierschutz\n");
+    }
+
+    #[test]
+    fn render_tree_marker() {
+        let (tax, _, _) = setup_tax_animals();
+
+        let style = TreeStyle::default().with_marker("(duplicate)");
+        let tree = tax.render_tree(&style, |concept| concept.name());
+        assert!(tree.contains("│   ├── Säugetiere\n│   │   ├── Affen (duplicate)\n│   │   ├── Hunde (duplicate)\n│   │   ├── Katzen (duplicate)\n│   │   └── Waale & Delfine\n"));
+        assert!(tree.ends_with("├── Tierheime\n│   ├── Hunde (duplicate)\n│   └── Katzen (duplicate)\n└── Tierschutz\n"));
+        assert_eq!(tree.matches("Doggen").count(), 1);
+        assert_eq!(
+            tree.lines().count(),
+            tax.iter_unique(Occurrence::First).count() + tree.matches("(duplicate)").count()
+        );
+
+        assert_eq!(style.marker(), Some("(duplicate)"));
+        assert_eq!(style.glyphs(), Glyphs::Unicode);
+        assert_eq!(style.max_depth(), None);
+    }
+
+    #[test]
+    fn display() {
+        let (tax, _, _) = setup_tax_animals();
+
+        assert_eq!(
+            format!("{}", tax),
+            tax.render_tree(&TreeStyle::default(), |concept| concept.name())
+        );
+        assert!(tax.to_string().starts_with("├── Tiere\n│   ├── Haustiere\n"));
+    }
+
+    #[test]
+    fn to_outline() {
+        let (tax, _, _) = setup_tax_animals();
+
+        let outline = tax.to_outline(Indent::Tab, |concept| concept.name());
+        assert!(outline.starts_with("Tiere\n\tHaustiere\n\t\tFische\n\t\tHunde\n\t\t\tDoggen\n\t\t\tSchäferhunde\n"));
+        assert!(outline.ends_with("Tierheime\n\tHunde\n\tKatzen\nTierschutz\n"));
+
+        // Sub-nodes are written below the first occurrence of a node only
+        assert_eq!(outline.matches("Hunde\n").count(), 3);
+        assert_eq!(outline.matches("Doggen\n").count(), 1);
+
+        let outline = tax.to_outline(Indent::Spaces(2), |concept| concept.name());
+        assert!(outline.starts_with("Tiere\n  Haustiere\n    Fische\n"));
+        assert_eq!(
+            tax.to_outline(Indent::Spaces(0), |concept| concept.name()),
+            tax.to_outline(Indent::Spaces(1), |concept| concept.name())
+        );
+
+        let tax: Taxonomy<Uuid, Concept> = Taxonomy::new();
+        assert_eq!(tax.to_outline(Indent::Tab, |concept| concept.name()), "");
+    }
+
+    #[test]
+    fn from_outline() {
+        let (tax, _, _) = setup_tax_animals();
+        let names = |tax: &Taxonomy<Uuid, Concept>| tax.iter().map(|concept| concept.name()).collect::<Vec<_>>();
+
+        for indent in [Indent::Tab, Indent::Spaces(4)] {
+            let outline = tax.to_outline(indent, |concept| concept.name());
+            let imported: Taxonomy<Uuid, Concept> = Taxonomy::from_outline(&outline, indent, Concept::new).unwrap();
+            assert_eq!(names(&imported), names(&tax));
+            assert_eq!(imported.to_outline(indent, |concept| concept.name()), outline);
+        }
+
+        // Repeated labels refer to the same node, repeated edges are ignored, blank lines are skipped
+        let outline = "A\n  B\n    C\n\n  D\n    B\n      C\n      E  \nF\n  C\n";
+        let imported: Taxonomy<Uuid, Concept> = Taxonomy::from_outline(outline, Indent::Spaces(2), Concept::new).unwrap();
+        assert_eq!(
+            imported.to_outline(Indent::Spaces(2), |concept| concept.name()),
+            "A\n  B\n    C\n    E\n  D\n    B\nF\n  C\n"
+        );
+
+        let tax: Taxonomy<Uuid, Concept> = Taxonomy::from_outline("\n \n", Indent::Tab, Concept::new).unwrap();
+        assert_eq!(tax.iter().count(), 0);
+    }
+
+    #[test]
+    fn from_outline_invalid() {
+        let import = |outline: &str, indent: Indent| Taxonomy::<Uuid, Concept>::from_outline(outline, indent, Concept::new);
+        let syntax = |outline: &str, indent: Indent| match import(outline, indent) {
+            Err(ImportError::Syntax(line, _)) => line,
+            _ => panic!("syntax error expected"),
+        };
+
+        assert_eq!(syntax("A\n  B\n", Indent::Tab), 2);
+        assert_eq!(syntax("A\n\tB\n", Indent::Spaces(2)), 2);
+        assert_eq!(syntax("A\n  B\n   C\n", Indent::Spaces(2)), 3);
+        assert_eq!(syntax("A\n\n\t\tB\n", Indent::Tab), 3);
+        assert_eq!(syntax(" A\n", Indent::Spaces(1)), 1);
+
+        // Label occurring below itself
+        let err = import("A\n\tB\n\t\tA\n", Indent::Tab).err().unwrap();
+        assert!(matches!(err, ImportError::Taxonomy(3, LoopDetected(_))));
+
+        // Elements with equal ids for different labels
+        let id = Uuid::new_v4();
+        let err = Taxonomy::<Uuid, Concept>::from_outline("A\nB\n", Indent::Tab, |label| Concept::with_id(id, label));
+        assert_eq!(err.err(), Some(ImportError::Taxonomy(2, DuplicateNode(Rc::new(id)))));
+    }
+
+    #[test]
+    fn to_adjacency_csv() {
+        let (tax, ids, _) = setup_tax_animals();
+
+        let csv = tax.to_adjacency_csv();
+        let mut lines = csv.lines();
+        assert_eq!(lines.next(), Some("parent_id,child_id,position"));
+        assert_eq!(lines.next(), Some(format!(",{},0", ids["Tiere"]).as_str()));
+        assert_eq!(lines.next(), Some(format!(",{},1", ids["Tierhalter"]).as_str()));
+
+        // One row per edge, Hunde having three super-nodes
+        assert_eq!(csv.lines().count(), 1 + tax.edges().count());
+        assert_eq!(csv.matches(&format!(",{},", ids["Hunde"])).count(), 3);
+
+        let tax: Taxonomy<Uuid, Concept> = Taxonomy::new();
+        assert_eq!(tax.to_adjacency_csv(), "parent_id,child_id,position\n");
+    }
+
+    #[test]
+    fn from_adjacency_csv() {
+        let (tax, _, _) = setup_tax_animals();
+        let names = |tax: &Taxonomy<Uuid, Concept>| tax.iter().map(|concept| concept.name()).collect::<Vec<_>>();
+
+        let csv = tax.to_adjacency_csv();
+        let imported: Taxonomy<Uuid, Concept> =
+            Taxonomy::from_adjacency_csv(&csv, |id| Concept::with_id(*id, &tax.get(Rc::new(*id)).unwrap().name())).unwrap();
+        assert_eq!(names(&imported), names(&tax));
+        assert_eq!(imported.to_adjacency_csv(), csv);
+
+        // Rows in any order, positions sorting sub-nodes, quoted fields, no header
+        struct Class {
+            name: String,
+        }
+
+        impl Identity<String> for Class {
+            fn id(&self) -> String {
+                self.name.clone()
+            }
+        }
+
+        let csv = "\"b\",d,\nA,\"c\",1\n\n,A,0\nA , b , 0\nb,\"e, f\",0\n";
+        let imported: Taxonomy<String, Class> =
+            Taxonomy::from_adjacency_csv(csv, |id: &String| Class { name: id.clone() }).unwrap();
+        assert_eq!(
+            imported.iter().map(|class| class.id()).collect::<Vec<_>>(),
+            vec!["A", "b", "e, f", "d", "c"]
+        );
+        assert_eq!(
+            imported.to_adjacency_csv(),
+            "parent_id,child_id,position\n,A,0\nA,b,0\nA,c,1\nb,\"e, f\",0\nb,d,1\n"
+        );
+
+        // Header in the first row which is not blank, later on it is an edge
+        let import = |csv: &str| Taxonomy::<String, Class>::from_adjacency_csv(csv, |id: &String| Class { name: id.clone() });
+        assert_eq!(import("\n  \nparent_id,child_id,position\n,A,0\n").unwrap().iter().count(), 1);
+        assert_eq!(
+            import(",A,0\nparent_id,child_id,position\n").err(),
+            Some(vec![ImportError::Syntax(2, "invalid position `position`".to_string())])
+        );
+    }
+
+    #[test]
+    fn from_adjacency_csv_invalid() {
+        struct Class {
+            name: String,
+        }
+
+        impl Identity<String> for Class {
+            fn id(&self) -> String {
+                self.name.clone()
+            }
+        }
+
+        let import = |csv: &str| Taxonomy::<String, Class>::from_adjacency_csv(csv, |id: &String| Class { name: id.clone() });
+        let lines = |csv: &str| import(csv).err().unwrap().iter().map(|err| err.line()).collect::<Vec<_>>();
+
+        // Syntax errors are collected
+        let errors = import(",A,0\nA,B\nA,B,x\nA,,0\nA,\"B,0\nA,B,0\n").err().unwrap();
+        assert_eq!(errors.len(), 4);
+        assert!(errors.iter().all(|err| matches!(err, ImportError::Syntax(_, _))));
+        assert_eq!(lines(",A,0\nA,B\nA,B,x\nA,,0\nA,\"B,0\nA,B,0\n"), vec![2, 3, 4, 5]);
+        let errors = Taxonomy::<Uuid, Concept>::from_adjacency_csv(",A,0\n", |id| Concept::with_id(*id, "A"));
+        assert!(matches!(errors.err().unwrap()[..], [ImportError::Syntax(1, _)]));
+
+        // Elements created have to carry the id of their child, rows of children not added fail
+        let errors = Taxonomy::<String, Class>::from_adjacency_csv(",A,0\nA,B,0\nB,C,0\n", |id: &String| Class {
+            name: id.replace('B', "X"),
+        })
+        .err()
+        .unwrap();
+        assert!(matches!(errors[0], ImportError::Syntax(2, _)));
+        assert_eq!(errors[1], ImportError::Taxonomy(3, NodeNotFound(Rc::new("B".to_string()))));
+
+        // Taxonomy errors are collected per row
+        let id = |id: &str| Rc::new(id.to_string());
+        let errors = import(",A,0\n,A,1\nA,B,0\nA,B,1\nB,C,0\nC,A,0\nX,D,0\nE,F,0\nF,E,0\nE,G,0\n")
+            .err()
+            .unwrap();
+        assert_eq!(
+            errors,
+            vec![
+                ImportError::Taxonomy(2, DuplicateRootNode(id("A"))),
+                ImportError::Taxonomy(4, DuplicateSubNode(id("A"), id("B"))),
+                ImportError::Taxonomy(6, LoopDetected(id("A"))),
+                ImportError::Taxonomy(7, NodeNotFound(id("X"))),
+                ImportError::Taxonomy(8, LoopDetected(id("E"))),
+                ImportError::Taxonomy(9, LoopDetected(id("F"))),
+                ImportError::Taxonomy(10, LoopDetected(id("E"))),
+            ]
+        );
+    }
+
+    #[test]
+    fn from_obo() {
+        struct Class {
+            id: String,
+            name: String,
+            is_obsolete: bool,
+        }
+
+        impl Identity<String> for Class {
+            fn id(&self) -> String {
+                self.id.clone()
+            }
+        }
+
+        let obo = "format-version: 1.4\nontology: ex ! header\n\n\
+                   [Term]\nid: EX:1\nname: animal\n\n\
+                   ! comment\n\
+                   [Term]\nid: EX:3 {source=\"x\"}\nname: dog\nis_a: EX:2 ! mammal\nis_a: EX:4\n\
+                   synonym: \"hound ! not a comment\" EXACT []\nxref: W:Dog ! comment\nsynonym: \"canine\" RELATED []\n\n\
+                   [Typedef]\nid: part_of\nname: part of\n\n\
+                   [Term]\nid: EX:2\nname: mammal\nis_a: EX:1\n\n\
+                   [Term]\nid: EX:4\nname: pet\n\n\
+                   [Term]\nid: EX:5\nname: dinosaur\nis_obsolete: true\n";
+        let tax: Taxonomy<String, Class, Vec<(String, String)>> = Taxonomy::from_obo(obo, |term| Class {
+            id: term.id().to_string(),
+            name: term.name().unwrap().to_string(),
+            is_obsolete: term.is_obsolete(),
+        })
+        .unwrap();
+        assert_eq!(
+            tax.iter().map(|class| class.name.clone()).collect::<Vec<_>>(),
+            vec!["animal", "mammal", "dog", "pet", "dog", "dinosaur"]
+        );
+        assert_eq!(
+            tax.iter()
+                .filter(|class| class.is_obsolete)
+                .map(|class| class.id())
+                .collect::<Vec<_>>(),
+            vec!["EX:5"]
+        );
+        assert!(tax.get(Rc::new("part_of".to_string())).is_none());
+
+        // Unknown tags are kept as metadata without comments
+        let metadata = tax.metadata(Rc::new("EX:3".to_string())).unwrap().unwrap();
+        assert_eq!(
+            metadata,
+            &vec![
+                ("synonym".to_string(), "\"hound ! not a comment\" EXACT []".to_string()),
+                ("xref".to_string(), "W:Dog".to_string()),
+                ("synonym".to_string(), "\"canine\" RELATED []".to_string()),
+            ]
+        );
+        assert_eq!(tax.metadata(Rc::new("EX:1".to_string())).unwrap(), None);
+
+        // Metadata collected into a map
+        let tax: Taxonomy<String, Class, HashMap<String, String>> = Taxonomy::from_obo(obo, |term| Class {
+            id: term.id().to_string(),
+            name: String::new(),
+            is_obsolete: false,
+        })
+        .unwrap();
+        let metadata = tax.metadata(Rc::new("EX:3".to_string())).unwrap().unwrap();
+        assert_eq!(metadata["xref"], "W:Dog");
+
+        let tax: Taxonomy<String, Class, Vec<(String, String)>> =
+            Taxonomy::from_obo("format-version: 1.4\n", |_| unreachable!()).unwrap();
+        assert_eq!(tax.iter().count(), 0);
+    }
+
+    #[test]
+    fn from_obo_invalid() {
+        struct Class {
+            id: String,
+        }
+
+        impl Identity<String> for Class {
+            fn id(&self) -> String {
+                self.id.clone()
+            }
+        }
+
+        let import = |obo: &str| {
+            Taxonomy::<String, Class, Vec<(String, String)>>::from_obo(obo, |term| Class {
+                id: term.id().to_string(),
+            })
+        };
+        let syntax = |obo: &str| match import(obo) {
+            Err(ImportError::Syntax(line, _)) => line,
+            _ => panic!("syntax error expected"),
+        };
+
+        assert_eq!(syntax("[Term\nid: A\n"), 1);
+        assert_eq!(syntax("[Term]\nid: A\nname\n"), 3);
+        assert_eq!(syntax("[Term]\nid: A\n: B\n"), 3);
+        assert_eq!(syntax("[Term]\nname: A\n\n[Term]\nid: B\n"), 1);
+        assert_eq!(syntax("[Term]\nid: A\nname: B\n[Term]\nname: C\n"), 4);
+        assert_eq!(syntax("[Term]\nid: A\nid: B\n"), 3);
+        assert_eq!(syntax("[Term]\nid: A\nname: B\nname: C\n"), 4);
+        assert_eq!(syntax("[Term]\nid: A\nis_a: ! none\n"), 3);
+        assert_eq!(syntax("[Term]\nid: A\nis_obsolete: yes\n"), 3);
+        assert_eq!(syntax("[Term]\nid: A\nis_obsolete: true\nis_obsolete: true\n"), 4);
+
+        let id = |id: &str| Rc::new(id.to_string());
+        assert_eq!(
+            import("[Term]\nid: A\n\n[Term]\nid: A\n").err(),
+            Some(ImportError::Taxonomy(4, DuplicateNode(id("A"))))
+        );
+        assert_eq!(
+            import("[Term]\nid: A\nis_a: B\n").err(),
+            Some(ImportError::Taxonomy(3, NodeNotFound(id("B"))))
+        );
+        assert_eq!(
+            import("[Term]\nid: A\nis_a: B\nis_a: B\n\n[Term]\nid: B\n").err(),
+            Some(ImportError::Taxonomy(4, DuplicateSubNode(id("B"), id("A"))))
+        );
+        assert_eq!(
+            import("[Term]\nid: A\n\n[Term]\nid: B\nis_a: A\nis_a: C\n\n[Term]\nid: C\nis_a: B\n").err(),
+            Some(ImportError::Taxonomy(7, LoopDetected(id("B"))))
+        );
+        assert_eq!(
+            import("[Term]\nid: A\nis_a: B\n\n[Term]\nid: B\nis_a: A\n").err(),
+            Some(ImportError::Taxonomy(1, LoopDetected(id("A"))))
+        );
+
+        // Element with another id than the term, with and without metadata
+        let other = |obo: &str| {
+            Taxonomy::<String, Class, Vec<(String, String)>>::from_obo(obo, |term| Class {
+                id: term.id().replace("X:1", "X:2"),
+            })
+        };
+        assert!(matches!(other("[Term]\nid: X:1\n"), Err(ImportError::Syntax(1, _))));
+        assert!(matches!(
+            other("[Term]\nid: A\n\n[Term]\nid: X:1\nis_a: A\ndef: \"B\" []\n"),
+            Err(ImportError::Syntax(4, _))
+        ));
+    }
+
+    #[test]
+    fn eq() {
+        let (mut tax, ids, _) = setup_tax_animals();
+        let before = tax.clone();
+
+        // Indexes, settings and history are not compared
+        tax.enable_reachability_index().enable_history(10);
+        tax.traverse();
+        assert_eq!(tax, before);
+
+        let mut empty: Taxonomy<Uuid, Concept> = Taxonomy::new();
+        empty.enable_reachability_index();
+        assert_eq!(empty, Taxonomy::new());
+
+        tax.remove(Rc::new(ids["Tierschutz"])).unwrap();
+        assert_ne!(tax, before);
+    }
+
+    #[test]
+    fn traverse() {
+        let (mut tax, _, list) = setup_tax_animals();
+
+        let mut i = 0;
+        while let Some(concept) = tax.traverse() {
+            let c = list.iter().nth(i).unwrap().1.clone();
+            assert_eq!(*concept, c);
+            i += 1;
+        }
+    }
+
+    #[test]
+    fn traverse_mut() {
+        let (mut tax, _, list) = setup_tax_animals();
+
+        let mut i = 0;
+        while let Some(concept) = tax.traverse_mut() {
+            let c = list.iter().nth(i).unwrap().1.clone();
+            assert_eq!(*concept, c);
+            i += 1;
+        }
+    }
+
+    #[test]
+    fn iter() {
+        let (tax, _, list) = setup_tax_animals();
+
+        let elements = tax.iter().collect::<Vec<&Concept>>();
+        assert_eq!(elements.len(), list.len());
+        for (element, (_, concept)) in elements.into_iter().zip(list.iter()) {
+            assert_eq!(element, concept);
+        }
+
+        // Two iterators traverse the taxonomy independently
+        let mut first = tax.iter();
+        let mut second = tax.iter();
+        first.next();
+        assert_eq!(second.next(), Some(&list.front().unwrap().1));
+        assert_eq!(first.next(), Some(&list.iter().nth(1).unwrap().1));
+
+        // Iterator is fused and does not start over
+        let mut iter = tax.iter();
+        for _ in iter.by_ref() {}
+        assert_eq!(iter.next(), None);
+
+        // Iterating an empty taxonomy
+        let tax = setup_tax_empty();
+        assert_eq!(tax.iter().next(), None);
+    }
+
+    #[test]
+    fn iter_in() {
+        let (tax, _, list) = setup_tax_animals();
+
+        // Pre-order equals traversal
+        let names = tax
+            .iter_in(TraversalOrder::PreOrder)
+            .map(|c| c.name())
+            .collect::<Vec<String>>();
+        assert_eq!(names, list.iter().map(|(_, c)| c.name()).collect::<Vec<String>>());
+
+        // Post-order returns sub-nodes before their super-node
+        let names = tax
+            .iter_in(TraversalOrder::PostOrder)
+            .map(|c| c.name())
+            .collect::<Vec<String>>();
+        assert_eq!(
+            names,
+            [
+                "Fische",
+                "Doggen",
+                "Schäferhunde",
+                "Hunde",
+                "Katzen",
+                "Haustiere",
+                "Hühner",
+                "Puten",
+                "Geflügel",
+                "Rind",
+                "Nutztiere",
+                "Affen",
+                "Schlangen",
+                "Zootiere",
+                "Affen",
+                "Doggen",
+                "Schäferhunde",
+                "Hunde",
+                "Katzen",
+                "Waale & Delfine",
+                "Säugetiere",
+                "Hühner",
+                "Puten",
+                "Vögel",
+                "Tiere",
+                "Tierhalter",
+                "Doggen",
+                "Schäferhunde",
+                "Hunde",
+                "Katzen",
+                "Tierheime",
+                "Tierschutz"
+            ]
+        );
+
+        // Breadth-first returns level by level
+        let names = tax
+            .iter_in(TraversalOrder::BreadthFirst)
+            .map(|c| c.name())
+            .collect::<Vec<String>>();
+        let levels = tax.levels().flatten().map(|c| c.name()).collect::<Vec<String>>();
+        assert_eq!(names.len(), list.len());
+        assert_eq!(names, levels);
+        assert_eq!(names[..5], ["Tiere", "Tierhalter", "Tierheime", "Tierschutz", "Haustiere"]);
+        assert_eq!(names[names.len() - 2..], ["Doggen", "Schäferhunde"]);
+
+        // Empty taxonomy
+        let tax = setup_tax_empty();
+        assert_eq!(tax.iter_in(TraversalOrder::PostOrder).next(), None);
+        assert_eq!(tax.iter_in(TraversalOrder::BreadthFirst).next(), None);
+    }
+
+    #[test]
+    fn levels() {
+        let (tax, _, _) = setup_tax_animals();
+
+        let levels = tax
+            .levels()
+            .map(|level| level.iter().map(|c| c.name()).collect::<Vec<String>>())
+            .collect::<Vec<Vec<String>>>();
+
+        assert_eq!(levels.len(), 4);
+        assert_eq!(levels[0], ["Tiere", "Tierhalter", "Tierheime", "Tierschutz"]);
+        assert_eq!(
+            levels[1],
+            ["Haustiere", "Nutztiere", "Zootiere", "Säugetiere", "Vögel", "Hunde", "Katzen"]
+        );
+        assert_eq!(levels[2].len(), 15);
+        assert_eq!(
+            levels[3],
+            ["Doggen", "Schäferhunde", "Hühner", "Puten", "Doggen", "Schäferhunde"]
+        );
+
+        let tax = setup_tax_empty();
+        assert_eq!(tax.levels().next(), None);
+    }
+
+    #[test]
+    fn iter_unique() {
+        let (mut tax, ids, _) = setup_tax_animals();
+        let expectation = tax._enumerate_nodes();
+
+        // First occurrence
+        let node_ids = tax
+            .iter_unique(Occurrence::First)
+            .map(|c| Rc::new(c.id()))
+            .collect::<Vec<Rc<Uuid>>>();
+        assert_eq!(node_ids, expectation);
+
+        // Primary occurrence defaults to the super-node a node was added to first
+        let node_ids = tax
+            .iter_unique(Occurrence::Primary)
+            .map(|c| Rc::new(c.id()))
+            .collect::<Vec<Rc<Uuid>>>();
+        assert_eq!(node_ids, expectation);
+
+        // Designating Tierheime as primary super-node moves Hunde and its sub-nodes
+        let _ = tax.set_primary_super(Rc::new(ids["Hunde"]), Some(Rc::new(ids["Tierheime"])));
+        let names = tax
+            .iter_unique(Occurrence::Primary)
+            .map(|c| c.name())
+            .collect::<Vec<String>>();
+        assert_eq!(names.len(), tax.nodes.len());
+        assert_eq!(
+            names[names.len() - 5..],
+            ["Tierheime", "Hunde", "Doggen", "Schäferhunde", "Tierschutz"]
+        );
+        assert_eq!(names[..5], ["Tiere", "Haustiere", "Fische", "Katzen", "Nutztiere"]);
+
+        let tax = setup_tax_empty();
+        assert!(tax.iter_unique(Occurrence::Primary).next().is_none());
+    }
+
+    #[test]
+    fn metadata() {
+        let (tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+
+        // Taxonomy without metadata
+        assert_eq!(tax.metadata(id("Tiere")), Ok(None));
+
+        let mut tax: Taxonomy<Uuid, Concept, String> = Taxonomy::new();
+        let element = Concept::new("Tiere");
+        let tiere = Rc::new(element.id());
+        tax.add(None, element).unwrap();
+        assert_eq!(tax.metadata(tiere.clone()), Ok(None));
+
+        tax.set_metadata(tiere.clone(), "created by admin".to_string()).unwrap();
+        assert_eq!(tax.metadata(tiere), Ok(Some(&"created by admin".to_string())));
+
+        let unknown = Rc::new(Uuid::new_v4());
+        assert_eq!(tax.metadata(unknown.clone()), Err(NodeNotFound(unknown)));
+    }
+
+    #[test]
+    fn metadata_mut() {
+        let mut tax: Taxonomy<Uuid, Concept, Vec<String>> = Taxonomy::new();
+        let element = Concept::new("Tiere");
+        let tiere = Rc::new(element.id());
+        tax.add(None, element).unwrap();
+        assert!(tax.metadata_mut(tiere.clone()).unwrap().is_none());
+
+        tax.set_metadata(tiere.clone(), vec!["admin".to_string()]).unwrap();
+        tax.metadata_mut(tiere.clone()).unwrap().unwrap().push("root".to_string());
+        assert_eq!(tax.metadata(tiere).unwrap().unwrap().len(), 2);
+
+        let unknown = Rc::new(Uuid::new_v4());
+        assert_eq!(tax.metadata_mut(unknown.clone()).err(), Some(NodeNotFound(unknown)));
+    }
+
+    #[test]
+    fn set_metadata() {
+        let mut tax: Taxonomy<Uuid, Concept, u32> = Taxonomy::new();
+        let element = Concept::new("Tiere");
+        let tiere = Rc::new(element.id());
+        tax.add(None, element).unwrap();
+        let element = Concept::new("Haustiere");
+        let haustiere = Rc::new(element.id());
+        tax.add(Some(*tiere), element).unwrap();
+
+        tax.set_metadata(tiere.clone(), 1)
+            .unwrap()
+            .set_metadata(haustiere.clone(), 2)
+            .unwrap();
+        assert_eq!(tax.metadata(tiere.clone()), Ok(Some(&1)));
+        assert_eq!(tax.metadata(haustiere.clone()), Ok(Some(&2)));
+        assert_eq!(tax.last_updated_node(), Some(haustiere));
+
+        // Metadata is replaced
+        tax.set_metadata(tiere.clone(), 3).unwrap();
+        assert_eq!(tax.metadata(tiere.clone()), Ok(Some(&3)));
+
+        let last_updated_node = tax.last_updated_node();
+        let unknown = Rc::new(Uuid::new_v4());
+        assert_eq!(tax.set_metadata(unknown.clone(), 4).err(), Some(NodeNotFound(unknown)));
+        assert_eq!(tax.last_updated_node(), last_updated_node);
+    }
+
+    #[test]
+    fn remove_metadata() {
+        let mut tax: Taxonomy<Uuid, Concept, u32> = Taxonomy::new();
+        let element = Concept::new("Tiere");
+        let tiere = Rc::new(element.id());
+        tax.add(None, element).unwrap();
+
+        assert_eq!(tax.remove_metadata(tiere.clone()), Ok(None));
+        tax.set_metadata(tiere.clone(), 1).unwrap();
+        assert_eq!(tax.remove_metadata(tiere.clone()), Ok(Some(1)));
+        assert_eq!(tax.metadata(tiere.clone()), Ok(None));
+
+        // Metadata is dropped together with the node
+        tax.set_metadata(tiere.clone(), 1).unwrap();
+        tax.remove(tiere.clone()).unwrap();
+        assert_eq!(tax.remove_metadata(tiere.clone()), Err(NodeNotFound(tiere)));
+    }
+
+    #[test]
+    fn primary_super() {
+        let (tax, ids, _) = setup_tax_animals();
+
+        assert_eq!(tax.primary_super(Rc::new(ids["Tiere"])), Ok(None));
+        assert_eq!(tax.primary_super(Rc::new(ids["Hunde"])), Ok(Some(Rc::new(ids["Haustiere"]))));
+
+        let id = Rc::new(Uuid::new_v4());
+        assert_eq!(tax.primary_super(id.clone()), Err(NodeNotFound(id)));
+    }
+
+    #[test]
+    fn primary_super_appended_to_root() {
+        let (mut tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+
+        // Appending to the root keeps the super-node the node has been added to first
+        tax.append(None, ids["Doggen"]).unwrap();
+        assert_eq!(tax.primary_super(id("Doggen")), Ok(Some(id("Hunde"))));
+        assert_eq!(
+            tax.canonical_path(id("Doggen")).unwrap(),
+            vec![id("Tiere"), id("Haustiere"), id("Hunde"), id("Doggen")]
+        );
+
+        // Removing the edge to the primary super-node makes the next one primary
+        tax.remove_from(Edge::new(Some(id("Hunde")), id("Doggen"))).unwrap();
+        assert_eq!(tax.primary_super(id("Doggen")), Ok(None));
+        assert_eq!(tax.canonical_path(id("Doggen")).unwrap(), vec![id("Doggen")]);
+    }
+
+    #[test]
+    fn set_primary_super() {
+        let (mut tax, ids, _) = setup_tax_animals();
+        let id_hunde = Rc::new(ids["Hunde"]);
+        let id_tierheime = Rc::new(ids["Tierheime"]);
+
+        let result = tax.set_primary_super(id_hunde.clone(), Some(id_tierheime.clone()));
+        assert!(result.is_ok());
+        assert_eq!(tax.primary_super(id_hunde.clone()), Ok(Some(id_tierheime.clone())));
+        assert_eq!(tax.last_updated_node(), Some(id_hunde.clone()));
+
+        // Edge does not exist
+        let result = tax.set_primary_super(id_hunde.clone(), None);
+        assert_eq!(result.err(), Some(EdgeNotFound(None, id_hunde.clone())));
+
+        // Node does not exist
+        let id = Rc::new(Uuid::new_v4());
+        let result = tax.set_primary_super(id.clone(), None);
+        assert_eq!(result.err(), Some(NodeNotFound(id)));
+
+        // Removing the edge to the primary super-node resets the designation
+        let _ = tax.remove_from(Edge::new(Some(id_tierheime), id_hunde.clone()));
+        assert_eq!(tax.primary_super(id_hunde), Ok(Some(Rc::new(ids["Haustiere"]))));
+    }
+
+    #[test]
+    fn visits() {
+        let (tax, ids, list) = setup_tax_animals();
+
+        let visits = tax.visits().collect::<Vec<_>>();
+        assert_eq!(visits.len(), list.len());
+        for (visit, (_, concept)) in visits.iter().zip(list.iter()) {
+            assert_eq!(visit.element(), concept);
+            assert_eq!(visit.edge().node_id(), Rc::new(concept.id()));
+            assert_eq!(*visit.path().last().unwrap(), visit.edge().node_id());
+            assert_eq!(visit.depth() + 1, visit.path().len());
+            assert_eq!(visit.edge().super_id(), visit.path().iter().rev().nth(1).cloned());
+        }
+
+        // Hunde is visited three times, once per super-node
+        let id_hunde = Rc::new(ids["Hunde"]);
+        let paths = visits
+            .iter()
+            .filter(|visit| visit.edge().node_id() == id_hunde)
+            .map(|visit| visit.path().to_vec())
+            .collect::<Vec<Vec<Rc<Uuid>>>>();
+        let expectation = vec![
+            vec![Rc::new(ids["Tiere"]), Rc::new(ids["Haustiere"]), id_hunde.clone()],
+            vec![Rc::new(ids["Tiere"]), Rc::new(ids["Säugetiere"]), id_hunde.clone()],
+            vec![Rc::new(ids["Tierheime"]), id_hunde.clone()],
+        ];
+        assert_eq!(paths, expectation);
+
+        // Root-nodes are reached through an edge without super-node
+        let first = &visits[0];
+        assert_eq!(first.depth(), 0);
+        assert_eq!(first.edge(), &Edge::new(None, Rc::new(ids["Tiere"])));
+
+        let tax = setup_tax_empty();
+        assert!(tax.visits().next().is_none());
+    }
+
+    #[test]
+    fn ancestors() {
+        let (tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+
+        let ancestors = tax.ancestors(id("Doggen"), None).unwrap().collect::<Vec<Rc<Uuid>>>();
+        let expectation = vec![id("Hunde"), id("Haustiere"), id("Säugetiere"), id("Tierheime"), id("Tiere")];
+        assert_eq!(ancestors, expectation);
+
+        let ancestors = tax.ancestors(id("Doggen"), Some(2)).unwrap().collect::<Vec<Rc<Uuid>>>();
+        assert_eq!(ancestors, expectation[..4]);
+
+        assert_eq!(tax.ancestors(id("Doggen"), Some(0)).unwrap().count(), 0);
+        assert_eq!(tax.ancestors(id("Tiere"), None).unwrap().count(), 0);
+
+        let unknown = Rc::new(Uuid::new_v4());
+        assert_eq!(tax.ancestors(unknown.clone(), None).err(), Some(NodeNotFound(unknown)));
+    }
+
+    #[test]
+    fn ancestor_set() {
+        let (tax, ids, _) = setup_tax_animals();
+
+        let ancestors = tax.ancestor_set(Rc::new(ids["Puten"]), None).unwrap();
+        assert_eq!(ancestors.len(), 4);
+        assert!(ancestors.contains(&Rc::new(ids["Vögel"])));
+        assert!(ancestors.contains(&Rc::new(ids["Tiere"])));
+    }
+
+    #[test]
+    fn descendants() {
+        let (tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+
+        assert_eq!(tax.descendants(id("Tiere"), None).unwrap().count(), 17);
+        assert_eq!(tax.descendants(id("Tiere"), Some(1)).unwrap().count(), 5);
+
+        let descendants = tax.descendants(id("Tierheime"), None).unwrap().collect::<Vec<Rc<Uuid>>>();
+        assert_eq!(descendants, vec![id("Hunde"), id("Katzen"), id("Doggen"), id("Schäferhunde")]);
+
+        assert_eq!(tax.descendants(id("Tierschutz"), None).unwrap().count(), 0);
+
+        let unknown = Rc::new(Uuid::new_v4());
+        assert_eq!(tax.descendants(unknown.clone(), None).err(), Some(NodeNotFound(unknown)));
+    }
+
+    #[test]
+    fn descendant_set() {
+        let (tax, ids, _) = setup_tax_animals();
+
+        let descendants = tax.descendant_set(Rc::new(ids["Tiere"]), None).unwrap();
+        assert_eq!(descendants, tax._enumerate_subs(Rc::new(ids["Tiere"])));
+
+        let descendants = tax.descendant_set(Rc::new(ids["Tiere"]), Some(2)).unwrap();
+        assert_eq!(descendants.len(), 15);
+    }
+
+    #[test]
+    fn parents() {
+        let (tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+
+        let parents = tax.parents(id("Katzen")).unwrap().collect::<Vec<Rc<Uuid>>>();
+        assert_eq!(parents, vec![id("Haustiere"), id("Säugetiere"), id("Tierheime")]);
+        assert_eq!(tax.parents(id("Tiere")).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn parent_set() {
+        let (tax, ids, _) = setup_tax_animals();
+
+        assert_eq!(tax.parent_set(Rc::new(ids["Hühne"])).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn children() {
+        let (tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+
+        let children = tax.children(id("Hunde")).unwrap().collect::<Vec<Rc<Uuid>>>();
+        assert_eq!(children, vec![id("Doggen"), id("Schäferhunde")]);
+        assert_eq!(tax.children(id("Doggen")).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn child_set() {
+        let (tax, ids, _) = setup_tax_animals();
+
+        assert_eq!(tax.child_set(Rc::new(ids["Säugetiere"])).unwrap().len(), 4);
+    }
+
+    #[test]
+    fn siblings() {
+        let (tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+
+        // Co-nodes below Haustiere, Säugetiere and Tierheime, Katzen only once
+        let siblings = tax.siblings(id("Hunde")).unwrap().collect::<Vec<Rc<Uuid>>>();
+        assert_eq!(siblings, vec![id("Fische"), id("Katzen"), id("Affen"), id("Waale & Delfine")]);
+
+        // Root-nodes are co-nodes
+        let siblings = tax.siblings(id("Tierhalter")).unwrap().collect::<Vec<Rc<Uuid>>>();
+        assert_eq!(siblings, vec![id("Tiere"), id("Tierheime"), id("Tierschutz")]);
+
+        let unknown = Rc::new(Uuid::new_v4());
+        assert_eq!(tax.siblings(unknown.clone()).err(), Some(NodeNotFound(unknown)));
+    }
+
+    #[test]
+    fn sibling_set() {
+        let (tax, ids, _) = setup_tax_animals();
+
+        let siblings = tax.sibling_set(Rc::new(ids["Doggen"])).unwrap();
+        assert_eq!(siblings.len(), 1);
+        assert!(siblings.contains(&Rc::new(ids["Schäferhunde"])));
+    }
+
+    /// Asserts that the reachability index matches the ancestors computed without index.
+    fn assert_reachability_index_consistent(tax: &Taxonomy<Uuid, Concept>) {
+        let reachability = tax.reachability.as_ref().unwrap();
+        assert_eq!(reachability.len(), tax.nodes.len());
+        for node_id in tax.nodes.keys() {
+            let ancestors = tax.ancestors(node_id.clone(), None).unwrap().collect::<HashSet<Rc<Uuid>>>();
+            assert_eq!(reachability[node_id], ancestors);
+        }
+    }
+
+    #[test]
+    fn is_descendant_of() {
+        let (mut tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+
+        for _ in 0..2 {
+            assert_eq!(tax.is_descendant_of(id("Doggen"), id("Tierheime")), Ok(true));
+            assert_eq!(tax.is_descendant_of(id("Doggen"), id("Tiere")), Ok(true));
+            assert_eq!(tax.is_descendant_of(id("Tiere"), id("Doggen")), Ok(false));
+            assert_eq!(tax.is_descendant_of(id("Doggen"), id("Doggen")), Ok(false));
+            assert_eq!(tax.is_descendant_of(id("Affen"), id("Haustiere")), Ok(false));
+
+            let unknown = Rc::new(Uuid::new_v4());
+            assert_eq!(
+                tax.is_descendant_of(unknown.clone(), id("Tiere")),
+                Err(NodeNotFound(unknown.clone()))
+            );
+            assert_eq!(tax.is_descendant_of(id("Tiere"), unknown.clone()), Err(NodeNotFound(unknown)));
+
+            // Same results with the reachability index
+            tax.enable_reachability_index();
+        }
+    }
+
+    #[test]
+    fn is_ancestor_of() {
+        let (mut tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+
+        assert_eq!(tax.is_ancestor_of(id("Säugetiere"), id("Schäferhunde")), Ok(true));
+        assert_eq!(tax.is_ancestor_of(id("Schäferhunde"), id("Säugetiere")), Ok(false));
+
+        tax.enable_reachability_index();
+        assert_eq!(tax.is_ancestor_of(id("Säugetiere"), id("Schäferhunde")), Ok(true));
+        assert_eq!(tax.is_ancestor_of(id("Vögel"), id("Schäferhunde")), Ok(false));
+    }
+
+    #[test]
+    fn is_a() {
+        let (tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+
+        assert_eq!(tax.is_a(id("Hunde"), id("Hunde")), Ok(true));
+        assert_eq!(tax.is_a(id("Hunde"), id("Säugetiere")), Ok(true));
+        assert_eq!(tax.is_a(id("Hunde"), id("Vögel")), Ok(false));
 
-        // Remove a non-existing edge of two existing nodes which don't share a super-sub relationship
-        let id_hunde = Rc::new(ids.get("Hunde").unwrap().clone());
-        let id_katzen = Rc::new(ids.get("Katzen").unwrap().clone());
-        let result = tax.remove_from(Edge::new(Some(id_hunde.clone()), id_katzen.clone())).err();
-        let expectation = EdgeNotFound(Some(id_hunde), id_katzen);
-        assert_eq!(result, Some(expectation));
+        let unknown = Rc::new(Uuid::new_v4());
+        assert_eq!(tax.is_a(unknown.clone(), unknown.clone()), Err(NodeNotFound(unknown)));
+    }
 
-        // Remove an existing edge of a root-node with no other super-nodes and no sub-nodes.
+    #[test]
+    fn lowest_common_ancestors() {
         let (mut tax, ids, _) = setup_tax_animals();
-        let id_tierhalter = Rc::new(ids.get("Tierhalter").unwrap().clone());
-        assert!(tax.remove_from(Edge::new(None, id_tierhalter.clone())).is_ok());
-        assert_eq!(tax.node0.contains(&id_tierhalter), false);
-        assert_eq!(tax.nodes.contains_key(&id_tierhalter), false);
-        assert_eq!(tax.last_updated_node().unwrap(), id_tierhalter);
+        let id = |name: &str| Rc::new(ids[name]);
+
+        for _ in 0..2 {
+            // Hunde and Katzen share three super-nodes
+            let lca = tax.lowest_common_ancestors(&[id("Hunde"), id("Katzen")]).unwrap();
+            assert_eq!(lca, HashSet::from([id("Haustiere"), id("Säugetiere"), id("Tierheime")]));
+
+            // Doggen and Affen share Säugetiere only
+            let lca = tax.lowest_common_ancestors(&[id("Doggen"), id("Affen")]).unwrap();
+            assert_eq!(lca, HashSet::from([id("Säugetiere")]));
+
+            // Three nodes
+            let lca = tax
+                .lowest_common_ancestors(&[id("Doggen"), id("Affen"), id("Puten")])
+                .unwrap();
+            assert_eq!(lca, HashSet::from([id("Tiere")]));
+
+            // A node is its own ancestor
+            let lca = tax.lowest_common_ancestors(&[id("Hunde"), id("Doggen")]).unwrap();
+            assert_eq!(lca, HashSet::from([id("Hunde")]));
+            let lca = tax.lowest_common_ancestors(&[id("Hunde")]).unwrap();
+            assert_eq!(lca, HashSet::from([id("Hunde")]));
+
+            // Different root-nodes
+            assert!(tax
+                .lowest_common_ancestors(&[id("Tierhalter"), id("Tiere")])
+                .unwrap()
+                .is_empty());
+            assert!(tax.lowest_common_ancestors(&[]).unwrap().is_empty());
+
+            let unknown = Rc::new(Uuid::new_v4());
+            let result = tax.lowest_common_ancestors(&[id("Tiere"), unknown.clone()]);
+            assert_eq!(result, Err(NodeNotFound(unknown)));
+
+            // Same results with the reachability index
+            tax.enable_reachability_index();
+        }
+    }
 
-        // Remove an existing edge of a root-node with no other super-nodes but with sub-nodes which have other super-nodes as well
-        let id_tierheime = Rc::new(ids.get("Tierheime").unwrap().clone());
-        let id_katzen = Rc::new(ids.get("Katzen").unwrap().clone());
-        let id_hunde = Rc::new(ids.get("Hunde").unwrap().clone());
-        assert!(tax.remove_from(Edge::new(None, id_tierheime.clone())).is_ok());
-        assert_eq!(tax.node0.contains(&id_tierheime), false);
-        assert_eq!(tax.nodes.contains_key(&id_tierheime), false);
-        assert_eq!(tax.nodes.contains_key(&id_hunde), true);
-        assert_eq!(tax.nodes.contains_key(&id_katzen), true);
-        assert_eq!(tax.last_updated_node().unwrap(), id_tierheime);
+    #[test]
+    fn paths_to() {
+        let (tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
 
-        // Remove an existing edge of a non-root-node with no other super-nodes but with sub-nodes which have and haven't other super-nodes as well
-        let id_tiere = Rc::new(ids.get("Tiere").unwrap().clone());
-        let id_saeugetiere = Rc::new(ids.get("Säugetiere").unwrap().clone());
-        let id_affen = Rc::new(ids.get("Affen").unwrap().clone());
-        let id_hunde = Rc::new(ids.get("Hunde").unwrap().clone());
-        let id_katzen = Rc::new(ids.get("Katzen").unwrap().clone());
-        let id_waale = Rc::new(ids.get("Waale & Delfine").unwrap().clone());
-        assert!(tax
-            .remove_from(Edge::new(Some(id_tiere.clone()), id_saeugetiere.clone()))
-            .is_ok());
-        assert_eq!(tax.node0.contains(&id_saeugetiere), false);
-        assert_eq!(tax.nodes.contains_key(&id_saeugetiere), false);
-        assert_eq!(tax.nodes.contains_key(&id_affen), true);
-        assert_eq!(tax.nodes.contains_key(&id_hunde), true);
-        assert_eq!(tax.nodes.contains_key(&id_katzen), true);
-        assert_eq!(tax.nodes.contains_key(&id_waale), false);
-        assert_eq!(tax.last_updated_node().unwrap(), id_saeugetiere);
+        assert_eq!(tax.paths_to(id("Tiere")).unwrap(), vec![vec![id("Tiere")]]);
+
+        let paths = tax.paths_to(id("Doggen")).unwrap();
+        assert_eq!(
+            paths,
+            vec![
+                vec![id("Tiere"), id("Haustiere"), id("Hunde"), id("Doggen")],
+                vec![id("Tiere"), id("Säugetiere"), id("Hunde"), id("Doggen")],
+                vec![id("Tierheime"), id("Hunde"), id("Doggen")],
+            ]
+        );
+
+        // Every path is also visited by a pre-order traversal
+        let visited = tax.visits().map(|visit| visit.path().to_vec()).collect::<HashSet<_>>();
+        assert!(paths.iter().all(|path| visited.contains(path)));
+
+        let unknown = Rc::new(Uuid::new_v4());
+        assert_eq!(tax.paths_to(unknown.clone()), Err(NodeNotFound(unknown)));
     }
 
     #[test]
-    fn remove_recursively() {
+    fn canonical_path() {
         let (mut tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
 
-        // Remove a non-existing node.
-        let node_id = Rc::new(Uuid::new_v4());
-        let result = tax.remove_recursively(node_id.clone()).err();
-        let expectation = NodeNotFound(node_id);
-        assert_eq!(result, Some(expectation));
-
-        // Remove a node with multiple super- and sub-nodes
-        let id_hunde = Rc::new(ids.get("Hunde").unwrap().clone());
-        let id_doggen = Rc::new(ids.get("Doggen").unwrap().clone());
-        let id_schaeferhunde = Rc::new(ids.get("Schäferhunde").unwrap().clone());
+        assert_eq!(tax.canonical_path(id("Tiere")).unwrap(), vec![id("Tiere")]);
+        assert_eq!(
+            tax.canonical_path(id("Doggen")).unwrap(),
+            vec![id("Tiere"), id("Haustiere"), id("Hunde"), id("Doggen")]
+        );
 
-        // Get a list of super-nodes of the to be removed node
-        let supers = tax._get_node_opt(id_hunde.clone()).unwrap().supers();
+        tax.set_primary_super(id("Hunde"), Some(id("Tierheime"))).unwrap();
+        assert_eq!(
+            tax.canonical_path(id("Doggen")).unwrap(),
+            vec![id("Tierheime"), id("Hunde"), id("Doggen")]
+        );
 
-        assert!(tax.remove_recursively(id_hunde.clone()).is_ok());
-        for super_node in supers {
-            assert_eq!(tax._get_node_opt(super_node).unwrap().subs().contains(&id_hunde), false);
+        // The canonical path is one of all paths
+        for name in ids.keys() {
+            let path = tax.canonical_path(id(name)).unwrap();
+            assert!(tax.paths_to(id(name)).unwrap().contains(&path));
         }
-        assert!(tax._get_node_opt(id_hunde.clone()).is_none());
-        assert!(tax._get_node_opt(id_doggen.clone()).is_none());
-        assert!(tax._get_node_opt(id_schaeferhunde.clone()).is_none());
-        assert_eq!(tax.last_updated_node().unwrap(), id_hunde);
+
+        let unknown = Rc::new(Uuid::new_v4());
+        assert_eq!(tax.canonical_path(unknown.clone()), Err(NodeNotFound(unknown)));
     }
 
     #[test]
-    fn traverse() {
-        let (mut tax, _, list) = setup_tax_animals();
+    fn enable_reachability_index() {
+        let (mut tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+
+        tax.enable_reachability_index();
+        assert!(tax.has_reachability_index());
+        assert_reachability_index_consistent(&tax);
+
+        // add
+        let c_dackel = Concept::new("Dackel");
+        let id_dackel = Rc::new(c_dackel.id());
+        let _ = tax.add(Some(ids["Hunde"]), c_dackel);
+        assert_eq!(tax.is_descendant_of(id_dackel.clone(), id("Tierheime")), Ok(true));
+        assert_reachability_index_consistent(&tax);
+
+        // append, a whole sub-tree gains ancestors
+        let _ = tax.append(Some(ids["Tierschutz"]), ids["Hunde"]);
+        assert_eq!(tax.is_descendant_of(id_dackel.clone(), id("Tierschutz")), Ok(true));
+        assert_reachability_index_consistent(&tax);
+
+        // append_at
+        let _ = tax.append_at(Some(ids["Tierhalter"]), ids["Vögel"], 0);
+        assert_eq!(tax.is_descendant_of(id("Puten"), id("Tierhalter")), Ok(true));
+        assert_reachability_index_consistent(&tax);
+
+        // move_to
+        let _ = tax.move_to(id("Hunde"), Some(id("Tierschutz")), None, 0);
+        assert_eq!(tax.is_descendant_of(id_dackel.clone(), id("Tierschutz")), Ok(false));
+        assert_reachability_index_consistent(&tax);
+
+        // remove_from
+        let _ = tax.remove_from(Edge::new(Some(id("Tierhalter")), id("Vögel")));
+        assert_eq!(tax.is_descendant_of(id("Puten"), id("Tierhalter")), Ok(false));
+        assert_reachability_index_consistent(&tax);
+
+        // remove
+        let _ = tax.remove(id_dackel);
+        assert_reachability_index_consistent(&tax);
+
+        // remove_recursively
+        let _ = tax.remove_recursively(id("Nutztiere"));
+        assert_reachability_index_consistent(&tax);
+    }
 
-        let mut i = 0;
-        while let Some(concept) = tax.traverse() {
-            let c = list.iter().nth(i).unwrap().1.clone();
-            assert_eq!(*concept, c);
-            i += 1;
-        }
+    #[test]
+    fn disable_reachability_index() {
+        let (mut tax, _, _) = setup_tax_animals();
+        assert!(!tax.has_reachability_index());
+
+        tax.enable_reachability_index().disable_reachability_index();
+        assert!(!tax.has_reachability_index());
+        assert!(tax.reachability.is_none());
     }
 
     #[test]
-    fn traverse_mut() {
-        let (mut tax, _, list) = setup_tax_animals();
+    fn iter_mut() {
+        let (mut tax, _, _) = setup_tax_animals();
+        let expectation = tax._enumerate_nodes();
 
-        let mut i = 0;
-        while let Some(concept) = tax.traverse_mut() {
-            let c = list.iter().nth(i).unwrap().1.clone();
-            assert_eq!(*concept, c);
-            i += 1;
-        }
+        let mut iter = tax.iter_mut();
+        assert_eq!(iter.len(), expectation.len());
+
+        let ids = iter.by_ref().map(|element| Rc::new(element.id())).collect::<Vec<Rc<Uuid>>>();
+        assert_eq!(ids, expectation);
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn into_iter() {
+        let (tax, _, _) = setup_tax_animals();
+        let expectation = tax._enumerate_nodes();
+
+        let ids = tax
+            .into_iter()
+            .map(|element| Rc::new(element.id()))
+            .collect::<Vec<Rc<Uuid>>>();
+        assert_eq!(ids, expectation);
     }
 
     //
     // Testing Taxonomy's private functions
     //
 
+    #[test]
+    fn _ancestor_ids() {
+        let (mut tax, ids, _) = setup_tax_animals();
+
+        let ancestors = tax._ancestor_ids(Rc::new(ids["Doggen"]));
+        assert_eq!(ancestors.len(), 5);
+
+        tax.enable_reachability_index();
+        assert_eq!(tax._ancestor_ids(Rc::new(ids["Doggen"])), ancestors);
+        assert!(tax._ancestor_ids(Rc::new(Uuid::new_v4())).is_empty());
+    }
+
     #[test]
     fn _add_non_root_node() {
         let (mut tax, _, list) = setup_tax_animals();
@@ -495,7 +3703,7 @@ mod tests {
         let super_id = Rc::new(list.iter().nth(1).unwrap().0);
         let counter_subs_pre = tax._get_node_opt(super_id.clone()).unwrap().count_subs();
 
-        tax._add_non_root_node(super_id.clone(), Node::new(c_nagetiere));
+        tax._add_non_root_node(super_id.clone(), Node::new(c_nagetiere), ());
 
         let counter_subs_post = tax._get_node_opt(super_id.clone()).unwrap().count_subs();
 
@@ -519,10 +3727,10 @@ mod tests {
         let c_plant = Concept::new("Plant");
         let id_plant = c_plant.id();
 
-        tax._add_root_node(Node::new(c_animal));
+        tax._add_root_node(Node::new(c_animal), ());
         assert_eq!(tax.last_updated_node().unwrap(), Rc::new(id_animal));
 
-        tax._add_root_node(Node::new(c_plant));
+        tax._add_root_node(Node::new(c_plant), ());
         assert_eq!(tax.last_updated_node().unwrap(), Rc::new(id_plant));
 
         assert_eq!(tax.nodes.len(), counter);
@@ -530,7 +3738,7 @@ mod tests {
         assert_eq!(tax.nodes.get(&id_plant).unwrap().id(), Rc::new(id_plant));
 
         assert_eq!(tax.node0.len(), counter);
-        assert_eq!(tax.node0.iter().nth(0).unwrap().clone(), Rc::new(id_animal));
+        assert_eq!(tax.node0.iter().next().unwrap().clone(), Rc::new(id_animal));
         assert_eq!(tax.node0.iter().nth(1).unwrap().clone(), Rc::new(id_plant));
     }
 
@@ -543,7 +3751,7 @@ mod tests {
         let c_zoos = Concept::new("Zoos");
         let id_zoos = c_zoos.id();
 
-        tax._add_root_node(Node::new(c_zoos));
+        tax._add_root_node(Node::new(c_zoos), ());
 
         assert_eq!(tax.nodes.len(), counter_nodes);
         assert_eq!(tax.nodes.get(&id_zoos).unwrap().id(), Rc::new(id_zoos));
@@ -559,117 +3767,163 @@ mod tests {
         let super_id = Rc::new(list.front().unwrap().0);
         let node_id = Rc::new(list.back().unwrap().0);
         let index = 0;
-        assert_eq!(
-            tax._get_node_opt(node_id.clone()).unwrap().supers().contains(&super_id),
-            false
-        );
-        tax._append_at(super_id.clone(), node_id.clone(), index);
+        assert!(!tax._get_node_opt(node_id.clone()).unwrap().supers().contains(&super_id));
+        tax._append_at(super_id.clone(), node_id.clone(), index, ());
         assert_eq!(*tax._get_node_opt(super_id.clone()).unwrap().subs().front().unwrap(), node_id);
-        assert_eq!(tax._get_node_opt(node_id).unwrap().supers().contains(&super_id), true);
+        assert!(tax._get_node_opt(node_id).unwrap().supers().contains(&super_id));
 
         // Append a node in the middle of sub-nodes to super-node.
         let (mut tax, _, list) = setup_tax_animals();
         let super_id = Rc::new(list.front().unwrap().0);
         let node_id = Rc::new(list.back().unwrap().0);
         let index = tax._get_node_opt(super_id.clone()).unwrap().subs().len() / 2;
-        assert_eq!(
-            tax._get_node_opt(node_id.clone()).unwrap().supers().contains(&super_id),
-            false
-        );
-        tax._append_at(super_id.clone(), node_id.clone(), index);
+        assert!(!tax._get_node_opt(node_id.clone()).unwrap().supers().contains(&super_id));
+        tax._append_at(super_id.clone(), node_id.clone(), index, ());
         assert_eq!(
             *tax._get_node_opt(super_id.clone()).unwrap().subs().iter().nth(index).unwrap(),
             node_id
         );
-        assert_eq!(tax._get_node_opt(node_id).unwrap().supers().contains(&super_id), true);
+        assert!(tax._get_node_opt(node_id).unwrap().supers().contains(&super_id));
 
         // Append a node as last sub-node to super-node.
         let (mut tax, _, list) = setup_tax_animals();
         let super_id = Rc::new(list.front().unwrap().0);
         let node_id = Rc::new(list.back().unwrap().0);
         let index = tax._get_node_opt(super_id.clone()).unwrap().subs().len();
-        assert_eq!(
-            tax._get_node_opt(node_id.clone()).unwrap().supers().contains(&super_id),
-            false
-        );
-        tax._append_at(super_id.clone(), node_id.clone(), index);
+        assert!(!tax._get_node_opt(node_id.clone()).unwrap().supers().contains(&super_id));
+        tax._append_at(super_id.clone(), node_id.clone(), index, ());
         assert_eq!(*tax._get_node_opt(super_id.clone()).unwrap().subs().back().unwrap(), node_id);
-        assert_eq!(tax._get_node_opt(node_id).unwrap().supers().contains(&super_id), true);
+        assert!(tax._get_node_opt(node_id).unwrap().supers().contains(&super_id));
 
         // Append a node as last sub-node to super-node (index out of bounds)
         let (mut tax, _, list) = setup_tax_animals();
         let super_id = Rc::new(list.front().unwrap().0);
         let node_id = Rc::new(list.back().unwrap().0);
         let index = 1000;
-        assert_eq!(
-            tax._get_node_opt(node_id.clone()).unwrap().supers().contains(&super_id),
-            false
-        );
-        tax._append_at(super_id.clone(), node_id.clone(), index);
+        assert!(!tax._get_node_opt(node_id.clone()).unwrap().supers().contains(&super_id));
+        tax._append_at(super_id.clone(), node_id.clone(), index, ());
         assert_eq!(*tax._get_node_opt(super_id.clone()).unwrap().subs().back().unwrap(), node_id);
-        assert_eq!(tax._get_node_opt(node_id).unwrap().supers().contains(&super_id), true);
+        assert!(tax._get_node_opt(node_id).unwrap().supers().contains(&super_id));
     }
 
     #[test]
     fn _append_root_at() {
         // Append a non-root node as 1st root-node to taxonomy.
         let (mut tax, ids, _) = setup_tax_animals();
-        let node_id = Rc::new(ids.get("Haustiere").unwrap().clone());
+        let node_id = Rc::new(*ids.get("Haustiere").unwrap());
         let index = 0;
-        assert_eq!(tax._get_node_opt(node_id.clone()).unwrap().is_root(), false);
-        tax._append_root_at(node_id.clone(), index);
+        assert!(!tax._get_node_opt(node_id.clone()).unwrap().is_root());
+        tax._append_root_at(node_id.clone(), index, ());
         assert_eq!(*tax.node0.front().unwrap(), node_id);
-        assert_eq!(tax._get_node_opt(node_id).unwrap().is_root(), true);
+        assert!(tax._get_node_opt(node_id).unwrap().is_root());
 
         // Append a non-root node in the middle of existing root-nodes.
         let (mut tax, ids, _) = setup_tax_animals();
-        let node_id = Rc::new(ids.get("Haustiere").unwrap().clone());
+        let node_id = Rc::new(*ids.get("Haustiere").unwrap());
         let index = tax.node0.len() / 2;
-        assert_eq!(tax._get_node_opt(node_id.clone()).unwrap().is_root(), false);
-        tax._append_root_at(node_id.clone(), index);
+        assert!(!tax._get_node_opt(node_id.clone()).unwrap().is_root());
+        tax._append_root_at(node_id.clone(), index, ());
         assert_eq!(*tax.node0.iter().nth(index).unwrap(), node_id);
-        assert_eq!(tax._get_node_opt(node_id).unwrap().is_root(), true);
+        assert!(tax._get_node_opt(node_id).unwrap().is_root());
 
         // Append a non-root node as last root-node to taxonomy.
         let (mut tax, ids, _) = setup_tax_animals();
-        let node_id = Rc::new(ids.get("Haustiere").unwrap().clone());
+        let node_id = Rc::new(*ids.get("Haustiere").unwrap());
         let index = tax.node0.len();
-        assert_eq!(tax._get_node_opt(node_id.clone()).unwrap().is_root(), false);
-        tax._append_root_at(node_id.clone(), index);
+        assert!(!tax._get_node_opt(node_id.clone()).unwrap().is_root());
+        tax._append_root_at(node_id.clone(), index, ());
         assert_eq!(*tax.node0.back().unwrap(), node_id);
-        assert_eq!(tax._get_node_opt(node_id).unwrap().is_root(), true);
+        assert!(tax._get_node_opt(node_id).unwrap().is_root());
 
         // Append a non-root node as last root-node to taxonomy (index out of bounds).
         let (mut tax, ids, _) = setup_tax_animals();
-        let node_id = Rc::new(ids.get("Haustiere").unwrap().clone());
+        let node_id = Rc::new(*ids.get("Haustiere").unwrap());
         let index = 1000;
-        assert_eq!(tax._get_node_opt(node_id.clone()).unwrap().is_root(), false);
-        tax._append_root_at(node_id.clone(), index);
+        assert!(!tax._get_node_opt(node_id.clone()).unwrap().is_root());
+        tax._append_root_at(node_id.clone(), index, ());
         assert_eq!(*tax.node0.back().unwrap(), node_id);
-        assert_eq!(tax._get_node_opt(node_id).unwrap().is_root(), true);
+        assert!(tax._get_node_opt(node_id).unwrap().is_root());
     }
 
     #[test]
     fn _enumerate_subs() {
         let (tax, ids, _) = setup_tax_animals();
 
-        assert_eq!(tax._enumerate_subs(Rc::new(ids.get("Tiere").unwrap().clone())).len(), 17);
-        assert_eq!(tax._enumerate_subs(Rc::new(ids.get("Tierheime").unwrap().clone())).len(), 4);
-        assert_eq!(tax._enumerate_subs(Rc::new(ids.get("Haustiere").unwrap().clone())).len(), 5);
-        assert_eq!(tax._enumerate_subs(Rc::new(ids.get("Nutztiere").unwrap().clone())).len(), 4);
-        assert_eq!(tax._enumerate_subs(Rc::new(ids.get("Zootiere").unwrap().clone())).len(), 2);
-        assert_eq!(tax._enumerate_subs(Rc::new(ids.get("Säugetiere").unwrap().clone())).len(), 6);
-        assert_eq!(tax._enumerate_subs(Rc::new(ids.get("Vögel").unwrap().clone())).len(), 2);
-        assert_eq!(tax._enumerate_subs(Rc::new(ids.get("Geflügel").unwrap().clone())).len(), 2);
-        assert_eq!(tax._enumerate_subs(Rc::new(ids.get("Rind").unwrap().clone())).len(), 0);
-        assert_eq!(tax._enumerate_subs(Rc::new(ids.get("Fische").unwrap().clone())).len(), 0);
-        assert_eq!(tax._enumerate_subs(Rc::new(ids.get("Hunde").unwrap().clone())).len(), 2);
-        assert_eq!(tax._enumerate_subs(Rc::new(ids.get("Katzen").unwrap().clone())).len(), 0);
+        assert_eq!(tax._enumerate_subs(Rc::new(*ids.get("Tiere").unwrap())).len(), 17);
+        assert_eq!(tax._enumerate_subs(Rc::new(*ids.get("Tierheime").unwrap())).len(), 4);
+        assert_eq!(tax._enumerate_subs(Rc::new(*ids.get("Haustiere").unwrap())).len(), 5);
+        assert_eq!(tax._enumerate_subs(Rc::new(*ids.get("Nutztiere").unwrap())).len(), 4);
+        assert_eq!(tax._enumerate_subs(Rc::new(*ids.get("Zootiere").unwrap())).len(), 2);
+        assert_eq!(tax._enumerate_subs(Rc::new(*ids.get("Säugetiere").unwrap())).len(), 6);
+        assert_eq!(tax._enumerate_subs(Rc::new(*ids.get("Vögel").unwrap())).len(), 2);
+        assert_eq!(tax._enumerate_subs(Rc::new(*ids.get("Geflügel").unwrap())).len(), 2);
+        assert_eq!(tax._enumerate_subs(Rc::new(*ids.get("Rind").unwrap())).len(), 0);
+        assert_eq!(tax._enumerate_subs(Rc::new(*ids.get("Fische").unwrap())).len(), 0);
+        assert_eq!(tax._enumerate_subs(Rc::new(*ids.get("Hunde").unwrap())).len(), 2);
+        assert_eq!(tax._enumerate_subs(Rc::new(*ids.get("Katzen").unwrap())).len(), 0);
 
         // Unknown Id
         assert_eq!(tax._enumerate_subs(Rc::new(Uuid::new_v4())).len(), 0);
     }
 
+    #[test]
+    fn _collect_ancestors() {
+        let (tax, ids, _) = setup_tax_animals();
+        let mut reachability = HashMap::new();
+
+        let ancestors = tax._collect_ancestors(Rc::new(ids["Doggen"]), &mut reachability);
+        assert_eq!(ancestors.len(), 5);
+
+        // Ancestors of ancestors are cached
+        assert_eq!(reachability.len(), 6);
+        assert!(reachability[&Rc::new(ids["Tiere"])].is_empty());
+        assert_eq!(reachability[&Rc::new(ids["Hunde"])].len(), 4);
+    }
+
+    #[test]
+    fn _descend_to_first_leaf() {
+        let (tax, _, list) = setup_tax_animals();
+
+        // Tiere > Haustiere > Fische
+        let mut cursor = vec![Cursor::new(None, 0)];
+        tax._descend_to_first_leaf(&mut cursor);
+        assert_eq!(cursor.len(), 3);
+        assert_eq!(
+            tax._get_node_id_at(cursor.last().unwrap()).1,
+            Some(Rc::new(list.iter().nth(2).unwrap().0))
+        );
+
+        // Tierhalter is a leaf already
+        let mut cursor = vec![Cursor::new(None, 1)];
+        tax._descend_to_first_leaf(&mut cursor);
+        assert_eq!(cursor.len(), 1);
+
+        let mut cursor = Vec::new();
+        tax._descend_to_first_leaf(&mut cursor);
+        assert!(cursor.is_empty());
+    }
+
+    #[test]
+    fn _enumerate_nodes() {
+        let (tax, _, list) = setup_tax_animals();
+
+        let node_ids = tax._enumerate_nodes();
+        assert_eq!(node_ids.len(), tax.nodes.len());
+
+        // Each node appears in order of its first occurrence
+        let mut expectation: Vec<Rc<Uuid>> = Vec::new();
+        for (id, _) in list.iter() {
+            let id = Rc::new(*id);
+            if !expectation.contains(&id) {
+                expectation.push(id);
+            }
+        }
+        assert_eq!(node_ids, expectation);
+
+        let tax = setup_tax_empty();
+        assert!(tax._enumerate_nodes().is_empty());
+    }
+
     #[test]
     fn _err_duplicate_node() {
         let (tax, _, list) = setup_tax_animals();
@@ -711,14 +3965,14 @@ mod tests {
         let (tax, _, list) = setup_tax_animals();
 
         // Error with existing super- / sub-node
-        let super_id = Rc::new(list.iter().nth(0).unwrap().0);
+        let super_id = Rc::new(list.iter().next().unwrap().0);
         let node_id = Rc::new(list.iter().nth(1).unwrap().0);
         let result = tax._err_duplicate_sub_node(super_id.clone(), node_id.clone()).err();
         let expectation = DuplicateSubNode(super_id, node_id);
         assert_eq!(result, Some(expectation));
 
         // Ok with existing super-node and an indirect sub-node
-        let super_id = Rc::new(list.iter().nth(0).unwrap().0);
+        let super_id = Rc::new(list.iter().next().unwrap().0);
         let node_id = Rc::new(list.iter().nth(4).unwrap().0);
         assert!(tax._err_duplicate_sub_node(super_id.clone(), node_id.clone()).is_ok());
     }
@@ -736,7 +3990,7 @@ mod tests {
         assert_eq!(result, Some(expectation));
 
         // Edge with existing super-node and sub-node
-        let super_id = Rc::new(list.iter().nth(0).unwrap().0);
+        let super_id = Rc::new(list.iter().next().unwrap().0);
         let node_id = Rc::new(list.iter().nth(1).unwrap().0);
         let edge = Edge::new(Some(super_id.clone()), node_id.clone());
         let result = tax._err_duplicate_edge(&edge).err();
@@ -761,13 +4015,13 @@ mod tests {
         assert!(tax._err_edge_not_found(&edge).is_ok());
 
         // Edge with existing super-node and sub-node
-        let super_id = Rc::new(list.iter().nth(0).unwrap().0);
+        let super_id = Rc::new(list.iter().next().unwrap().0);
         let node_id = Rc::new(list.iter().nth(1).unwrap().0);
         let edge = Edge::new(Some(super_id), node_id);
         assert!(tax._err_edge_not_found(&edge).is_ok());
 
         // Edge with existing super-node and an indirect sub-node
-        let super_id = Rc::new(list.iter().nth(0).unwrap().0);
+        let super_id = Rc::new(list.iter().next().unwrap().0);
         let node_id = Rc::new(list.iter().nth(4).unwrap().0);
         let edge = Edge::new(Some(super_id.clone()), node_id.clone());
         let result = tax._err_edge_not_found(&edge).err();
@@ -775,7 +4029,7 @@ mod tests {
         assert_eq!(result, Some(expectation));
 
         // Edge with existing super-node and a coordinate node
-        let super_id = Rc::new(list.iter().nth(0).unwrap().0);
+        let super_id = Rc::new(list.iter().next().unwrap().0);
         let node_id = Rc::new(list.back().unwrap().0);
         let edge = Edge::new(Some(super_id.clone()), node_id.clone());
         let result = tax._err_edge_not_found(&edge).err();
@@ -796,7 +4050,7 @@ mod tests {
         let (tax, ids, _) = setup_tax_animals();
 
         // Append the same element to itself
-        let id = Rc::new(ids.get("Schäferhunde").unwrap().clone());
+        let id = Rc::new(*ids.get("Schäferhunde").unwrap());
         let expectation = LoopDetected(id.clone());
         assert_eq!(
             tax._err_loop_detected(id.clone(), id.clone(), None).err().unwrap(),
@@ -804,8 +4058,8 @@ mod tests {
         );
 
         // Append a node's direct super-node to itself as sub-node
-        let id_hunde = Rc::new(ids.get("Hunde").unwrap().clone());
-        let id_saeugetiere = Rc::new(ids.get("Säugetiere").unwrap().clone());
+        let id_hunde = Rc::new(*ids.get("Hunde").unwrap());
+        let id_saeugetiere = Rc::new(*ids.get("Säugetiere").unwrap());
         let expectation = LoopDetected(id_saeugetiere.clone());
         assert_eq!(
             tax._err_loop_detected(id_hunde.clone(), id_saeugetiere.clone(), None)
@@ -815,19 +4069,19 @@ mod tests {
         );
 
         // Append a node's indirect super-node to itself as sub-node
-        let id_hunde = Rc::new(ids.get("Hunde").unwrap().clone());
-        let id_tiere = Rc::new(ids.get("Tiere").unwrap().clone());
+        let id_hunde = Rc::new(*ids.get("Hunde").unwrap());
+        let id_tiere = Rc::new(*ids.get("Tiere").unwrap());
         let expectation = LoopDetected(id_tiere.clone());
         assert_eq!(tax._err_loop_detected(id_hunde, id_tiere, None).err().unwrap(), expectation);
 
         // Append a node's coordinate node to itself as sub-node
-        let id_haustiere = Rc::new(ids.get("Haustiere").unwrap().clone());
-        let id_saeugetiere = Rc::new(ids.get("Säugetiere").unwrap().clone());
+        let id_haustiere = Rc::new(*ids.get("Haustiere").unwrap());
+        let id_saeugetiere = Rc::new(*ids.get("Säugetiere").unwrap());
         assert!(tax._err_loop_detected(id_haustiere, id_saeugetiere, None).is_ok());
 
         // Append a node to another super-node
-        let id_zootiere = Rc::new(ids.get("Zootiere").unwrap().clone());
-        let id_hunde = Rc::new(ids.get("Hunde").unwrap().clone());
+        let id_zootiere = Rc::new(*ids.get("Zootiere").unwrap());
+        let id_hunde = Rc::new(*ids.get("Hunde").unwrap());
         assert!(tax._err_loop_detected(id_zootiere, id_hunde, None).is_ok());
     }
 
@@ -870,12 +4124,149 @@ mod tests {
         tax._next();
         let (super_id, node_id) = tax._get_node_id_from_cursor();
         assert_eq!(super_id, None);
-        assert_eq!(node_id, Some(Rc::new(list.iter().nth(0).unwrap().0)));
+        assert_eq!(node_id, Some(Rc::new(list.iter().next().unwrap().0)));
 
         tax._next();
         let (super_id, node_id) = tax._get_node_id_from_cursor();
-        assert_eq!(super_id, Some(Rc::new(list.iter().nth(0).unwrap().0)));
+        assert_eq!(super_id, Some(Rc::new(list.iter().next().unwrap().0)));
+        assert_eq!(node_id, Some(Rc::new(list.iter().nth(1).unwrap().0)));
+    }
+
+    #[test]
+    fn _get_node_id_at() {
+        let (tax, _, list) = setup_tax_animals();
+        let id_tiere = Rc::new(list.front().unwrap().0);
+
+        let (super_id, node_id) = tax._get_node_id_at(&Cursor::new(None, 0));
+        assert_eq!(super_id, None);
+        assert_eq!(node_id, Some(id_tiere.clone()));
+
+        let (super_id, node_id) = tax._get_node_id_at(&Cursor::new(Some(id_tiere.clone()), 0));
+        assert_eq!(super_id, Some(id_tiere.clone()));
         assert_eq!(node_id, Some(Rc::new(list.iter().nth(1).unwrap().0)));
+
+        let (super_id, node_id) = tax._get_node_id_at(&Cursor::new(Some(id_tiere.clone()), 99));
+        assert_eq!(super_id, Some(id_tiere));
+        assert_eq!(node_id, None);
+    }
+
+    #[test]
+    fn _reindex() {
+        let (mut tax, ids, _) = setup_tax_animals();
+
+        // Without index, nothing happens
+        tax._reindex(Rc::new(ids["Hunde"]));
+        assert!(tax.reachability.is_none());
+
+        tax.enable_reachability_index();
+        tax.reachability.as_mut().unwrap().clear();
+        tax._reindex(Rc::new(ids["Hunde"]));
+        assert_eq!(tax.reachability.as_ref().unwrap()[&Rc::new(ids["Doggen"])].len(), 5);
+    }
+
+    #[test]
+    fn _reindex_nodes() {
+        let (mut tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+        tax.enable_reachability_index();
+        let before = tax.reachability.clone();
+
+        // Stale entries of all nodes are dropped before any is collected again
+        let unknown = Rc::new(Uuid::new_v4());
+        let index = tax.reachability.as_mut().unwrap();
+        index.get_mut(&id("Haustiere")).unwrap().clear();
+        index.get_mut(&id("Hunde")).unwrap().clear();
+        index.insert(unknown.clone(), HashSet::new());
+        tax._reindex_nodes([id("Hunde"), id("Haustiere"), unknown]);
+        assert_eq!(tax.reachability, before);
+    }
+
+    #[test]
+    fn _relatives_of() {
+        let (tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+
+        let supers = tax._relatives_of(id("Affen"), Direction::Supers);
+        assert_eq!(supers, vec![id("Zootiere"), id("Säugetiere")]);
+
+        let subs = tax._relatives_of(id("Geflügel"), Direction::Subs);
+        assert_eq!(subs, vec![id("Hühne"), id("Puten")]);
+
+        assert!(tax._relatives_of(Rc::new(Uuid::new_v4()), Direction::Subs).is_empty());
+    }
+
+    #[test]
+    fn _sorted_index() {
+        let (mut tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+
+        // Not sorted: index is returned unchanged
+        assert_eq!(tax._sorted_index(Some(id("Tiere")), id("Fische"), 3), 3);
+        assert_eq!(tax._sorted_index(None, id("Fische"), 100), 100);
+
+        // Sorted: index is behind all nodes comparing less or equal
+        tax.enable_keep_sorted(|a: &Concept, b: &Concept| a.name().cmp(&b.name()));
+        assert_eq!(tax._sorted_index(Some(id("Tiere")), id("Fische"), 3), 0);
+        assert_eq!(tax._sorted_index(Some(id("Tiere")), id("Nutztiere"), 0), 2);
+        assert_eq!(tax._sorted_index(Some(id("Tiere")), id("Zootiere"), 0), 5);
+        assert_eq!(tax._sorted_index(None, id("Tierheime"), 0), 3);
+    }
+
+    #[test]
+    fn _sort_subs_by() {
+        let (mut tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+
+        // Stable sort
+        tax._sort_subs_by(Some(id("Tiere")), &mut |_: &Concept, _: &Concept| Ordering::Equal);
+        let expectation = vec![
+            id("Haustiere"),
+            id("Nutztiere"),
+            id("Zootiere"),
+            id("Säugetiere"),
+            id("Vögel"),
+        ];
+        assert_eq!(tax._sub_ids_of(Some(id("Tiere"))), expectation);
+
+        tax._sort_subs_by(None, &mut |a: &Concept, b: &Concept| b.name().cmp(&a.name()));
+        let expectation = vec![id("Tierschutz"), id("Tierheime"), id("Tierhalter"), id("Tiere")];
+        assert_eq!(tax._sub_ids_of(None), expectation);
+    }
+
+    #[test]
+    fn _sub_ids_of() {
+        let (tax, ids, _) = setup_tax_animals();
+
+        assert_eq!(tax._sub_ids_of(None).len(), 4);
+        assert_eq!(tax._sub_ids_of(Some(Rc::new(ids["Tiere"]))).len(), 5);
+        assert!(tax._sub_ids_of(Some(Rc::new(Uuid::new_v4()))).is_empty());
+    }
+
+    #[test]
+    fn _skip_subs_from() {
+        let (tax, ids, _) = setup_tax_animals();
+
+        // Tiere is skipped including its sub-nodes
+        let mut cursor = vec![Cursor::new(None, 0)];
+        tax._skip_subs_from(&mut cursor);
+        assert_eq!(
+            tax._get_node_id_at(cursor.last().unwrap()).1,
+            Some(Rc::new(ids["Tierhalter"]))
+        );
+
+        // Last sub-node of Tiere is skipped, cursor points to next root-node
+        let mut cursor = vec![Cursor::new(None, 0), Cursor::new(Some(Rc::new(ids["Tiere"])), 4)];
+        tax._skip_subs_from(&mut cursor);
+        assert_eq!(cursor.len(), 1);
+        assert_eq!(
+            tax._get_node_id_at(cursor.last().unwrap()).1,
+            Some(Rc::new(ids["Tierhalter"]))
+        );
+
+        // Last root-node is skipped
+        let mut cursor = vec![Cursor::new(None, 3)];
+        tax._skip_subs_from(&mut cursor);
+        assert!(cursor.is_empty());
     }
 
     #[test]
@@ -960,6 +4351,155 @@ mod tests {
         assert_eq!(list.len(), n);
     }
 
+    #[test]
+    fn _next_breadth_first_from() {
+        let (tax, _, _) = setup_tax_animals();
+
+        let mut queue = tax._root_node_ids().collect::<VecDeque<Rc<Uuid>>>();
+        assert_eq!(queue.len(), 4);
+
+        // Returning the first root-node queues its sub-nodes
+        let node_id = tax._next_breadth_first_from(&mut queue).unwrap();
+        assert_eq!(tax.get(node_id).unwrap().name(), "Tiere");
+        assert_eq!(queue.len(), 3 + 5);
+
+        let mut n = 1;
+        while tax._next_breadth_first_from(&mut queue).is_some() {
+            n += 1;
+        }
+        assert_eq!(n, 32);
+        assert!(tax._next_breadth_first_from(&mut queue).is_none());
+    }
+
+    #[test]
+    fn _next_level() {
+        let (tax, ids, _) = setup_tax_animals();
+
+        let level = vec![Rc::new(ids["Tierheime"]), Rc::new(ids["Tierschutz"])];
+        let next_level = tax._next_level(&level);
+        assert_eq!(next_level, vec![Rc::new(ids["Hunde"]), Rc::new(ids["Katzen"])]);
+
+        let next_level = tax._next_level(&next_level);
+        assert_eq!(next_level, vec![Rc::new(ids["Doggen"]), Rc::new(ids["Schäferhunde"])]);
+
+        assert!(tax._next_level(&next_level).is_empty());
+        assert!(tax._next_level(&[Rc::new(Uuid::new_v4())]).is_empty());
+    }
+
+    #[test]
+    fn _next_unique_from() {
+        let (tax, ids, _) = setup_tax_animals();
+
+        for occurrence in [Occurrence::First, Occurrence::Primary] {
+            let mut cursor = Vec::new();
+            let mut visited = HashSet::new();
+            let mut n = 0;
+            while let Some(node_id) = tax._next_unique_from(&mut cursor, &mut visited, occurrence) {
+                assert!(visited.contains(&node_id));
+                n += 1;
+            }
+            assert_eq!(n, tax.nodes.len());
+            assert_eq!(visited.len(), tax.nodes.len());
+            assert!(cursor.is_empty());
+        }
+
+        // Visited nodes are skipped including their sub-nodes
+        let mut cursor = Vec::new();
+        let mut visited = HashSet::new();
+        visited.insert(Rc::new(ids["Tiere"]));
+        let node_id = tax._next_unique_from(&mut cursor, &mut visited, Occurrence::First);
+        assert_eq!(node_id, Some(Rc::new(ids["Tierhalter"])));
+    }
+
+    #[test]
+    fn _next_post_order_from() {
+        let (tax, _, _) = setup_tax_animals();
+
+        let mut cursor = Vec::new();
+        let mut names = Vec::new();
+        while let Some(node_id) = tax._next_post_order_from(&mut cursor) {
+            names.push(tax.get(node_id).unwrap().name());
+        }
+        assert_eq!(
+            names,
+            [
+                "Fische",
+                "Doggen",
+                "Schäferhunde",
+                "Hunde",
+                "Katzen",
+                "Haustiere",
+                "Hühner",
+                "Puten",
+                "Geflügel",
+                "Rind",
+                "Nutztiere",
+                "Affen",
+                "Schlangen",
+                "Zootiere",
+                "Affen",
+                "Doggen",
+                "Schäferhunde",
+                "Hunde",
+                "Katzen",
+                "Waale & Delfine",
+                "Säugetiere",
+                "Hühner",
+                "Puten",
+                "Vögel",
+                "Tiere",
+                "Tierhalter",
+                "Doggen",
+                "Schäferhunde",
+                "Hunde",
+                "Katzen",
+                "Tierheime",
+                "Tierschutz"
+            ]
+        );
+        assert!(cursor.is_empty());
+
+        let tax = setup_tax_empty();
+        assert!(tax._next_post_order_from(&mut cursor).is_none());
+    }
+
+    #[test]
+    fn _next_from() {
+        let (mut tax, _, list) = setup_tax_animals();
+
+        // An external cursor does not touch taxonomy's own cursor
+        let mut cursor = Vec::new();
+        let mut n = 0;
+        while let Some(node_id) = tax._next_from(&mut cursor) {
+            assert_eq!(*node_id, list.iter().nth(n).unwrap().0);
+            n += 1;
+        }
+        assert_eq!(list.len(), n);
+        assert!(cursor.is_empty());
+        assert!(tax.cursor.is_empty());
+
+        tax._next();
+        let mut cursor = Vec::new();
+        tax._next_from(&mut cursor);
+        tax._next_from(&mut cursor);
+        assert_eq!(tax.cursor.len(), 1);
+        assert_eq!(cursor.len(), 2);
+    }
+
+    #[test]
+    fn _paths_to() {
+        let (tax, ids, _) = setup_tax_animals();
+
+        // Number of paths equals number of occurrences in a pre-order traversal
+        for id in ids.values() {
+            let occurrences = tax
+                .iter_in(TraversalOrder::PreOrder)
+                .filter(|element| element.id() == *id)
+                .count();
+            assert_eq!(tax._paths_to(Rc::new(*id)).len(), occurrences);
+        }
+    }
+
     #[test]
     fn _post_update() {
         let (mut tax, _, _) = setup_tax_animals();
@@ -986,9 +4526,9 @@ mod tests {
         let _ = tax.add(None, c_graeser);
         assert_eq!(tax.last_updated_node().unwrap(), Rc::new(id_graeser));
 
-        let _ = tax.append(Some(id_pflanzen.clone()), id_baeume);
+        let _ = tax.append(Some(id_pflanzen), id_baeume);
         assert_eq!(tax.last_updated_node().unwrap(), Rc::new(id_baeume));
-        let _ = tax.append_at(Some(id_pflanzen.clone()), id_blumen, 0);
+        let _ = tax.append_at(Some(id_pflanzen), id_blumen, 0);
         assert_eq!(tax.last_updated_node().unwrap(), Rc::new(id_blumen));
 
         let _ = tax.move_to(Rc::new(id_graeser), None, Some(Rc::new(id_pflanzen)), 2).unwrap();
@@ -1006,39 +4546,26 @@ mod tests {
 
     #[test]
     fn _pre_update() {
-        // _pre_update does not do anything, so testing is skipped
-        /*
-        let (_tax, _, _) = setup_tax_animals();
-
-        let c_pflanzen = Concept::new("Pflanzen");
-        let id_pflanzen = c_pflanzen.id();
-
-        let c_blumen = Concept::new("Blumen");
-        let id_blumen = c_blumen.id();
-
-        let c_baeume = Concept::new("Bäume");
-        let id_baeume = c_baeume.id();
-
-        let c_graeser = Concept::new("Graeser");
-        let id_graeser = c_graeser.id();
-
-        let _ = tax.add(None, c_pflanzen);
-        let _ = tax.add(None, c_blumen);
-        let _ = tax.add(None, c_baeume);
-        let _ = tax.add(None, c_graeser);
-
-        let _ = tax.append(Some(id_pflanzen.clone()), id_baeume);
-
-        let _ = tax.append_at(Some(id_pflanzen.clone()), id_blumen, 0);
-
-        let _ = tax.move_to(Rc::new(id_graeser), None, Some(Rc::new(id_pflanzen)), 2);
-
-        let _ = tax.remove(Rc::new(id_graeser));
-
-        let _ = tax.remove_from(Edge::new(None, Rc::new(id_blumen)));
-
-        let _ = tax.remove_recursively(Rc::new(id_pflanzen));
-         */
+        let (mut tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+
+        // Without an open transaction nothing is recorded
+        tax._pre_update(id("Hunde"));
+        assert!(tax.journal.is_empty());
+
+        tax.journal
+            .push(Journal::new(Node::snapshot, LinkedList::new(), None, Vec::new(), false, None));
+        let hunde = tax._get_node_opt(id("Hunde")).unwrap().clone();
+        tax._pre_update(id("Hunde"));
+        tax.remove_from(Edge::new(Some(id("Tierheime")), id("Hunde"))).unwrap();
+
+        // The state before the first update is kept
+        let unknown = Rc::new(Uuid::new_v4());
+        tax._pre_update(unknown.clone());
+        let journal = tax.journal.pop().unwrap();
+        assert_eq!(journal.nodes[&id("Hunde")], Some(hunde));
+        assert_eq!(journal.nodes[&id("Tierheime")].as_ref().unwrap().count_subs(), 2);
+        assert_eq!(journal.nodes[&unknown], None);
     }
 
     #[test]
@@ -1058,7 +4585,7 @@ mod tests {
         tax._remove_non_root_node(node_id.clone());
 
         assert_eq!(tax.nodes.len(), num_root_nodes - 1);
-        assert_eq!(tax.nodes.contains_key(&node_id), false);
+        assert!(!tax.nodes.contains_key(&node_id));
         assert_eq!(tax.last_updated_node().unwrap(), node_id);
     }
 
@@ -1084,8 +4611,8 @@ mod tests {
         tax._remove_root_node(node_id.clone());
 
         assert_eq!(tax.node0.len(), num_root_nodes - 1);
-        assert_eq!(tax.node0.contains(&node_id), false);
-        assert_eq!(tax.nodes.contains_key(&node_id), false);
+        assert!(!tax.node0.contains(&node_id));
+        assert!(!tax.nodes.contains_key(&node_id));
         assert_eq!(tax.node0.iter().nth(middle).unwrap().clone(), node_id_successor);
         assert_eq!(tax.last_updated_node().unwrap(), node_id);
 
@@ -1095,20 +4622,44 @@ mod tests {
         tax._remove_root_node(node_id.clone());
 
         assert_eq!(tax.node0.len(), num_root_nodes - 2);
-        assert_eq!(tax.node0.contains(&node_id), false);
-        assert_eq!(tax.nodes.contains_key(&node_id), false);
+        assert!(!tax.node0.contains(&node_id));
+        assert!(!tax.nodes.contains_key(&node_id));
         assert_eq!(tax.node0.iter().last().unwrap().clone(), node_id_predecessor);
         assert_eq!(tax.last_updated_node().unwrap(), node_id);
 
         // Remove root-node from the front
-        let node_id = tax.node0.iter().nth(0).unwrap().clone();
+        let node_id = tax.node0.iter().next().unwrap().clone();
         let node_id_successor = tax.node0.iter().nth(1).unwrap().clone();
         tax._remove_root_node(node_id.clone());
 
         assert_eq!(tax.node0.len(), num_root_nodes - 3);
-        assert_eq!(tax.node0.contains(&node_id), false);
-        assert_eq!(tax.nodes.contains_key(&node_id), false);
-        assert_eq!(tax.node0.iter().nth(0).unwrap().clone(), node_id_successor);
+        assert!(!tax.node0.contains(&node_id));
+        assert!(!tax.nodes.contains_key(&node_id));
+        assert_eq!(tax.node0.iter().next().unwrap().clone(), node_id_successor);
         assert_eq!(tax.last_updated_node().unwrap(), node_id);
     }
+
+    #[test]
+    fn _reorder() {
+        let (mut tax, ids, _) = setup_tax_animals();
+        let id = |name: &str| Rc::new(ids[name]);
+
+        // Every index is reachable, out of bound moves to the back
+        for index in 0..5 {
+            tax._reorder(Some(id("Tiere")), id("Zootiere"), index);
+            assert_eq!(tax._sub_ids_of(Some(id("Tiere")))[index], id("Zootiere"));
+            assert_eq!(tax._sub_ids_of(Some(id("Tiere"))).len(), 5);
+        }
+        tax._reorder(Some(id("Tiere")), id("Zootiere"), 0)
+            ._reorder(Some(id("Tiere")), id("Zootiere"), 5);
+        assert_eq!(tax._sub_ids_of(Some(id("Tiere")))[4], id("Zootiere"));
+
+        for index in 0..4 {
+            tax._reorder(None, id("Tierheime"), index);
+            assert_eq!(tax._sub_ids_of(None)[index], id("Tierheime"));
+            assert_eq!(tax._sub_ids_of(None).len(), 4);
+        }
+        tax._reorder(None, id("Tierheime"), 0)._reorder(None, id("Tierheime"), 100);
+        assert_eq!(tax._sub_ids_of(None)[3], id("Tierheime"));
+    }
 }
@@ -0,0 +1,16 @@
+/// Order in which an [`Iter`](crate::Iter) returns the elements of a taxonomy.
+///
+/// All orders follow the order of root-nodes and the order of sub-nodes below each super-node.
+/// A node with more than one super-node is returned once per super-node.
+#[derive(Eq, PartialEq, Clone, Copy, Debug, Default, Hash)]
+pub enum TraversalOrder {
+    /// Depth-first, every node before its sub-nodes. This is the order of [`Taxonomy::traverse`](crate::Taxonomy::traverse).
+    #[default]
+    PreOrder,
+
+    /// Depth-first, every node after its sub-nodes.
+    PostOrder,
+
+    /// Level by level, all root-nodes first, then their sub-nodes and so on.
+    BreadthFirst,
+}
@@ -0,0 +1,125 @@
+use super::Taxonomy;
+use crate::Identity;
+use crate::TreeStyle;
+
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::hash::Hash;
+use std::rc::Rc;
+
+impl<K, V, M, E> Taxonomy<K, V, M, E>
+where
+    K: Hash + Eq,
+    V: Identity<K>,
+{
+    /// Renders the taxonomy as tree, one node per line, each line terminated by a line feed.
+    ///
+    /// Root-nodes and sub-nodes are rendered in order, `label` returns the text of a node. A node with more than
+    /// one super-node is rendered below each of its super-nodes, see [`TreeStyle`] for marking such repeated nodes,
+    /// limiting the depth and choosing the glyphs drawing the branches.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kodiak_taxonomy::{Glyphs, Identity, Taxonomy, TaxonomyError, TreeStyle};
+    ///
+    /// struct Class {
+    ///     name: String,
+    /// }
+    ///
+    /// impl Identity<String> for Class {
+    ///     fn id(&self) -> String {
+    ///         self.name.clone()
+    ///     }
+    /// }
+    ///
+    /// fn main() -> Result<(), TaxonomyError<String>> {
+    ///     let mut tax: Taxonomy<String, Class> = Taxonomy::new();
+    ///     tax.add(None, Class{name: "Device".to_string()})?
+    ///        .add(Some("Device".to_string()), Class{name: "Server".to_string()})?
+    ///        .add(Some("Device".to_string()), Class{name: "Client".to_string()})?
+    ///        .add(None, Class{name: "Backup".to_string()})?
+    ///        .append(Some("Backup".to_string()), "Server".to_string())?;
+    ///
+    ///     let tree = tax.render_tree(&TreeStyle::default(), |class| class.name.clone());
+    ///     assert_eq!(tree, "├── Device\n│   ├── Server\n│   └── Client\n└── Backup\n    └── Server\n");
+    ///
+    ///     let style = TreeStyle::default().with_glyphs(Glyphs::Ascii).with_marker("(*)");
+    ///     let tree = tax.render_tree(&style, |class| class.name.to_uppercase());
+    ///     assert_eq!(tree, "|-- DEVICE\n|   |-- SERVER\n|   `-- CLIENT\n`-- BACKUP\n    `-- SERVER (*)\n");
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    // Test coverage: { unit = done, integration = done, doc = done } -> ok
+    pub fn render_tree<L>(&self, style: &TreeStyle, mut label: L) -> String
+    where
+        L: FnMut(&V) -> String,
+    {
+        let [tee, corner, pipe, blank] = style.glyphs().branches();
+        let mut tree = String::new();
+        let mut rendered = HashSet::new();
+
+        // Depth-first with the prefix of each node's line, whether the node is the last sibling and its depth
+        let mut stack = Self::_tree_level(self.node0.iter().cloned().collect(), String::new(), 1);
+        while let Some((node_id, prefix, is_last, depth)) = stack.pop() {
+            let element = self._get_node_opt(node_id.clone()).unwrap().get();
+            tree.push_str(&prefix);
+            tree.push_str(if is_last { corner } else { tee });
+            tree.push_str(&label(element));
+
+            let is_repeated = !rendered.insert(node_id.clone());
+            if let Some(marker) = style.marker().filter(|_| is_repeated) {
+                tree.push(' ');
+                tree.push_str(marker);
+                tree.push('\n');
+                continue;
+            }
+            tree.push('\n');
+
+            if style.max_depth().is_none_or(|max_depth| depth < max_depth) {
+                let prefix = prefix + if is_last { blank } else { pipe };
+                stack.extend(Self::_tree_level(self._sub_ids_of(Some(node_id)), prefix, depth + 1));
+            }
+        }
+
+        tree
+    }
+}
+
+/// Renders the taxonomy as tree, see [`Taxonomy::render_tree`], using the default [`TreeStyle`] and the
+/// elements' `Display` implementation as label.
+impl<K, V, M, E> Display for Taxonomy<K, V, M, E>
+where
+    K: Hash + Eq,
+    V: Identity<K> + Display,
+{
+    // Test coverage: { unit = done, integration = done, doc = none } -> ok
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            self.render_tree(&TreeStyle::default(), |element| element.to_string())
+        )
+    }
+}
+
+#[doc(hidden)]
+impl<K, V, M, E> Taxonomy<K, V, M, E>
+where
+    K: Hash + Eq,
+    V: Identity<K>,
+{
+    /// Returns the nodes of a level ready to be pushed on the stack of [`render_tree`](Self::render_tree), i.e. in
+    /// reverse order.
+    // Test coverage: { unit = done, integration = n/a, doc = n/a } -> ok
+    fn _tree_level(node_ids: Vec<Rc<K>>, prefix: String, depth: usize) -> Vec<(Rc<K>, String, bool, usize)> {
+        let last = node_ids.len().saturating_sub(1);
+        node_ids
+            .into_iter()
+            .enumerate()
+            .rev()
+            .map(|(index, node_id)| (node_id, prefix.clone(), index == last, depth))
+            .collect()
+    }
+}
@@ -0,0 +1,50 @@
+use crate::Glyphs;
+
+/// Style of a tree rendered by [`Taxonomy::render_tree`](crate::Taxonomy::render_tree).
+///
+/// By default, a tree is drawn with [`Glyphs::Unicode`], all levels are rendered and a node with more than one
+/// super-node is rendered including its sub-nodes below each of its super-nodes.
+#[derive(Eq, PartialEq, Clone, Debug, Default, Hash)]
+pub struct TreeStyle {
+    glyphs: Glyphs,
+    max_depth: Option<usize>,
+    marker: Option<String>,
+}
+
+impl TreeStyle {
+    /// Returns the style drawing the tree with `glyphs`.
+    pub fn with_glyphs(mut self, glyphs: Glyphs) -> Self {
+        self.glyphs = glyphs;
+        self
+    }
+
+    /// Returns the style rendering `max_depth` levels at most, i.e. `1` renders root-nodes only. Root-nodes are
+    /// rendered in any case.
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = Some(max_depth);
+        self
+    }
+
+    /// Returns the style marking repeated nodes. A node with more than one super-node is rendered including its
+    /// sub-nodes at its first occurrence only, further occurrences are followed by `marker` and their sub-nodes
+    /// are skipped.
+    pub fn with_marker(mut self, marker: &str) -> Self {
+        self.marker = Some(marker.to_string());
+        self
+    }
+
+    /// Returns the glyphs drawing the tree.
+    pub fn glyphs(&self) -> Glyphs {
+        self.glyphs
+    }
+
+    /// Returns the number of levels rendered at most or `None` if all levels are rendered.
+    pub fn max_depth(&self) -> Option<usize> {
+        self.max_depth
+    }
+
+    /// Returns the marker of repeated nodes or `None` if repeated nodes are rendered like their first occurrence.
+    pub fn marker(&self) -> Option<&str> {
+        self.marker.as_deref()
+    }
+}
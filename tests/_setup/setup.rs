@@ -40,21 +40,21 @@ pub fn sub_with_two_super(tax: &mut Taxonomy<Uuid, Concept>) -> LinkedList<Uuid>
     let element = Concept::new("Network Device");
     let super1a_id = element.id();
     let _ = tax.add(None, element);
-    list.push_back(super1a_id.clone());
+    list.push_back(super1a_id);
 
     let sub_node = Concept::new("Firewall");
     let sub_id = sub_node.id();
     let _ = tax.add(Some(super1a_id), sub_node);
-    list.push_back(sub_id.clone());
+    list.push_back(sub_id);
 
     let element = Concept::new("Security Device");
     let super1b_id = element.id();
     let _ = tax.add(None, element);
-    list.push_back(super1b_id.clone());
+    list.push_back(super1b_id);
 
     // Add Firewall a second time
     let _ = tax.append(Some(super1b_id), sub_id);
-    list.push_back(sub_id.clone());
+    list.push_back(sub_id);
 
     list
 }
@@ -72,32 +72,32 @@ pub fn two_root_with_two_sub_each(tax: &mut Taxonomy<Uuid, Concept>) -> LinkedLi
     let element = Concept::new("Device");
     let root1_id = element.id();
     let _ = tax.add(None, element);
-    list.push_back(root1_id.clone());
+    list.push_back(root1_id);
 
     let element = Concept::new("Network Device");
     let super1a_id = element.id();
     let _ = tax.add(Some(root1_id), element);
-    list.push_back(super1a_id.clone());
+    list.push_back(super1a_id);
 
     let element = Concept::new("Security Device");
     let super1b_id = element.id();
     let _ = tax.add(Some(root1_id), element);
-    list.push_back(super1b_id.clone());
+    list.push_back(super1b_id);
 
     let element = Concept::new("Organisation");
     let root2_id = element.id();
     let _ = tax.add(None, element);
-    list.push_back(root2_id.clone());
+    list.push_back(root2_id);
 
     let element = Concept::new("Department");
     let super2a_id = element.id();
     let _ = tax.add(Some(root2_id), element);
-    list.push_back(super2a_id.clone());
+    list.push_back(super2a_id);
 
     let element = Concept::new("User");
     let super2b_id = element.id();
     let _ = tax.add(Some(root2_id), element);
-    list.push_back(super2b_id.clone());
+    list.push_back(super2b_id);
 
     list
 }
@@ -115,32 +115,32 @@ pub fn two_root_with_super_and_sub(tax: &mut Taxonomy<Uuid, Concept>) -> LinkedL
     let element = Concept::new("Device");
     let root1_id = element.id();
     let _ = tax.add(None, element);
-    list.push_back(root1_id.clone());
+    list.push_back(root1_id);
 
     let element = Concept::new("Network Device");
     let super1a_id = element.id();
     let _ = tax.add(Some(root1_id), element);
-    list.push_back(super1a_id.clone());
+    list.push_back(super1a_id);
 
     let element = Concept::new("Security Device");
     let super1b_id = element.id();
     let _ = tax.add(Some(root1_id), element);
-    list.push_back(super1b_id.clone());
+    list.push_back(super1b_id);
 
     let element = Concept::new("Firewall");
     let sub_id = element.id();
     let _ = tax.add(Some(super1b_id), element);
-    list.push_back(sub_id.clone());
+    list.push_back(sub_id);
 
     let element = Concept::new("Organisation");
     let root2_id = element.id();
     let _ = tax.add(None, element);
-    list.push_back(root2_id.clone());
+    list.push_back(root2_id);
 
     let element = Concept::new("User");
     let super2a_id = element.id();
     let _ = tax.add(Some(root2_id), element);
-    list.push_back(super2a_id.clone());
+    list.push_back(super2a_id);
 
     list
 }
@@ -165,42 +165,42 @@ pub fn four_root_with_super_and_sub(tax: &mut Taxonomy<Uuid, Concept>) -> Linked
     let element = Concept::new("CRM");
     let crm_id = element.id();
     let _ = tax.add(None, element);
-    list.push_back(crm_id.clone());
+    list.push_back(crm_id);
 
     let element = Concept::new("Customer");
     let customer_id = element.id();
     let _ = tax.add(Some(crm_id), element);
-    list.push_back(customer_id.clone());
+    list.push_back(customer_id);
 
     let element = Concept::new("Contact");
     let contact_id = element.id();
     let _ = tax.add(Some(crm_id), element);
-    list.push_back(contact_id.clone());
+    list.push_back(contact_id);
 
     let element = Concept::new("CMDB");
     let cmdb_id = element.id();
     let _ = tax.add(None, element);
-    list.push_back(cmdb_id.clone());
+    list.push_back(cmdb_id);
 
     let element = Concept::new("Device");
     let device_id = element.id();
     let _ = tax.add(Some(cmdb_id), element);
-    list.push_back(device_id.clone());
+    list.push_back(device_id);
 
     let element = Concept::new("Network Device");
     let net_device_id = element.id();
     let _ = tax.add(Some(device_id), element);
-    list.push_back(net_device_id.clone());
+    list.push_back(net_device_id);
 
     let element = Concept::new("Security Device");
     let sec_device_id = element.id();
     let _ = tax.add(Some(device_id), element);
-    list.push_back(sec_device_id.clone());
+    list.push_back(sec_device_id);
 
     let element = Concept::new("Firewall");
     let firewall_id = element.id();
     let _ = tax.add(Some(sec_device_id), element);
-    list.push_back(firewall_id.clone());
+    list.push_back(firewall_id);
 
     /*
     let element = Concept::new("Organisation");
@@ -212,20 +212,20 @@ pub fn four_root_with_super_and_sub(tax: &mut Taxonomy<Uuid, Concept>) -> Linked
     let user_element = Concept::new("User");
     let user_id = user_element.id();
     let _ = tax.add(Some(firewall_id), user_element);
-    list.push_back(user_id.clone());
+    list.push_back(user_id);
 
     let element = Concept::new("SRM");
     let srm_id = element.id();
     let _ = tax.add(None, element);
-    list.push_back(srm_id.clone());
+    list.push_back(srm_id);
 
     let element = Concept::new("HRM");
     let hrm_id = element.id();
     let _ = tax.add(None, element);
-    list.push_back(hrm_id.clone());
+    list.push_back(hrm_id);
 
     let _ = tax.append(Some(hrm_id), user_id);
-    list.push_back(user_id.clone());
+    list.push_back(user_id);
 
     list
 }
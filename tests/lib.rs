@@ -2,10 +2,30 @@ mod _setup;
 mod _structs;
 
 mod taxonomy_add;
+mod taxonomy_adjacency;
 mod taxonomy_append;
+mod taxonomy_diagram;
+mod taxonomy_edge;
 mod taxonomy_get;
 mod taxonomy_get_mut;
+mod taxonomy_history;
+mod taxonomy_iter;
+mod taxonomy_metadata;
+mod taxonomy_obo;
+mod taxonomy_observer;
+mod taxonomy_outline;
+mod taxonomy_relatives;
+mod taxonomy_remove;
+mod taxonomy_reorder;
+#[cfg(feature = "serde")]
+mod taxonomy_serde;
+mod taxonomy_skos;
+mod taxonomy_sort;
+mod taxonomy_subsumption;
+mod taxonomy_take;
+mod taxonomy_transaction;
 mod taxonomy_traverse;
+mod taxonomy_tree;
 
 mod taxonomy_default;
 
@@ -0,0 +1,66 @@
+// Integration with kodiak's taxonomy library
+use kodiak_taxonomy::{Identity, ImportError, Taxonomy, TaxonomyError};
+
+// Shared code across integration tests
+use crate::setup::*;
+use crate::Concept;
+
+use uuid::Uuid;
+
+use std::collections::HashMap;
+use std::rc::Rc;
+
+// Tests:
+// pub fn to_adjacency_csv(&self) -> String
+// pub fn from_adjacency_csv<F>(input: &str, element: F) -> Result<Self, Vec<ImportError<K>>>
+
+fn names(tax: &Taxonomy<Uuid, Concept>) -> Vec<String> {
+    tax.iter().map(|element| element.name()).collect()
+}
+
+#[test]
+fn test_adjacency_round_trip_keeps_polyhierarchy() {
+    let mut tax = setup_tax_empty();
+    let ids = four_root_with_super_and_sub(&mut tax);
+
+    let csv = tax.to_adjacency_csv();
+    assert!(csv.starts_with(&format!("parent_id,child_id,position\n,{},0\n", ids.front().unwrap())));
+    assert_eq!(csv.lines().count(), 1 + tax.edges().count());
+
+    // Rows in reverse order are imported as well
+    let elements = tax
+        .iter()
+        .map(|element| (element.id(), element.name()))
+        .collect::<HashMap<_, _>>();
+    let mut rows = csv.lines().skip(1).map(|line| format!("{}\n", line)).collect::<Vec<_>>();
+    rows.reverse();
+    let reversed = rows.concat();
+    let imported: Taxonomy<Uuid, Concept> =
+        Taxonomy::from_adjacency_csv(&reversed, |id| Concept::with_id(*id, &elements[id])).unwrap();
+    assert_eq!(names(&imported), names(&tax));
+    assert_eq!(imported.to_adjacency_csv(), csv);
+}
+
+#[test]
+fn test_adjacency_import_reports_all_rows() {
+    let (crm, customer, missing) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+    let csv = format!(
+        ",{0},0\n{0},{1},0\n{0},{1},1\n{2},{1},0\n{1},not-a-uuid,0\n{1},{0},0\n",
+        crm, customer, missing
+    );
+
+    let errors = Taxonomy::<Uuid, Concept>::from_adjacency_csv(&csv, |id| Concept::with_id(*id, ""))
+        .err()
+        .unwrap();
+    assert_eq!(errors.iter().map(|err| err.line()).collect::<Vec<_>>(), vec![3, 4, 5, 6]);
+    assert_eq!(
+        errors[0],
+        ImportError::Taxonomy(3, TaxonomyError::DuplicateSubNode(Rc::new(crm), Rc::new(customer)))
+    );
+    assert_eq!(
+        errors[1],
+        ImportError::Taxonomy(4, TaxonomyError::NodeNotFound(Rc::new(missing)))
+    );
+    assert!(matches!(errors[2], ImportError::Syntax(5, _)));
+    assert_eq!(errors[3], ImportError::Taxonomy(6, TaxonomyError::LoopDetected(Rc::new(crm))));
+}
@@ -0,0 +1,56 @@
+// Integration with kodiak's taxonomy library
+use kodiak_taxonomy::Identity;
+
+// Shared code across integration tests
+use crate::setup::*;
+
+use std::rc::Rc;
+
+// Tests:
+// pub fn to_dot<L, S>(&self, node_id: Option<Rc<K>>, label: L, style: S) -> Result<String, TaxonomyError<K>>
+// pub fn to_mermaid<L, S>(&self, node_id: Option<Rc<K>>, label: L, style: S) -> Result<String, TaxonomyError<K>>
+
+#[test]
+fn test_diagram_renders_polyhierarchy_once() {
+    let mut tax = setup_tax_empty();
+    let list = four_root_with_super_and_sub(&mut tax)
+        .into_iter()
+        .map(Rc::new)
+        .collect::<Vec<_>>();
+    let user = &list[8];
+
+    let dot = tax.to_dot(None, |element| element.name(), |_, _| None).unwrap();
+    let mermaid = tax.to_mermaid(None, |element| element.name(), |_, _| None).unwrap();
+    assert_eq!(dot.matches("[label=\"User\"]").count(), 1);
+    assert_eq!(mermaid.matches("[\"User\"]").count(), 1);
+
+    // User has two super-nodes, thus two incoming arrows
+    let name = dot
+        .lines()
+        .find(|line| line.ends_with("[label=\"User\"];"))
+        .map(|line| line.trim().split(' ').next().unwrap().to_string())
+        .unwrap();
+    assert_eq!(dot.matches(&format!(" -> {};", name)).count(), 2);
+    assert_eq!(mermaid.matches(&format!(" --> {}\n", name)).count(), 2);
+
+    // Root-nodes are grouped
+    let cluster = &dot[dot.find("subgraph cluster_roots").unwrap()..dot.find("    }\n").unwrap()];
+    for root in ["CRM", "CMDB", "SRM", "HRM"] {
+        assert!(cluster.contains(&format!("[label=\"{}\"]", root)));
+    }
+    let subgraph = &mermaid[mermaid.find("subgraph roots").unwrap()..mermaid.find("    end\n").unwrap()];
+    assert_eq!(subgraph.lines().count(), 5);
+
+    // Styles are applied to edges into User only
+    let mermaid = tax
+        .to_mermaid(
+            None,
+            |element| element.name(),
+            |_, sub| match sub.id() == **user {
+                true => Some("stroke:red".to_string()),
+                false => None,
+            },
+        )
+        .unwrap();
+    assert_eq!(mermaid.matches("linkStyle ").count(), 2);
+}
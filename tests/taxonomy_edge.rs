@@ -0,0 +1,128 @@
+// Integration with kodiak's taxonomy library
+use kodiak_taxonomy::{Edge, Identity, Taxonomy, TaxonomyError};
+
+// Shared code across integration tests
+use crate::Concept;
+
+use std::rc::Rc;
+use uuid::Uuid;
+
+// Tests:
+// pub fn add_with_payload(&mut self, super_id: Option<K>, element: V, payload: E) -> Result<&mut Self, TaxonomyError<K>>
+// pub fn append_with_payload(&mut self, super_id: Option<K>, node_id: K, payload: E) -> Result<&mut Self, TaxonomyError<K>>
+// pub fn append_at_with_payload(&mut self, super_id: Option<K>, node_id: K, index: usize, payload: E) -> Result<&mut Self, TaxonomyError<K>>
+// pub fn edge(&self, super_id: Option<Rc<K>>, node_id: Rc<K>) -> Result<Edge<K, &E>, TaxonomyError<K>>
+// pub fn set_edge_payload(&mut self, super_id: Option<Rc<K>>, node_id: Rc<K>, payload: E) -> Result<&mut Self, TaxonomyError<K>>
+// pub fn edges(&self) -> Edges<'_, K, V, M, E>
+// pub fn edges_into(&self, node_id: Rc<K>) -> Result<Edges<'_, K, V, M, E>, TaxonomyError<K>>
+// pub fn edges_from(&self, super_id: Option<Rc<K>>) -> Result<Edges<'_, K, V, M, E>, TaxonomyError<K>>
+// pub fn remove_from<P>(&mut self, edge: Edge<K, P>) -> Result<&mut Self, TaxonomyError<K>>
+
+#[derive(Debug, Default, PartialEq)]
+struct Confidence(u8);
+
+#[test]
+fn test_edge_payload() {
+    let mut tax: Taxonomy<Uuid, Concept, (), Confidence> = Taxonomy::new();
+
+    let device = Concept::new("Device");
+    let device_id = device.id();
+    let security = Concept::new("Security Device");
+    let security_id = security.id();
+    let firewall = Concept::new("Firewall");
+    let firewall_id = firewall.id();
+
+    tax.add(None, device)
+        .unwrap()
+        .add_with_payload(Some(device_id), security, Confidence(100))
+        .unwrap()
+        .add_with_payload(Some(device_id), firewall, Confidence(90))
+        .unwrap()
+        .append_at_with_payload(Some(security_id), firewall_id, 0, Confidence(60))
+        .unwrap();
+
+    let device_id = Some(Rc::new(device_id));
+    let security_id = Some(Rc::new(security_id));
+    let firewall_id = Rc::new(firewall_id);
+
+    let edge = tax.edge(security_id.clone(), firewall_id.clone()).unwrap();
+    assert_eq!(edge.super_id(), security_id);
+    assert_eq!(edge.node_id(), firewall_id);
+    assert_eq!(edge.payload(), &&Confidence(60));
+    assert_eq!(
+        tax.edge(device_id.clone(), firewall_id.clone()).unwrap().into_payload(),
+        &Confidence(90)
+    );
+    assert_eq!(
+        tax.edge(None, device_id.clone().unwrap()).unwrap().into_payload(),
+        &Confidence::default()
+    );
+
+    // Payload is carried along when moving a node
+    tax.move_to(firewall_id.clone(), security_id.clone(), None, 0).unwrap();
+    assert_eq!(tax.edge(None, firewall_id.clone()).unwrap().into_payload(), &Confidence(60));
+
+    tax.set_edge_payload(None, firewall_id.clone(), Confidence(70)).unwrap();
+    assert_eq!(tax.edge(None, firewall_id.clone()).unwrap().into_payload(), &Confidence(70));
+
+    let result = tax.edge(security_id.clone(), firewall_id.clone()).err();
+    assert_eq!(result, Some(TaxonomyError::EdgeNotFound(security_id, firewall_id)));
+}
+
+#[test]
+fn test_edges() {
+    let mut tax: Taxonomy<Uuid, Concept, (), Confidence> = Taxonomy::new();
+
+    let device = Concept::new("Device");
+    let device_id = device.id();
+    let security = Concept::new("Security Device");
+    let security_id = security.id();
+    let firewall = Concept::new("Firewall");
+    let firewall_id = firewall.id();
+
+    tax.add(None, device)
+        .unwrap()
+        .add(None, security)
+        .unwrap()
+        .add_with_payload(Some(device_id), firewall, Confidence(90))
+        .unwrap()
+        .append_with_payload(Some(security_id), firewall_id, Confidence(60))
+        .unwrap();
+
+    let device_id = Rc::new(device_id);
+    let security_id = Rc::new(security_id);
+    let firewall_id = Rc::new(firewall_id);
+
+    let edges = tax
+        .edges()
+        .map(|edge| (edge.super_id(), edge.node_id(), edge.into_payload().0))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        edges,
+        vec![
+            (None, device_id.clone(), 0),
+            (None, security_id.clone(), 0),
+            (Some(device_id.clone()), firewall_id.clone(), 90),
+            (Some(security_id.clone()), firewall_id.clone(), 60),
+        ]
+    );
+
+    let supers = tax
+        .edges_into(firewall_id.clone())
+        .unwrap()
+        .map(|edge| edge.super_id())
+        .collect::<Vec<_>>();
+    assert_eq!(supers, vec![Some(device_id.clone()), Some(security_id.clone())]);
+
+    let subs = tax.edges_from(None).unwrap().map(|edge| edge.node_id()).collect::<Vec<_>>();
+    assert_eq!(subs, vec![device_id.clone(), security_id.clone()]);
+
+    // Edges are constructible by users, e.g. to remove a node from one of its super-nodes
+    tax.remove_from(Edge::new(Some(device_id.clone()), firewall_id.clone()))
+        .unwrap();
+    assert_eq!(tax.edges_into(firewall_id.clone()).unwrap().count(), 1);
+    assert_eq!(tax.edges_from(Some(device_id.clone())).unwrap().count(), 0);
+
+    let result = tax.remove_from(Edge::new(Some(device_id.clone()), firewall_id.clone())).err();
+    assert_eq!(result, Some(TaxonomyError::EdgeNotFound(Some(device_id), firewall_id)));
+}
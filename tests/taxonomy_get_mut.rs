@@ -1,13 +1,14 @@
 use std::rc::Rc;
+use uuid::Uuid;
 // Integration with kodiak's taxonomy library
-use kodiak_taxonomy::Identity;
+use kodiak_taxonomy::{Identity, RefMut};
 
 // Shared code across integration tests
 use crate::setup::*;
 use crate::Concept;
 
 // Tests:
-// pub fn get_mut(&mut self, id: &H) -> Option<&mut T>
+// pub fn get_mut(&mut self, node_id: Rc<K>) -> Option<RefMut<'_, K, V, M, E>>
 
 #[test]
 fn test_get_mut_some() {
@@ -17,7 +18,7 @@ fn test_get_mut_some() {
     let super_id = element.id();
     let _ = tax.add(None, element);
 
-    let result: Option<&mut Concept> = tax.get_mut(Rc::new(super_id));
+    let result: Option<RefMut<Uuid, Concept>> = tax.get_mut(Rc::new(super_id));
 
     assert!(result.is_some());
 }
@@ -29,7 +30,7 @@ fn test_get_none() {
     let element = Concept::new("Animal");
     let super_id = element.id();
 
-    let result: Option<&mut Concept> = tax.get_mut(Rc::new(super_id));
+    let result: Option<RefMut<Uuid, Concept>> = tax.get_mut(Rc::new(super_id));
 
     assert!(result.is_none());
 }
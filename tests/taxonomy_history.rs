@@ -0,0 +1,58 @@
+// Integration with kodiak's taxonomy library
+use kodiak_taxonomy::{Identity, Operation};
+
+// Shared code across integration tests
+use crate::setup::*;
+use crate::Concept;
+
+use std::rc::Rc;
+
+// Tests:
+// pub fn enable_history(&mut self, capacity: usize) -> &mut Self
+// pub fn history(&self) -> Vec<&Operation<K>>
+// pub fn undo(&mut self) -> Option<&Operation<K>>
+// pub fn redo(&mut self) -> Option<&Operation<K>>
+
+#[test]
+fn test_history_undo_redo() {
+    let mut tax = setup_tax_empty();
+    let list = four_root_with_super_and_sub(&mut tax)
+        .into_iter()
+        .map(Rc::new)
+        .collect::<Vec<_>>();
+    let (crm, device, firewall, user, srm) = (&list[0], &list[4], &list[7], &list[8], &list[9]);
+    let names = |tax: &kodiak_taxonomy::Taxonomy<_, Concept>| tax.iter().map(|element| element.name()).collect::<Vec<_>>();
+    let before = names(&tax);
+    tax.enable_history(10);
+
+    let server = Concept::new("Server");
+    let server_id = Rc::new(server.id());
+    tax.add(Some(**device), server)
+        .unwrap()
+        .append(Some(**srm), *server_id)
+        .unwrap()
+        .move_to(user.clone(), Some(firewall.clone()), Some(crm.clone()), 0)
+        .unwrap()
+        .remove_recursively(srm.clone())
+        .unwrap();
+    let after = names(&tax);
+
+    assert_eq!(
+        tax.history(),
+        vec![
+            &Operation::Add(Some(device.clone()), server_id.clone()),
+            &Operation::Append(Some(srm.clone()), server_id.clone()),
+            &Operation::MoveTo(user.clone(), Some(firewall.clone()), Some(crm.clone())),
+            &Operation::RemoveSubtree(srm.clone()),
+        ]
+    );
+
+    while tax.undo().is_some() {}
+    assert_eq!(names(&tax), before);
+    assert!(tax.get(server_id.clone()).is_none());
+
+    while tax.redo().is_some() {}
+    assert_eq!(names(&tax), after);
+    assert_eq!(tax.index_of(Some(crm.clone()), user.clone()), Ok(0));
+    assert!(tax.get(server_id).is_none());
+}
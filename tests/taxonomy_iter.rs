@@ -0,0 +1,163 @@
+// Integration with kodiak's taxonomy library
+use kodiak_taxonomy::{Identity, Occurrence, TraversalOrder};
+use std::rc::Rc;
+
+// Shared code across integration tests
+use crate::setup::*;
+use crate::Concept;
+
+// Tests:
+// pub fn iter(&self) -> Iter<'_, K, V>
+// pub fn iter_in(&self, order: TraversalOrder) -> Iter<'_, K, V>
+// pub fn visits(&self) -> Visits<'_, K, V>
+// pub fn iter_unique(&self, occurrence: Occurrence) -> IterUnique<'_, K, V>
+// pub fn levels(&self) -> Levels<'_, K, V>
+// pub fn iter_mut(&mut self) -> IterMut<'_, K, V>
+// impl IntoIterator for Taxonomy<K, V>, &Taxonomy<K, V> and &mut Taxonomy<K, V>
+
+#[test]
+fn test_iter() {
+    let (tax, _, list) = setup_tax_animals();
+
+    assert_eq!(tax.iter().count(), list.len());
+    for (element, expectation) in tax.iter().zip(list.iter()) {
+        assert_eq!(element.id(), expectation.id());
+    }
+}
+
+#[test]
+fn test_iter_for_loop() {
+    let (tax, _, mut list) = setup_tax_animals();
+
+    for element in &tax {
+        assert_eq!(list.pop_front().unwrap().id(), element.id());
+    }
+    assert_eq!(list.len(), 0);
+}
+
+#[test]
+fn test_iter_mut_visits_each_node_once() {
+    let mut tax = setup_tax_empty();
+    let list = sub_with_two_super(&mut tax);
+
+    let mut names = Vec::new();
+    for element in &mut tax {
+        names.push(element.name());
+    }
+    assert_eq!(names, vec!["Network Device", "Firewall", "Security Device"]);
+    assert_eq!(tax.iter().count(), list.len());
+}
+
+#[test]
+fn test_into_iter() {
+    let mut tax = setup_tax_empty();
+    let list = two_root_with_super_and_sub(&mut tax);
+
+    let elements = tax.into_iter().collect::<Vec<Concept>>();
+    assert_eq!(
+        elements.iter().map(|element| element.id()).collect::<Vec<_>>(),
+        list.into_iter().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_iter_in_post_order() {
+    let mut tax = setup_tax_empty();
+    let _ = two_root_with_super_and_sub(&mut tax);
+
+    let names = tax
+        .iter_in(TraversalOrder::PostOrder)
+        .map(|element| element.name())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        names,
+        vec![
+            "Network Device",
+            "Firewall",
+            "Security Device",
+            "Device",
+            "User",
+            "Organisation"
+        ]
+    );
+}
+
+#[test]
+fn test_iter_in_breadth_first() {
+    let mut tax = setup_tax_empty();
+    let _ = two_root_with_super_and_sub(&mut tax);
+
+    let names = tax
+        .iter_in(TraversalOrder::BreadthFirst)
+        .map(|element| element.name())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        names,
+        vec![
+            "Device",
+            "Organisation",
+            "Network Device",
+            "Security Device",
+            "User",
+            "Firewall"
+        ]
+    );
+}
+
+#[test]
+fn test_levels() {
+    let mut tax = setup_tax_empty();
+    let _ = sub_with_two_super(&mut tax);
+
+    let levels = tax.levels().map(|level| level.len()).collect::<Vec<_>>();
+    assert_eq!(levels, vec![2, 2]);
+}
+
+#[test]
+fn test_visits_tell_occurrences_apart() {
+    let mut tax = setup_tax_empty();
+    let list = sub_with_two_super(&mut tax);
+    let ids = list.into_iter().collect::<Vec<_>>();
+    let (network_id, firewall_id, security_id) = (Rc::new(ids[0]), Rc::new(ids[1]), Rc::new(ids[2]));
+
+    let firewalls = tax
+        .visits()
+        .filter(|visit| visit.element().id() == *firewall_id)
+        .collect::<Vec<_>>();
+
+    assert_eq!(firewalls.len(), 2);
+    assert_eq!(firewalls[0].depth(), 1);
+    assert_eq!(firewalls[0].edge().super_id(), Some(network_id.clone()));
+    assert_eq!(firewalls[0].path(), &[network_id, firewall_id.clone()]);
+    assert_eq!(firewalls[1].edge().super_id(), Some(security_id.clone()));
+    assert_eq!(firewalls[1].path(), &[security_id, firewall_id]);
+}
+
+#[test]
+fn test_iter_unique() {
+    let (mut tax, ids, list) = setup_tax_animals();
+
+    let first = tax
+        .iter_unique(Occurrence::First)
+        .map(|element| element.id())
+        .collect::<Vec<_>>();
+    let mut expectation = Vec::new();
+    for element in list.iter() {
+        if !expectation.contains(&element.id()) {
+            expectation.push(element.id());
+        }
+    }
+    assert_eq!(first, expectation);
+
+    // Katzen is returned below Tierheime, which is the last root-node
+    let katzen = Rc::new(ids["Katzen"]);
+    let result = tax.set_primary_super(katzen.clone(), Some(Rc::new(ids["Tierheime"])));
+    assert!(result.is_ok());
+
+    let primary = tax
+        .iter_unique(Occurrence::Primary)
+        .map(|element| element.id())
+        .collect::<Vec<_>>();
+    assert_eq!(primary.len(), first.len());
+    assert_eq!(primary.last(), Some(&*katzen));
+}
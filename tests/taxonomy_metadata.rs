@@ -0,0 +1,51 @@
+// Integration with kodiak's taxonomy library
+use kodiak_taxonomy::{Identity, Taxonomy, TaxonomyError};
+
+// Shared code across integration tests
+use crate::Concept;
+
+use std::collections::HashMap;
+use std::rc::Rc;
+use uuid::Uuid;
+
+// Tests:
+// pub fn metadata(&self, node_id: Rc<K>) -> Result<Option<&M>, TaxonomyError<K>>
+// pub fn metadata_mut(&mut self, node_id: Rc<K>) -> Result<Option<RefMut<'_, K, V, M, E, M>>, TaxonomyError<K>>
+// pub fn set_metadata(&mut self, node_id: Rc<K>, metadata: M) -> Result<&mut Self, TaxonomyError<K>>
+// pub fn remove_metadata(&mut self, node_id: Rc<K>) -> Result<Option<M>, TaxonomyError<K>>
+
+#[test]
+fn test_metadata_as_attribute_map() {
+    let mut tax: Taxonomy<Uuid, Concept, HashMap<String, String>> = Taxonomy::new();
+
+    let element = Concept::new("Device");
+    let device_id = Rc::new(element.id());
+    tax.add(None, element).unwrap();
+
+    let element = Concept::new("Firewall");
+    let firewall_id = Rc::new(element.id());
+    tax.add(Some(*device_id), element).unwrap();
+
+    tax.set_metadata(device_id.clone(), HashMap::from([("icon".to_string(), "server".to_string())]))
+        .unwrap();
+    tax.metadata_mut(device_id.clone())
+        .unwrap()
+        .unwrap()
+        .insert("created_by".to_string(), "admin".to_string());
+
+    let metadata = tax.metadata(device_id.clone()).unwrap().unwrap();
+    assert_eq!(metadata.len(), 2);
+    assert_eq!(metadata["icon"], "server");
+    assert_eq!(tax.metadata(firewall_id.clone()), Ok(None));
+
+    // Metadata is not part of the element
+    assert_eq!(tax.get(device_id.clone()).unwrap().name(), "Device");
+
+    let metadata = tax.remove_metadata(device_id.clone()).unwrap().unwrap();
+    assert_eq!(metadata["created_by"], "admin");
+    assert_eq!(tax.metadata(device_id), Ok(None));
+
+    let unknown = Rc::new(Uuid::new_v4());
+    let result = tax.set_metadata(unknown.clone(), HashMap::new()).err();
+    assert_eq!(result, Some(TaxonomyError::NodeNotFound(unknown)));
+}
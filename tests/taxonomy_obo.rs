@@ -0,0 +1,92 @@
+// Integration with kodiak's taxonomy library
+use kodiak_taxonomy::{Identity, ImportError, OboTerm, Taxonomy, TaxonomyError};
+
+use std::rc::Rc;
+
+// Tests:
+// pub fn from_obo<F>(input: &str, element: F) -> Result<Self, ImportError<K>>
+
+struct Term {
+    id: String,
+    name: String,
+}
+
+impl Identity<String> for Term {
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+}
+
+fn term(term: &OboTerm) -> Term {
+    Term {
+        id: term.id().to_string(),
+        name: term.name().unwrap_or_default().to_string(),
+    }
+}
+
+const OBO: &str = "format-version: 1.4
+data-version: 2026-01-01
+ontology: it
+
+[Term]
+id: IT:0001
+name: CMDB
+
+[Term]
+id: IT:0002
+name: Device
+is_a: IT:0001 ! CMDB
+
+[Term]
+id: IT:0003
+name: Firewall
+is_a: IT:0002 ! Device
+def: \"A device filtering network traffic.\" [IT:curator]
+
+[Term]
+id: IT:0004
+name: HRM
+
+[Term]
+id: IT:0005
+name: User
+is_a: IT:0003 ! Firewall
+is_a: IT:0004 ! HRM
+relationship: part_of IT:0004
+
+[Term]
+id: IT:0006
+name: Mainframe
+is_a: IT:0002
+is_obsolete: true
+
+[Typedef]
+id: part_of
+name: part of
+is_transitive: true
+";
+
+#[test]
+fn test_obo_import_keeps_polyhierarchy_and_tags() {
+    let tax: Taxonomy<String, Term, Vec<(String, String)>> = Taxonomy::from_obo(OBO, term).unwrap();
+
+    let names = tax.iter().map(|term| term.name.clone()).collect::<Vec<_>>();
+    assert_eq!(names, vec!["CMDB", "Device", "Firewall", "User", "Mainframe", "HRM", "User"]);
+    assert_eq!(tax.parents(Rc::new("IT:0005".to_string())).unwrap().count(), 2);
+
+    let metadata = tax.metadata(Rc::new("IT:0005".to_string())).unwrap().unwrap();
+    assert_eq!(metadata, &vec![("relationship".to_string(), "part_of IT:0004".to_string())]);
+}
+
+#[test]
+fn test_obo_import_reports_line() {
+    let obo = OBO.replace("is_a: IT:0004 ! HRM", "is_a: IT:0009 ! unknown");
+    let result = Taxonomy::<String, Term, Vec<(String, String)>>::from_obo(&obo, term);
+    assert_eq!(
+        result.err(),
+        Some(ImportError::Taxonomy(
+            28,
+            TaxonomyError::NodeNotFound(Rc::new("IT:0009".to_string()))
+        ))
+    );
+}
@@ -0,0 +1,56 @@
+// Integration with kodiak's taxonomy library
+use kodiak_taxonomy::{Event, Identity, RemovalPolicy};
+
+// Shared code across integration tests
+use crate::setup::*;
+use crate::Concept;
+
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::rc::Rc;
+
+// Tests:
+// pub fn subscribe<F>(&mut self, observer: F) -> usize
+// pub fn unsubscribe(&mut self, subscription: usize) -> bool
+
+#[test]
+fn test_subscribe_keeps_index_in_sync() {
+    let mut tax = setup_tax_empty();
+    let list = four_root_with_super_and_sub(&mut tax)
+        .into_iter()
+        .map(Rc::new)
+        .collect::<Vec<_>>();
+    let (crm, device, firewall, user, srm) = (&list[0], &list[4], &list[7], &list[8], &list[9]);
+
+    // An index of all node ids, kept in sync by an observer
+    let index = Rc::new(RefCell::new(tax.iter().map(|element| element.id()).collect::<HashSet<_>>()));
+    let observer = index.clone();
+    let subscription = tax.subscribe(move |event| match event {
+        Event::NodeAdded(_, node_id) => {
+            observer.borrow_mut().insert(**node_id);
+        }
+        Event::NodeRemoved(node_id) => {
+            observer.borrow_mut().remove(&**node_id);
+        }
+        _ => (),
+    });
+
+    let server = Concept::new("Server");
+    let server_id = server.id();
+    tax.add(Some(**device), server)
+        .unwrap()
+        .append(Some(**srm), server_id)
+        .unwrap()
+        .move_to(user.clone(), Some(firewall.clone()), Some(crm.clone()), 0)
+        .unwrap()
+        .remove_subtree(device.clone(), RemovalPolicy::Orphans)
+        .unwrap();
+
+    let ids = tax.iter().map(|element| element.id()).collect::<HashSet<_>>();
+    assert_eq!(*index.borrow(), ids);
+    assert!(index.borrow().contains(&server_id));
+
+    assert!(tax.unsubscribe(subscription));
+    tax.remove(Rc::new(server_id)).unwrap();
+    assert!(index.borrow().contains(&server_id));
+}
@@ -0,0 +1,46 @@
+// Integration with kodiak's taxonomy library
+use kodiak_taxonomy::{ImportError, Indent, Taxonomy, TaxonomyError};
+
+// Shared code across integration tests
+use crate::setup::*;
+use crate::Concept;
+
+use uuid::Uuid;
+
+// Tests:
+// pub fn to_outline<L>(&self, indent: Indent, label: L) -> String
+// pub fn from_outline<F>(input: &str, indent: Indent, element: F) -> Result<Self, ImportError<K>>
+
+fn names(tax: &Taxonomy<Uuid, Concept>) -> Vec<String> {
+    tax.iter().map(|element| element.name()).collect()
+}
+
+#[test]
+fn test_outline_round_trip_keeps_polyhierarchy() {
+    let mut tax = setup_tax_empty();
+    four_root_with_super_and_sub(&mut tax);
+
+    let outline = tax.to_outline(Indent::Spaces(2), |element| element.name());
+    assert!(outline.starts_with("CRM\n  Customer\n  Contact\nCMDB\n"));
+    assert!(outline.ends_with("HRM\n  User\n"));
+    assert_eq!(outline.matches("User\n").count(), 2);
+
+    let imported: Taxonomy<Uuid, Concept> = Taxonomy::from_outline(&outline, Indent::Spaces(2), Concept::new).unwrap();
+    assert_eq!(names(&imported), names(&tax));
+    assert_eq!(imported.to_outline(Indent::Spaces(2), |element| element.name()), outline);
+}
+
+#[test]
+fn test_outline_import_reports_line() {
+    let outline = "CRM\n\tCustomer\n\t\tContact\n\tContact\n\t\t\tUser\n";
+    let result = Taxonomy::<Uuid, Concept>::from_outline(outline, Indent::Tab, Concept::new);
+    assert!(matches!(result, Err(ImportError::Syntax(5, _))));
+
+    let outline = "CRM\n\tCustomer\n\t\tContact\n\t\t\tCRM\n";
+    let result = Taxonomy::<Uuid, Concept>::from_outline(outline, Indent::Tab, Concept::new);
+    assert!(matches!(
+        result,
+        Err(ImportError::Taxonomy(4, TaxonomyError::LoopDetected(_)))
+    ));
+    assert_eq!(result.err().unwrap().to_string().split(':').next(), Some("line 4"));
+}
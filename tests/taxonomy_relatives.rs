@@ -0,0 +1,100 @@
+// Integration with kodiak's taxonomy library
+use kodiak_taxonomy::TaxonomyError;
+
+// Shared code across integration tests
+use crate::setup::*;
+
+use std::collections::HashSet;
+use std::rc::Rc;
+
+// Tests:
+// pub fn ancestors(&self, node_id: Rc<K>, max_depth: Option<usize>) -> Result<Relatives<'_, K, V>, TaxonomyError<K>>
+// pub fn descendants(&self, node_id: Rc<K>, max_depth: Option<usize>) -> Result<Relatives<'_, K, V>, TaxonomyError<K>>
+// pub fn parents(&self, node_id: Rc<K>) -> Result<Relatives<'_, K, V>, TaxonomyError<K>>
+// pub fn children(&self, node_id: Rc<K>) -> Result<Relatives<'_, K, V>, TaxonomyError<K>>
+// pub fn siblings(&self, node_id: Rc<K>) -> Result<Siblings<'_, K, V>, TaxonomyError<K>>
+// pub fn paths_to(&self, node_id: Rc<K>) -> Result<Vec<Vec<Rc<K>>>, TaxonomyError<K>>
+// pub fn canonical_path(&self, node_id: Rc<K>) -> Result<Vec<Rc<K>>, TaxonomyError<K>>
+
+#[test]
+fn test_ancestors_and_descendants() {
+    let mut tax = setup_tax_empty();
+    let list = four_root_with_super_and_sub(&mut tax)
+        .into_iter()
+        .map(Rc::new)
+        .collect::<Vec<_>>();
+    let (cmdb, device, sec_device, firewall, user, hrm) = (&list[3], &list[4], &list[6], &list[7], &list[8], &list[10]);
+
+    let ancestors = tax.ancestors(user.clone(), None).unwrap().collect::<Vec<_>>();
+    assert_eq!(
+        ancestors,
+        vec![
+            firewall.clone(),
+            hrm.clone(),
+            sec_device.clone(),
+            device.clone(),
+            cmdb.clone()
+        ]
+    );
+
+    let descendants = tax.descendant_set(cmdb.clone(), Some(2)).unwrap();
+    assert_eq!(
+        descendants,
+        HashSet::from([device.clone(), list[5].clone(), sec_device.clone()])
+    );
+}
+
+#[test]
+fn test_parents_children_siblings() {
+    let mut tax = setup_tax_empty();
+    let list = sub_with_two_super(&mut tax).into_iter().map(Rc::new).collect::<Vec<_>>();
+    let (network, firewall, security) = (&list[0], &list[1], &list[2]);
+
+    assert_eq!(
+        tax.parents(firewall.clone()).unwrap().collect::<Vec<_>>(),
+        vec![network.clone(), security.clone()]
+    );
+    assert_eq!(
+        tax.children(security.clone()).unwrap().collect::<Vec<_>>(),
+        vec![firewall.clone()]
+    );
+    assert_eq!(tax.sibling_set(network.clone()).unwrap(), HashSet::from([security.clone()]));
+    assert!(tax.sibling_set(firewall.clone()).unwrap().is_empty());
+}
+
+#[test]
+fn test_relatives_node_not_found_err() {
+    let tax = setup_tax_empty();
+    let id = Rc::new(uuid::Uuid::new_v4());
+
+    assert_eq!(tax.parent_set(id.clone()).err(), Some(TaxonomyError::NodeNotFound(id)));
+}
+
+#[test]
+fn test_paths_to_and_canonical_path() {
+    let mut tax = setup_tax_empty();
+    let list = four_root_with_super_and_sub(&mut tax)
+        .into_iter()
+        .map(Rc::new)
+        .collect::<Vec<_>>();
+    let (cmdb, device, sec_device, firewall, user, hrm) = (&list[3], &list[4], &list[6], &list[7], &list[8], &list[10]);
+
+    let paths = tax.paths_to(user.clone()).unwrap();
+    assert_eq!(
+        paths,
+        vec![
+            vec![
+                cmdb.clone(),
+                device.clone(),
+                sec_device.clone(),
+                firewall.clone(),
+                user.clone()
+            ],
+            vec![hrm.clone(), user.clone()],
+        ]
+    );
+    assert_eq!(tax.canonical_path(user.clone()).unwrap(), paths[0]);
+
+    tax.set_primary_super(user.clone(), Some(hrm.clone())).unwrap();
+    assert_eq!(tax.canonical_path(user.clone()).unwrap(), paths[1]);
+}
@@ -0,0 +1,69 @@
+// Integration with kodiak's taxonomy library
+use kodiak_taxonomy::{RemovalPolicy, TaxonomyError};
+
+// Shared code across integration tests
+use crate::setup::*;
+
+use std::rc::Rc;
+
+// Tests:
+// pub fn remove_recursively(&mut self, node_id: Rc<K>) -> Result<&mut Self, TaxonomyError<K>>
+// pub fn remove_subtree(&mut self, node_id: Rc<K>, policy: RemovalPolicy) -> Result<&mut Self, TaxonomyError<K>>
+
+#[test]
+fn test_remove_subtree_orphans() {
+    let mut tax = setup_tax_empty();
+    let list = four_root_with_super_and_sub(&mut tax)
+        .into_iter()
+        .map(Rc::new)
+        .collect::<Vec<_>>();
+    let (device, user, hrm) = (&list[4], &list[8], &list[10]);
+
+    // User remains as it is reachable via HRM
+    tax.remove_subtree(device.clone(), RemovalPolicy::Orphans).unwrap();
+
+    let names = tax.iter().map(|element| element.name()).collect::<Vec<_>>();
+    assert_eq!(names, vec!["CRM", "Customer", "Contact", "CMDB", "SRM", "HRM", "User"]);
+    let supers = tax
+        .edges_into(user.clone())
+        .unwrap()
+        .map(|edge| edge.super_id())
+        .collect::<Vec<_>>();
+    assert_eq!(supers, vec![Some(hrm.clone())]);
+}
+
+#[test]
+fn test_remove_subtree_all() {
+    let mut tax = setup_tax_empty();
+    let list = four_root_with_super_and_sub(&mut tax)
+        .into_iter()
+        .map(Rc::new)
+        .collect::<Vec<_>>();
+    let (cmdb, user, hrm) = (&list[3], &list[8], &list[10]);
+
+    tax.remove_subtree(cmdb.clone(), RemovalPolicy::All).unwrap();
+
+    let names = tax.iter().map(|element| element.name()).collect::<Vec<_>>();
+    assert_eq!(names, vec!["CRM", "Customer", "Contact", "SRM", "HRM"]);
+    assert!(tax.get(user.clone()).is_none());
+    assert_eq!(tax.edges_from(Some(hrm.clone())).unwrap().count(), 0);
+
+    let result = tax.remove_subtree(cmdb.clone(), RemovalPolicy::All).err();
+    assert_eq!(result, Some(TaxonomyError::NodeNotFound(cmdb.clone())));
+}
+
+#[test]
+fn test_remove_recursively() {
+    let mut tax = setup_tax_empty();
+    let list = four_root_with_super_and_sub(&mut tax)
+        .into_iter()
+        .map(Rc::new)
+        .collect::<Vec<_>>();
+    let (crm, cmdb) = (&list[0], &list[3]);
+
+    tax.remove_recursively(cmdb.clone()).unwrap();
+    tax.remove_recursively(crm.clone()).unwrap();
+
+    let names = tax.iter().map(|element| element.name()).collect::<Vec<_>>();
+    assert_eq!(names, vec!["SRM", "HRM"]);
+}
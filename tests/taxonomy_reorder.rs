@@ -0,0 +1,73 @@
+// Integration with kodiak's taxonomy library
+use kodiak_taxonomy::TaxonomyError;
+
+// Shared code across integration tests
+use crate::setup::*;
+
+use std::rc::Rc;
+
+// Tests:
+// pub fn index_of(&self, super_id: Option<Rc<K>>, node_id: Rc<K>) -> Result<usize, TaxonomyError<K>>
+// pub fn reorder(&mut self, super_id: Option<Rc<K>>, node_id: Rc<K>, index: usize) -> Result<&mut Self, TaxonomyError<K>>
+// pub fn swap(&mut self, super_id: Option<Rc<K>>, node_id: Rc<K>, other_id: Rc<K>) -> Result<&mut Self, TaxonomyError<K>>
+// pub fn move_up(&mut self, super_id: Option<Rc<K>>, node_id: Rc<K>) -> Result<&mut Self, TaxonomyError<K>>
+// pub fn move_down(&mut self, super_id: Option<Rc<K>>, node_id: Rc<K>) -> Result<&mut Self, TaxonomyError<K>>
+// pub fn move_to_front(&mut self, super_id: Option<Rc<K>>, node_id: Rc<K>) -> Result<&mut Self, TaxonomyError<K>>
+// pub fn move_to_back(&mut self, super_id: Option<Rc<K>>, node_id: Rc<K>) -> Result<&mut Self, TaxonomyError<K>>
+// pub fn move_to(&mut self, node_id: Rc<K>, from_super_id: Option<Rc<K>>, to_super_id: Option<Rc<K>>, index: usize) -> Result<&mut Self, TaxonomyError<K>>
+
+#[test]
+fn test_reorder_root_nodes() {
+    let mut tax = setup_tax_empty();
+    let list = four_root_with_super_and_sub(&mut tax)
+        .into_iter()
+        .map(Rc::new)
+        .collect::<Vec<_>>();
+    let (crm, cmdb, srm, hrm) = (&list[0], &list[3], &list[9], &list[10]);
+
+    let roots = |tax: &kodiak_taxonomy::Taxonomy<_, _>| {
+        [crm, cmdb, srm, hrm]
+            .into_iter()
+            .map(|id| tax.index_of(None, id.clone()).unwrap())
+            .collect::<Vec<usize>>()
+    };
+    assert_eq!(roots(&tax), vec![0, 1, 2, 3]);
+
+    tax.move_to_back(None, crm.clone()).unwrap();
+    assert_eq!(roots(&tax), vec![3, 0, 1, 2]);
+
+    tax.move_to_front(None, hrm.clone()).unwrap();
+    assert_eq!(roots(&tax), vec![3, 1, 2, 0]);
+
+    tax.swap(None, crm.clone(), hrm.clone()).unwrap();
+    assert_eq!(roots(&tax), vec![0, 1, 2, 3]);
+
+    tax.move_down(None, crm.clone()).unwrap().move_up(None, srm.clone()).unwrap();
+    assert_eq!(roots(&tax), vec![2, 0, 1, 3]);
+
+    tax.reorder(None, srm.clone(), 3).unwrap();
+    assert_eq!(roots(&tax), vec![1, 0, 3, 2]);
+
+    // The first root-node is traversed first
+    assert_eq!(tax.iter().next().unwrap().name(), "CMDB");
+}
+
+#[test]
+fn test_reorder_sub_nodes() {
+    let mut tax = setup_tax_empty();
+    let list = four_root_with_super_and_sub(&mut tax)
+        .into_iter()
+        .map(Rc::new)
+        .collect::<Vec<_>>();
+    let (crm, customer, contact, device) = (&list[0], &list[1], &list[2], &list[4]);
+
+    tax.move_to(contact.clone(), Some(crm.clone()), Some(crm.clone()), 0).unwrap();
+    assert_eq!(tax.index_of(Some(crm.clone()), contact.clone()), Ok(0));
+    assert_eq!(tax.index_of(Some(crm.clone()), customer.clone()), Ok(1));
+
+    let names = tax.iter().take(3).map(|concept| concept.name()).collect::<Vec<_>>();
+    assert_eq!(names, vec!["CRM", "Contact", "Customer"]);
+
+    let result = tax.reorder(Some(crm.clone()), device.clone(), 0).err();
+    assert_eq!(result, Some(TaxonomyError::EdgeNotFound(Some(crm.clone()), device.clone())));
+}
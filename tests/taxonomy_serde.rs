@@ -0,0 +1,83 @@
+// Integration with kodiak's taxonomy library
+use kodiak_taxonomy::{Identity, Taxonomy};
+
+// Shared code across integration tests
+use crate::setup::*;
+use crate::Concept;
+
+use uuid::Uuid;
+
+use std::rc::Rc;
+
+// Tests:
+// impl Serialize for Taxonomy<K, V, M, E>
+// impl Deserialize for Taxonomy<K, V, M, E>
+
+#[test]
+fn test_serde_round_trip_keeps_order() {
+    let mut tax = setup_tax_empty();
+    let list = four_root_with_super_and_sub(&mut tax)
+        .into_iter()
+        .map(Rc::new)
+        .collect::<Vec<_>>();
+    let (crm, contact, cmdb, user, hrm) = (&list[0], &list[2], &list[3], &list[8], &list[10]);
+    tax.move_down(None, crm.clone()).unwrap();
+    tax.move_to_front(Some(crm.clone()), contact.clone()).unwrap();
+
+    let json = serde_json::to_string(&tax).unwrap();
+    let mut deserialized: Taxonomy<Uuid, Concept> = serde_json::from_str(&json).unwrap();
+
+    let elements = |tax: &Taxonomy<Uuid, Concept>| tax.iter().map(|element| (element.id(), element.name())).collect::<Vec<_>>();
+    assert_eq!(elements(&deserialized), elements(&tax));
+    assert_eq!(deserialized.iter().next().unwrap().id(), **cmdb);
+    assert!(deserialized.is_a(user.clone(), hrm.clone()).unwrap());
+    assert_eq!(serde_json::to_string(&deserialized).unwrap(), json);
+
+    // Deserialized taxonomy can be changed like any other
+    let prospect = Concept::new("Prospect");
+    let prospect_id = prospect.id();
+    deserialized.add(Some(**crm), prospect).unwrap();
+    deserialized.append(Some(**hrm), prospect_id).unwrap();
+    assert!(deserialized.is_a(Rc::new(prospect_id), crm.clone()).unwrap());
+}
+
+#[test]
+fn test_serde_round_trip_keeps_root_as_primary_super() {
+    let mut tax = setup_tax_empty();
+    let a = Concept::new("A");
+    let b = Concept::new("B");
+    let (a_id, b_id) = (a.id(), b.id());
+    tax.add(None, a).unwrap();
+    tax.add(Some(a_id), b).unwrap();
+    tax.append(None, b_id).unwrap();
+    tax.set_primary_super(Rc::new(b_id), None).unwrap();
+    assert_eq!(tax.primary_super(Rc::new(b_id)).unwrap(), None);
+
+    let json = serde_json::to_string(&tax).unwrap();
+    let deserialized: Taxonomy<Uuid, Concept> = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(deserialized.primary_super(Rc::new(b_id)).unwrap(), None);
+    assert_eq!(deserialized.canonical_path(Rc::new(b_id)).unwrap(), vec![Rc::new(b_id)]);
+    assert_eq!(serde_json::to_string(&deserialized).unwrap(), json);
+}
+
+#[test]
+fn test_serde_rejects_inconsistent_edges() {
+    let mut tax = setup_tax_empty();
+    let list = four_root_with_super_and_sub(&mut tax).into_iter().collect::<Vec<_>>();
+    let (crm, customer) = (list[0], list[1]);
+
+    // Customer lists CRM as super-node, but CRM doesn't list Customer as sub-node
+    let mut value = serde_json::to_value(&tax).unwrap();
+    for node in value["nodes"].as_array_mut().unwrap() {
+        if node["element"]["id"] == serde_json::json!(crm) {
+            node["subs"]
+                .as_array_mut()
+                .unwrap()
+                .retain(|sub| *sub != serde_json::json!(customer));
+        }
+    }
+
+    let err = serde_json::from_value::<Taxonomy<Uuid, Concept>>(value).unwrap_err();
+    assert!(err.to_string().contains("not found"));
+}
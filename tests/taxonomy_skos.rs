@@ -0,0 +1,78 @@
+// Integration with kodiak's taxonomy library
+use kodiak_taxonomy::{Identity, ImportError, SkosConcept, Taxonomy, TaxonomyError};
+
+// Shared code across integration tests
+use crate::setup::*;
+use crate::Concept;
+
+use uuid::Uuid;
+
+use std::rc::Rc;
+
+// Tests:
+// pub fn to_turtle<F>(&self, scheme: &str, concept: F) -> String
+// pub fn to_ntriples<F>(&self, scheme: &str, concept: F) -> String
+// pub fn from_turtle<F>(input: &str, element: F) -> Result<Self, ImportError<K>>
+// pub fn from_ntriples<F>(input: &str, element: F) -> Result<Self, ImportError<K>>
+
+fn concept(element: &Concept) -> SkosConcept {
+    SkosConcept::new(
+        format!("http://example.org/it/{}", element.name().replace(' ', "_")),
+        Some(element.name()),
+    )
+}
+
+fn names(tax: &Taxonomy<Uuid, Concept>) -> Vec<String> {
+    tax.iter().map(|element| element.name()).collect()
+}
+
+#[test]
+fn test_skos_round_trip_keeps_polyhierarchy() {
+    let mut tax = setup_tax_empty();
+    four_root_with_super_and_sub(&mut tax);
+
+    let turtle = tax.to_turtle("http://example.org/it", concept);
+    assert!(turtle.contains("<http://example.org/it/User> a skos:Concept ;"));
+    assert!(turtle.contains("    skos:broader <http://example.org/it/Firewall>, <http://example.org/it/HRM> .\n"));
+
+    let imported: Taxonomy<Uuid, Concept> =
+        Taxonomy::from_turtle(&turtle, |concept| Concept::new(concept.label().unwrap())).unwrap();
+    assert_eq!(names(&imported), names(&tax));
+
+    let ntriples = tax.to_ntriples("http://example.org/it", concept);
+    let imported: Taxonomy<Uuid, Concept> =
+        Taxonomy::from_ntriples(&ntriples, |concept| Concept::new(concept.label().unwrap())).unwrap();
+    assert_eq!(names(&imported), names(&tax));
+    assert_eq!(imported.to_ntriples("http://example.org/it", concept), ntriples);
+}
+
+#[test]
+fn test_skos_import_reports_line() {
+    let turtle = r#"@prefix skos: <http://www.w3.org/2004/02/skos/core#> .
+@prefix ex: <http://example.org/> .
+
+ex:device a skos:Concept ; skos:narrower ex:server .
+ex:server a skos:Concept ; skos:narrower ex:router .
+ex:router a skos:Concept ; skos:narrower ex:server .
+"#;
+    let result = Taxonomy::<Uuid, Concept>::from_turtle(turtle, |concept| Concept::new(concept.uri()));
+    match result {
+        Err(ImportError::Taxonomy(6, TaxonomyError::LoopDetected(_))) => (),
+        _ => panic!("loop expected in line 6"),
+    }
+
+    let result = Taxonomy::<Uuid, Concept>::from_turtle("ex:device a skos:Concept .", |concept| Concept::new(concept.uri()));
+    assert_eq!(result.err().map(|err| err.line()), Some(1));
+
+    let tax = Taxonomy::<Uuid, Concept>::from_turtle(
+        &turtle.replace(
+            "ex:router a skos:Concept ; skos:narrower ex:server",
+            "ex:router a skos:Concept ; skos:narrower ex:firewall",
+        ),
+        |concept| Concept::new(concept.uri()),
+    )
+    .unwrap();
+    let device = tax.iter().next().unwrap().id();
+    assert_eq!(tax.children(Rc::new(device)).unwrap().count(), 1);
+    assert_eq!(tax.iter().count(), 4);
+}
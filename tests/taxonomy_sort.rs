@@ -0,0 +1,81 @@
+// Integration with kodiak's taxonomy library
+use kodiak_taxonomy::Identity;
+
+// Shared code across integration tests
+use crate::setup::*;
+use crate::Concept;
+
+use std::rc::Rc;
+
+// Tests:
+// pub fn sort_subs_by<F>(&mut self, super_id: Option<Rc<K>>, cmp: F) -> Result<&mut Self, TaxonomyError<K>>
+// pub fn sort_all_by<F>(&mut self, cmp: F) -> &mut Self
+// pub fn enable_keep_sorted<F>(&mut self, cmp: F) -> &mut Self
+
+#[test]
+fn test_sort_all_by() {
+    let mut tax = setup_tax_empty();
+    four_root_with_super_and_sub(&mut tax);
+
+    tax.sort_all_by(|a, b| a.name().cmp(&b.name()));
+
+    let names = tax.iter().map(|concept| concept.name()).collect::<Vec<_>>();
+    let expectation = vec![
+        "CMDB",
+        "Device",
+        "Network Device",
+        "Security Device",
+        "Firewall",
+        "User",
+        "CRM",
+        "Contact",
+        "Customer",
+        "HRM",
+        "User",
+        "SRM",
+    ];
+    assert_eq!(names, expectation);
+}
+
+#[test]
+fn test_sort_subs_by() {
+    let mut tax = setup_tax_empty();
+    let list = two_root_with_two_sub_each(&mut tax)
+        .into_iter()
+        .map(Rc::new)
+        .collect::<Vec<_>>();
+    let organisation = &list[3];
+
+    // Descending by name
+    tax.sort_subs_by(Some(organisation.clone()), |a, b| b.name().cmp(&a.name()))
+        .unwrap();
+
+    let names = tax.iter().map(|concept| concept.name()).collect::<Vec<_>>();
+    let expectation = vec![
+        "Device",
+        "Network Device",
+        "Security Device",
+        "Organisation",
+        "User",
+        "Department",
+    ];
+    assert_eq!(names, expectation);
+}
+
+#[test]
+fn test_keep_sorted() {
+    let mut tax = setup_tax_empty();
+    tax.enable_keep_sorted(|a: &Concept, b: &Concept| a.name().cmp(&b.name()));
+
+    for name in ["Mammal", "Bird", "Fish"] {
+        tax.add(None, Concept::new(name)).unwrap();
+    }
+    let bird = tax.iter().next().unwrap().id();
+    for name in ["Sparrow", "Eagle", "Owl"] {
+        tax.add(Some(bird), Concept::new(name)).unwrap();
+    }
+
+    let names = tax.iter().map(|concept| concept.name()).collect::<Vec<_>>();
+    let expectation = vec!["Bird", "Eagle", "Owl", "Sparrow", "Fish", "Mammal"];
+    assert_eq!(names, expectation);
+}
@@ -0,0 +1,79 @@
+// Integration with kodiak's taxonomy library
+use kodiak_taxonomy::TaxonomyError;
+
+// Shared code across integration tests
+use crate::setup::*;
+
+use std::collections::HashSet;
+use std::rc::Rc;
+
+// Tests:
+// pub fn is_descendant_of(&self, node_id: Rc<K>, ancestor_id: Rc<K>) -> Result<bool, TaxonomyError<K>>
+// pub fn is_ancestor_of(&self, node_id: Rc<K>, descendant_id: Rc<K>) -> Result<bool, TaxonomyError<K>>
+// pub fn enable_reachability_index(&mut self) -> &mut Self
+// pub fn lowest_common_ancestors(&self, node_ids: &[Rc<K>]) -> Result<HashSet<Rc<K>>, TaxonomyError<K>>
+
+#[test]
+fn test_is_descendant_of() {
+    let mut tax = setup_tax_empty();
+    let list = four_root_with_super_and_sub(&mut tax)
+        .into_iter()
+        .map(Rc::new)
+        .collect::<Vec<_>>();
+    let (crm, cmdb, user, hrm) = (&list[0], &list[3], &list[8], &list[10]);
+
+    assert_eq!(tax.is_descendant_of(user.clone(), cmdb.clone()), Ok(true));
+    assert_eq!(tax.is_descendant_of(user.clone(), hrm.clone()), Ok(true));
+    assert_eq!(tax.is_descendant_of(user.clone(), crm.clone()), Ok(false));
+    assert_eq!(tax.is_ancestor_of(cmdb.clone(), user.clone()), Ok(true));
+}
+
+#[test]
+fn test_reachability_index_is_kept_up_to_date() {
+    let mut tax = setup_tax_empty();
+    tax.enable_reachability_index();
+    let list = four_root_with_super_and_sub(&mut tax)
+        .into_iter()
+        .map(Rc::new)
+        .collect::<Vec<_>>();
+    let (crm, cmdb, device, user, hrm) = (&list[0], &list[3], &list[4], &list[8], &list[10]);
+
+    assert_eq!(tax.is_descendant_of(user.clone(), cmdb.clone()), Ok(true));
+
+    // Device and its sub-nodes move from CMDB to CRM
+    assert!(tax.move_to(device.clone(), Some(cmdb.clone()), Some(crm.clone()), 0).is_ok());
+    assert_eq!(tax.is_descendant_of(user.clone(), cmdb.clone()), Ok(false));
+    assert_eq!(tax.is_descendant_of(user.clone(), crm.clone()), Ok(true));
+    assert_eq!(tax.is_descendant_of(user.clone(), hrm.clone()), Ok(true));
+
+    assert!(tax.remove(user.clone()).is_ok());
+    assert_eq!(
+        tax.is_descendant_of(user.clone(), crm.clone()),
+        Err(TaxonomyError::NodeNotFound(user.clone()))
+    );
+}
+
+#[test]
+fn test_lowest_common_ancestors() {
+    let mut tax = setup_tax_empty();
+    let list = four_root_with_super_and_sub(&mut tax)
+        .into_iter()
+        .map(Rc::new)
+        .collect::<Vec<_>>();
+    let (customer, contact, net_device, firewall, user) = (&list[1], &list[2], &list[5], &list[7], &list[8]);
+
+    let lca = tax.lowest_common_ancestors(&[customer.clone(), contact.clone()]).unwrap();
+    assert_eq!(lca, HashSet::from([list[0].clone()]));
+
+    let lca = tax.lowest_common_ancestors(&[net_device.clone(), user.clone()]).unwrap();
+    assert_eq!(lca, HashSet::from([list[4].clone()]));
+
+    let lca = tax.lowest_common_ancestors(&[firewall.clone(), user.clone()]).unwrap();
+    assert_eq!(lca, HashSet::from([firewall.clone()]));
+
+    // CRM and CMDB are different root-nodes
+    assert!(tax
+        .lowest_common_ancestors(&[customer.clone(), user.clone()])
+        .unwrap()
+        .is_empty());
+}
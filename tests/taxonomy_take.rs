@@ -0,0 +1,61 @@
+// Integration with kodiak's taxonomy library
+use kodiak_taxonomy::TaxonomyError;
+
+// Shared code across integration tests
+use crate::setup::*;
+
+use std::rc::Rc;
+
+// Tests:
+// pub fn take(&mut self, node_id: Rc<K>) -> Result<V, TaxonomyError<K>>
+// pub fn take_subtree(&mut self, node_id: Rc<K>) -> Result<Taxonomy<K, V, M, E>, TaxonomyError<K>>
+
+#[test]
+fn test_take() {
+    let mut tax = setup_tax_empty();
+    let list = four_root_with_super_and_sub(&mut tax)
+        .into_iter()
+        .map(Rc::new)
+        .collect::<Vec<_>>();
+    let (firewall, user, hrm) = (&list[7], &list[8], &list[10]);
+
+    let result = tax.take(firewall.clone()).err();
+    assert_eq!(result, Some(TaxonomyError::NodeHasSubNode(firewall.clone())));
+
+    // User is taken from all its super-nodes
+    let element = tax.take(user.clone()).unwrap();
+    assert_eq!(element.name(), "User");
+    assert!(tax.get(user.clone()).is_none());
+    assert_eq!(tax.edges_from(Some(hrm.clone())).unwrap().count(), 0);
+    assert_eq!(tax.edges_from(Some(firewall.clone())).unwrap().count(), 0);
+
+    let element = tax.take(firewall.clone()).unwrap();
+    assert_eq!(element.name(), "Firewall");
+}
+
+#[test]
+fn test_take_subtree() {
+    let mut tax = setup_tax_empty();
+    let list = four_root_with_super_and_sub(&mut tax)
+        .into_iter()
+        .map(Rc::new)
+        .collect::<Vec<_>>();
+    let (device, user, hrm) = (&list[4], &list[8], &list[10]);
+
+    let subtree = tax.take_subtree(device.clone()).unwrap();
+
+    let names = tax.iter().map(|element| element.name()).collect::<Vec<_>>();
+    assert_eq!(names, vec!["CRM", "Customer", "Contact", "CMDB", "SRM", "HRM"]);
+    assert_eq!(tax.edges_from(Some(hrm.clone())).unwrap().count(), 0);
+
+    let names = subtree.iter().map(|element| element.name()).collect::<Vec<_>>();
+    assert_eq!(names, vec!["Device", "Network Device", "Security Device", "Firewall", "User"]);
+    assert_eq!(subtree.edges_into(user.clone()).unwrap().count(), 1);
+
+    // The returned taxonomy is owned by the caller, e.g. to hand back its elements
+    let names = subtree.into_iter().map(|element| element.name()).collect::<Vec<_>>();
+    assert_eq!(names.len(), 5);
+
+    let result = tax.take_subtree(device.clone()).err();
+    assert_eq!(result, Some(TaxonomyError::NodeNotFound(device.clone())));
+}
@@ -0,0 +1,60 @@
+// Integration with kodiak's taxonomy library
+use kodiak_taxonomy::{Identity, TaxonomyError};
+
+// Shared code across integration tests
+use crate::setup::*;
+use crate::Concept;
+
+use std::rc::Rc;
+
+// Tests:
+// pub fn transaction<F, T, Er>(&mut self, f: F) -> Result<T, Er>
+
+#[test]
+fn test_transaction_rollback() {
+    let mut tax = setup_tax_empty();
+    let list = four_root_with_super_and_sub(&mut tax)
+        .into_iter()
+        .map(Rc::new)
+        .collect::<Vec<_>>();
+    let (crm, device, firewall, user, srm) = (&list[0], &list[4], &list[7], &list[8], &list[9]);
+    let names = |tax: &kodiak_taxonomy::Taxonomy<_, Concept>| tax.iter().map(|element| element.name()).collect::<Vec<_>>();
+    let before = names(&tax);
+
+    let server = Concept::new("Server");
+    let server_id = server.id();
+    let result = tax.transaction(|tx| {
+        tx.add(Some(**device), server)?
+            .append(Some(**srm), server_id)?
+            .move_to(user.clone(), Some(firewall.clone()), Some(crm.clone()), 0)?
+            .remove_recursively(srm.clone())?
+            .remove(device.clone())?;
+        Ok(())
+    });
+
+    assert_eq!(result, Err(TaxonomyError::NodeHasSubNode(device.clone())));
+    assert_eq!(names(&tax), before);
+    assert!(tax.get(Rc::new(server_id)).is_none());
+}
+
+#[test]
+fn test_transaction_commit() {
+    let mut tax = setup_tax_empty();
+    let list = four_root_with_super_and_sub(&mut tax)
+        .into_iter()
+        .map(Rc::new)
+        .collect::<Vec<_>>();
+    let (device, srm) = (&list[4], &list[9]);
+
+    let server = Concept::new("Server");
+    let server_id = server.id();
+    let count = tax
+        .transaction(|tx| {
+            tx.add(Some(**device), server)?.append(Some(**srm), server_id)?;
+            Ok::<usize, TaxonomyError<_>>(tx.iter().count())
+        })
+        .unwrap();
+
+    assert_eq!(count, 14);
+    assert_eq!(tax.edges_into(Rc::new(server_id)).unwrap().count(), 2);
+}
@@ -0,0 +1,28 @@
+// Integration with kodiak's taxonomy library
+use kodiak_taxonomy::{Glyphs, TreeStyle};
+
+// Shared code across integration tests
+use crate::setup::*;
+
+// Tests:
+// pub fn render_tree<L>(&self, style: &TreeStyle, label: L) -> String
+// impl Display for Taxonomy<K, V, M, E>
+
+#[test]
+fn test_render_tree_of_polyhierarchy() {
+    let mut tax = setup_tax_empty();
+    four_root_with_super_and_sub(&mut tax);
+
+    let tree = tax.to_string();
+    assert_eq!(tree.lines().count(), tax.iter().count());
+    assert!(tree.starts_with("├── CRM\n│   ├── Customer\n│   └── Contact\n├── CMDB\n"));
+    assert!(tree.ends_with("└── HRM\n    └── User\n"));
+
+    let style = TreeStyle::default().with_glyphs(Glyphs::Ascii).with_marker("*");
+    let tree = tax.render_tree(&style, |element| element.name());
+    assert!(tree.ends_with("`-- HRM\n    `-- User *\n"));
+    assert_eq!(tree.matches("User").count(), 2);
+
+    let tree = tax.render_tree(&TreeStyle::default().with_max_depth(1), |element| element.name());
+    assert_eq!(tree, "├── CRM\n├── CMDB\n├── SRM\n└── HRM\n");
+}